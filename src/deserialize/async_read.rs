@@ -0,0 +1,45 @@
+use crate::{
+    deserialize::{from_bytes, Result},
+    DeserializeError, DeserializeErrorKind, PushParser,
+};
+use core::{future::poll_fn, pin::Pin};
+use data_format::Deserialize;
+use futures_io::AsyncRead;
+
+/// The number of bytes read from the source at a time
+const CHUNK_SIZE: usize = 8192;
+
+/// Asynchronously reads JSON from `reader` and deserializes it into `T`.
+///
+/// The input is buffered a chunk at a time only until the value is complete, so anything after it
+/// may be left unread.
+pub async fn from_async_read<T, R>(mut reader: R) -> Result<'static, T>
+where
+    T: for<'de> Deserialize<'de>,
+    R: AsyncRead + Unpin,
+{
+    let parse = |bytes: &[u8]| from_bytes::<T>(bytes).map_err(DeserializeError::into_owned);
+
+    let mut parser = PushParser::new();
+    let mut chunk = vec![0; CHUNK_SIZE];
+    loop {
+        if let Some(value) = parser.next_with(parse)? {
+            return Ok(value.into_inner());
+        }
+
+        let length = poll_fn(|cx| Pin::new(&mut reader).poll_read(cx, &mut chunk))
+            .await
+            .map_err(|error| DeserializeError::from(DeserializeErrorKind::Io(error)))?;
+        if length == 0 {
+            break;
+        }
+
+        parser.feed(&chunk[..length]);
+    }
+
+    parser.finish();
+    match parser.next_with(parse)? {
+        Some(value) => Ok(value.into_inner()),
+        None => Err(DeserializeErrorKind::UnexpectedEndOfJSON.into()),
+    }
+}
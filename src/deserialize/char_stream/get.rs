@@ -0,0 +1,30 @@
+use super::CharStream;
+use lct_streams::Position;
+
+impl<'de> CharStream<'de> {
+    /// Gets the bytes of the whole document
+    pub(crate) fn bytes(&self) -> &'de [u8] {
+        self.bytes
+    }
+
+    /// Gets the offset of the next character
+    pub(crate) fn offset(&self) -> usize {
+        self.base_offset + self.inner.offset()
+    }
+
+    /// Gets the position of the next character
+    pub(crate) fn position(&self) -> Position {
+        self.rebase(self.inner.position())
+    }
+
+    /// Converts `position` in the inner stream to a position in the whole document
+    pub(super) fn rebase(&self, position: Position) -> Position {
+        match position.line() {
+            1 => Position::new(
+                self.base_position.line(),
+                self.base_position.column() + position.column() - 1,
+            ),
+            line => Position::new(self.base_position.line() + line - 1, position.column()),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use lct_streams::{Position, SliceByteCharStream};
+
+mod get;
+mod new;
+mod next;
+mod skip;
+
+/// A stream of the characters in a slice of bytes, which can move past runs of bytes found by the
+/// block scanners in one step
+pub(crate) struct CharStream<'de> {
+    /// The bytes of the whole document
+    bytes: &'de [u8],
+
+    /// The stream over the bytes from `base_offset` on
+    inner: SliceByteCharStream<'de>,
+
+    /// The offset in `bytes` where `inner` starts
+    base_offset: usize,
+
+    /// The position in `bytes` where `inner` starts
+    base_position: Position,
+}
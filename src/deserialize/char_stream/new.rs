@@ -0,0 +1,14 @@
+use super::CharStream;
+use lct_streams::{Position, SliceByteCharStream};
+
+impl<'de> CharStream<'de> {
+    /// Creates a new [`CharStream`] at the start of `bytes`
+    pub(crate) fn new(bytes: &'de [u8]) -> Self {
+        CharStream {
+            bytes,
+            inner: SliceByteCharStream::new(bytes),
+            base_offset: 0,
+            base_position: Position::new(1, 1),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use super::CharStream;
+use lct_streams::{InvalidUtf8, Position};
+
+impl<'de> CharStream<'de> {
+    /// Gets the next character without moving past it
+    pub(crate) fn peek(&mut self) -> Result<Option<char>, InvalidUtf8> {
+        self.inner.peek()
+    }
+
+    /// Gets the next character and its position without moving past it
+    pub(crate) fn peek_pos(&mut self) -> Result<Option<(char, Position)>, InvalidUtf8> {
+        let next = self.inner.peek_pos()?;
+        Ok(next.map(|(c, position)| (c, self.rebase(position))))
+    }
+
+    /// Moves past the next character, returning it
+    pub(crate) fn next(&mut self) -> Result<Option<char>, InvalidUtf8> {
+        self.inner.next()
+    }
+
+    /// Moves past the next character, returning it and its position
+    pub(crate) fn next_pos(&mut self) -> Result<Option<(char, Position)>, InvalidUtf8> {
+        let next = self.inner.next_pos()?;
+        Ok(next.map(|(c, position)| (c, self.rebase(position))))
+    }
+}
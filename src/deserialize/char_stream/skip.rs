@@ -0,0 +1,18 @@
+use super::CharStream;
+use crate::deserialize::advance;
+use lct_streams::SliceByteCharStream;
+
+impl<'de> CharStream<'de> {
+    /// Moves past the next `length` bytes at once. The bytes must be valid UTF-8 and end on a
+    /// character boundary.
+    pub(crate) fn skip(&mut self, length: usize) {
+        if length == 0 {
+            return;
+        }
+
+        let offset = self.offset();
+        self.base_position = advance(self.position(), &self.bytes[offset..offset + length]);
+        self.base_offset = offset + length;
+        self.inner = SliceByteCharStream::new(&self.bytes[self.base_offset..]);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
     deserialize::{
-        expect, number, peek, skip_whitespace, string, Deserializer, ListDeserializer,
-        MapDeserializer,
+        expect, number, peek, skip_whitespace,
+        spanned::{self, SpanMapDeserializer},
+        string, Deserializer, ListDeserializer, MapDeserializer,
     },
     DeserializeError, DeserializeErrorKind,
 };
@@ -34,10 +35,11 @@ impl<'a, 'de> data_format::Deserializer<'de> for Deserializer<'a, 'de> {
             ('t', pos) => expect(&mut self.stream, "true", "true").map(|_| (true, pos)),
             (c, pos) => Err(DeserializeError::unexpected(c, "true or false", pos)),
         }?;
+        let end = self.stream.position();
         converter
             .convert_bool(value)
             .map_err(|mut error: DeserializeError<'de>| {
-                error.set_position(pos);
+                error.set_span(pos, end);
                 error
             })
     }
@@ -64,10 +66,11 @@ impl<'a, 'de> data_format::Deserializer<'de> for Deserializer<'a, 'de> {
 
     fn deserialize_isize<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
         let (value, pos) = number::deserialize_isize(self.stream)?;
+        let end = self.stream.position();
         converter
             .convert_isize(value)
             .map_err(|mut error: DeserializeError<'de>| {
-                error.set_position(pos);
+                error.set_span(pos, end);
                 error
             })
     }
@@ -94,10 +97,11 @@ impl<'a, 'de> data_format::Deserializer<'de> for Deserializer<'a, 'de> {
 
     fn deserialize_usize<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
         let (value, pos) = number::deserialize_usize(self.stream)?;
+        let end = self.stream.position();
         converter
             .convert_usize(value)
             .map_err(|mut error: DeserializeError<'de>| {
-                error.set_position(pos);
+                error.set_span(pos, end);
                 error
             })
     }
@@ -108,20 +112,22 @@ impl<'a, 'de> data_format::Deserializer<'de> for Deserializer<'a, 'de> {
 
     fn deserialize_f64<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
         let (value, pos) = number::deserialize_f64(self.stream)?;
+        let end = self.stream.position();
         converter
             .convert_f64(value)
             .map_err(|mut error: DeserializeError<'de>| {
-                error.set_position(pos);
+                error.set_span(pos, end);
                 error
             })
     }
 
     fn deserialize_string<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
         let (string, pos) = string::deserialize_string(self.stream)?;
+        let end = self.stream.position();
         converter
             .convert_str_borrow(string)
             .map_err(|mut error: DeserializeError<'de>| {
-                error.set_position(pos);
+                error.set_span(pos, end);
                 error
             })
     }
@@ -132,10 +138,11 @@ impl<'a, 'de> data_format::Deserializer<'de> for Deserializer<'a, 'de> {
     ) -> Result<C::Value, Self::Error> {
         let pos = self.stream.position();
         expect(&mut self.stream, "null", "null")?;
+        let end = self.stream.position();
         converter
             .convert_unit()
             .map_err(|mut error: DeserializeError<'de>| {
-                error.set_position(pos);
+                error.set_span(pos, end);
                 error
             })
     }
@@ -150,9 +157,13 @@ impl<'a, 'de> data_format::Deserializer<'de> for Deserializer<'a, 'de> {
 
         let pos = self.stream.position();
         let result = converter
-            .convert_list(ListDeserializer::new(self.stream))
+            .convert_list(ListDeserializer::new(
+                self.stream,
+                self.options,
+                self.last_keys,
+            ))
             .map_err(|mut error| {
-                error.set_position(pos);
+                error.set_span(pos, self.stream.position());
                 error
             })?;
 
@@ -164,13 +175,32 @@ impl<'a, 'de> data_format::Deserializer<'de> for Deserializer<'a, 'de> {
     fn deserialize_map<C: Converter<'de>>(mut self, converter: C) -> Result<C::Value, Self::Error> {
         skip_whitespace(&mut self.stream)?;
 
+        if spanned::is_spanned(&converter) {
+            let start = self.stream.position();
+            return converter
+                .convert_map(SpanMapDeserializer::new(
+                    self.stream,
+                    self.options,
+                    self.last_keys,
+                    start,
+                ))
+                .map_err(|mut error| {
+                    error.set_span(start, self.stream.position());
+                    error
+                });
+        }
+
         expect(&mut self.stream, '{', "a map")?;
 
         let pos = self.stream.position();
         let result = converter
-            .convert_map(MapDeserializer::new(self.stream))
+            .convert_map(MapDeserializer::new(
+                self.stream,
+                self.options,
+                self.last_keys,
+            ))
             .map_err(|mut error| {
-                error.set_position(pos);
+                error.set_span(pos, self.stream.position());
                 error
             })?;
 
@@ -186,7 +216,15 @@ impl<'a, 'de> data_format::Deserializer<'de> for Deserializer<'a, 'de> {
         match peek(&mut self.stream) {
             Ok(('n', _)) => self.deserialize_unit(converter),
             Err(e) => match e.kind() {
-                DeserializeErrorKind::UnexpectedEndOfJSON => converter.convert_unit(),
+                DeserializeErrorKind::UnexpectedEndOfJSON => {
+                    let pos = self.stream.position();
+                    converter
+                        .convert_unit()
+                        .map_err(|mut error: DeserializeError<'de>| {
+                            error.set_span(pos, pos);
+                            error
+                        })
+                }
                 _ => Err(e),
             },
             _ => converter.convert_some(self),
@@ -1,4 +1,7 @@
-use lct_streams::SliceByteCharStream;
+use crate::{
+    deserialize::{CharStream, LastKeys},
+    DeserializeOptions,
+};
 
 mod deserializer;
 mod new;
@@ -6,5 +9,12 @@ mod new;
 /// A structure which deserializes JSON from a stream of bytes
 pub(super) struct Deserializer<'a, 'de> {
     /// The stream to read bytes from
-    stream: &'a mut SliceByteCharStream<'de>,
+    stream: &'a mut CharStream<'de>,
+
+    /// The options controlling deserialization
+    options: DeserializeOptions,
+
+    /// The keys of the objects in the value, if an enclosing object has scanned them for
+    /// [`DuplicateKeys::KeepLast`](crate::DuplicateKeys::KeepLast)
+    last_keys: Option<&'a LastKeys<'de>>,
 }
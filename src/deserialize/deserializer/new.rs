@@ -1,9 +1,20 @@
-use crate::deserialize::Deserializer;
-use lct_streams::SliceByteCharStream;
+use crate::{
+    deserialize::{CharStream, Deserializer, LastKeys},
+    DeserializeOptions,
+};
 
 impl<'a, 'de> Deserializer<'a, 'de> {
-    /// Creates a new [`Deserializer`] over `stream`
-    pub(crate) fn new(stream: &'a mut SliceByteCharStream<'de>) -> Self {
-        Deserializer { stream }
+    /// Creates a new [`Deserializer`] over `stream`, passing `last_keys` on to the objects in the
+    /// value
+    pub(crate) fn new(
+        stream: &'a mut CharStream<'de>,
+        options: DeserializeOptions,
+        last_keys: Option<&'a LastKeys<'de>>,
+    ) -> Self {
+        Deserializer {
+            stream,
+            options,
+            last_keys,
+        }
     }
 }
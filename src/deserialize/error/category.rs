@@ -0,0 +1,46 @@
+use crate::{DeserializeError, DeserializeErrorKind};
+
+/// The broad category of a [`DeserializeError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// The input is not syntactically valid JSON
+    Syntax,
+
+    /// The input is valid JSON but doesn't match the type being deserialized
+    Data,
+
+    /// The input ended before a complete JSON value was read
+    Eof,
+
+    /// Reading the input from its source failed
+    Io,
+}
+
+impl<'de> DeserializeError<'de> {
+    /// Gets the broad category of this error
+    pub fn classify(&self) -> ErrorCategory {
+        self.kind.classify()
+    }
+}
+
+impl<'de> DeserializeErrorKind<'de> {
+    /// Gets the broad category of this kind of error
+    pub fn classify(&self) -> ErrorCategory {
+        match self {
+            DeserializeErrorKind::Unexpected { .. } | DeserializeErrorKind::InvalidUtf8(_) => {
+                ErrorCategory::Syntax
+            }
+            DeserializeErrorKind::UnexpectedEndOfJSON => ErrorCategory::Eof,
+            DeserializeErrorKind::InvalidType { .. }
+            | DeserializeErrorKind::InvalidValue { .. }
+            | DeserializeErrorKind::InvalidLength { .. }
+            | DeserializeErrorKind::UnknownField { .. }
+            | DeserializeErrorKind::MissingField(_)
+            | DeserializeErrorKind::DuplicateField(_)
+            | DeserializeErrorKind::DuplicateKey(_)
+            | DeserializeErrorKind::Custom(_) => ErrorCategory::Data,
+            #[cfg(not(feature = "no_std"))]
+            DeserializeErrorKind::Io(_) => ErrorCategory::Io,
+        }
+    }
+}
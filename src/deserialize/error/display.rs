@@ -2,6 +2,14 @@ use crate::DeserializeError;
 
 impl<'de> core::fmt::Display for DeserializeError<'de> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{} at {}", self.kind, self.position.unwrap())
+        self.kind.fmt(f)?;
+
+        let path = self.path();
+        match (path.is_root(), self.position) {
+            (true, Some(position)) => write!(f, " at {}", position),
+            (false, Some(position)) => write!(f, " at {} ({})", path, position),
+            (false, None) => write!(f, " at {}", path),
+            (true, None) => Ok(()),
+        }
     }
 }
@@ -5,6 +5,7 @@ impl<'de> From<DeserializeErrorKind<'de>> for DeserializeError<'de> {
         DeserializeError {
             kind,
             position: None,
+            details: None,
         }
     }
 }
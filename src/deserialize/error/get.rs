@@ -1,4 +1,4 @@
-use crate::{DeserializeError, DeserializeErrorKind};
+use crate::{DeserializeError, DeserializeErrorKind, ErrorPath};
 use lct_streams::Position;
 
 impl<'de> DeserializeError<'de> {
@@ -11,4 +11,27 @@ impl<'de> DeserializeError<'de> {
     pub fn position(&self) -> Option<Position> {
         self.position
     }
+
+    /// Gets the byte offset in the input the error occurred at
+    pub fn offset(&self) -> Option<usize> {
+        self.details.as_ref().and_then(|details| details.offset)
+    }
+
+    /// Gets the start and end of the token or value which caused the error. The end is the same
+    /// as the start if only the position is known.
+    pub fn span(&self) -> Option<(Position, Position)> {
+        let end = self.details.as_ref().and_then(|details| details.end);
+        self.position.map(|start| (start, end.unwrap_or(start)))
+    }
+
+    /// Gets the location in the document of the value which caused the error
+    pub fn path(&self) -> &ErrorPath {
+        /// The path of errors which haven't had one set
+        static ROOT: ErrorPath = ErrorPath::root();
+
+        match &self.details {
+            Some(details) => &details.path,
+            None => &ROOT,
+        }
+    }
 }
@@ -0,0 +1,71 @@
+use crate::{DeserializeError, DeserializeErrorKind};
+#[cfg(feature = "no_std")]
+use alloc::borrow::Cow;
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+impl<'de> DeserializeError<'de> {
+    /// Converts this error into one which doesn't borrow from the input, so it can outlive it
+    /// and be sent between threads
+    pub fn into_owned(self) -> DeserializeError<'static> {
+        DeserializeError {
+            kind: self.kind.into_owned(),
+            position: self.position,
+            details: self.details,
+        }
+    }
+}
+
+impl<'de> DeserializeErrorKind<'de> {
+    /// Converts this kind into one which doesn't borrow from the input
+    pub fn into_owned(self) -> DeserializeErrorKind<'static> {
+        match self {
+            DeserializeErrorKind::InvalidType {
+                unexpected,
+                expected,
+            } => DeserializeErrorKind::InvalidType {
+                unexpected,
+                expected,
+            },
+            DeserializeErrorKind::InvalidValue {
+                unexpected,
+                expected,
+            } => DeserializeErrorKind::InvalidValue {
+                unexpected,
+                expected,
+            },
+            DeserializeErrorKind::InvalidLength {
+                unexpected,
+                expected,
+            } => DeserializeErrorKind::InvalidLength {
+                unexpected,
+                expected,
+            },
+            DeserializeErrorKind::UnknownField { field, expected } => {
+                DeserializeErrorKind::UnknownField {
+                    field: Cow::Owned(field.into_owned()),
+                    expected,
+                }
+            }
+            DeserializeErrorKind::MissingField(field) => DeserializeErrorKind::MissingField(field),
+            DeserializeErrorKind::DuplicateField(field) => {
+                DeserializeErrorKind::DuplicateField(field)
+            }
+            DeserializeErrorKind::DuplicateKey(key) => {
+                DeserializeErrorKind::DuplicateKey(Cow::Owned(key.into_owned()))
+            }
+            DeserializeErrorKind::Unexpected {
+                unexpected,
+                expected,
+            } => DeserializeErrorKind::Unexpected {
+                unexpected,
+                expected,
+            },
+            DeserializeErrorKind::UnexpectedEndOfJSON => DeserializeErrorKind::UnexpectedEndOfJSON,
+            DeserializeErrorKind::InvalidUtf8(error) => DeserializeErrorKind::InvalidUtf8(error),
+            DeserializeErrorKind::Custom(error) => DeserializeErrorKind::Custom(error),
+            #[cfg(not(feature = "no_std"))]
+            DeserializeErrorKind::Io(error) => DeserializeErrorKind::Io(error),
+        }
+    }
+}
@@ -26,6 +26,14 @@ impl<'de> core::fmt::Display for DeserializeErrorKind<'de> {
                 expected,
             } => write!(f, "unexpected length {}, expected {}", unexpected, expected),
             DeserializeErrorKind::UnknownField { field, expected } => {
+                if let Some(suggestion) = self.suggestion() {
+                    return write!(
+                        f,
+                        "unknown field \"{}\", did you mean \"{}\"?",
+                        field, suggestion
+                    );
+                }
+
                 write!(f, "unknown field \"{}\", expected ", field)?;
                 for i in 0..expected.len() {
                     if i != 0 {
@@ -39,6 +47,9 @@ impl<'de> core::fmt::Display for DeserializeErrorKind<'de> {
             DeserializeErrorKind::DuplicateField(field) => {
                 write!(f, "\"{}\" appears more than once", field)
             }
+            DeserializeErrorKind::DuplicateKey(key) => {
+                write!(f, "duplicate key \"{}\" in object", key)
+            }
             DeserializeErrorKind::Unexpected {
                 unexpected,
                 expected,
@@ -49,6 +60,8 @@ impl<'de> core::fmt::Display for DeserializeErrorKind<'de> {
             ),
             DeserializeErrorKind::UnexpectedEndOfJSON => write!(f, "unexpected end of JSON"),
             DeserializeErrorKind::InvalidUtf8(error) => error.fmt(f),
+            #[cfg(not(feature = "no_std"))]
+            DeserializeErrorKind::Io(error) => error.fmt(f),
         }
     }
 }
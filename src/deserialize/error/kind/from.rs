@@ -6,3 +6,10 @@ impl<'de> From<InvalidUtf8> for DeserializeErrorKind<'de> {
         DeserializeErrorKind::InvalidUtf8(error)
     }
 }
+
+#[cfg(not(feature = "no_std"))]
+impl<'de> From<std::io::Error> for DeserializeErrorKind<'de> {
+    fn from(error: std::io::Error) -> Self {
+        DeserializeErrorKind::Io(error)
+    }
+}
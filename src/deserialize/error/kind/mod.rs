@@ -7,6 +7,7 @@ use std::borrow::Cow;
 
 mod display;
 mod from;
+mod suggestion;
 
 /// A kind of error that can occur while deserializing
 #[derive(Debug)]
@@ -53,6 +54,9 @@ pub enum DeserializeErrorKind<'de> {
     /// A field in a map has been duplicated
     DuplicateField(&'static str),
 
+    /// A key appears more than once in the same object
+    DuplicateKey(Cow<'de, str>),
+
     /// An unexpected character was encountered
     Unexpected {
         /// The error causing string
@@ -70,4 +74,8 @@ pub enum DeserializeErrorKind<'de> {
 
     /// A custom error
     Custom(String),
+
+    /// Reading the input failed
+    #[cfg(not(feature = "no_std"))]
+    Io(std::io::Error),
 }
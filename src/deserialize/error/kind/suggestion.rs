@@ -0,0 +1,54 @@
+use crate::DeserializeErrorKind;
+#[cfg(feature = "no_std")]
+use alloc::{vec, vec::Vec};
+
+impl<'de> DeserializeErrorKind<'de> {
+    /// Gets the expected field name closest to an unknown field, if any is close enough to
+    /// plausibly be what was meant
+    pub fn suggestion(&self) -> Option<&'static str> {
+        let (field, expected) = match self {
+            DeserializeErrorKind::UnknownField { field, expected } => (field, *expected),
+            _ => return None,
+        };
+
+        let field: Vec<char> = field.chars().collect();
+        expected
+            .iter()
+            .map(|candidate| {
+                let candidate_chars: Vec<char> = candidate.chars().collect();
+                let distance = edit_distance(&field, &candidate_chars);
+                (distance, candidate_chars.len(), *candidate)
+            })
+            .filter(|(distance, len, _)| *distance <= field.len().max(*len) / 3 + 1)
+            .min_by_key(|(distance, _, _)| *distance)
+            .map(|(_, _, candidate)| candidate)
+    }
+}
+
+/// Counts the insertions, deletions, substitutions and adjacent transpositions needed to turn
+/// `a` into `b`
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    // Three rows of the distance matrix are kept, as transpositions look back two rows
+    let mut previous2 = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = (a[i - 1] != b[j - 1]) as usize;
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(previous2[j - 2] + 1);
+            }
+        }
+
+        core::mem::swap(&mut previous2, &mut previous);
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
@@ -1,14 +1,23 @@
+#[cfg(feature = "no_std")]
+use alloc::boxed::Box;
 use lct_streams::Position;
 
+mod category;
 mod deserialize_error;
 mod display;
 mod from;
 mod get;
+mod into_owned;
 mod kind;
 mod new;
+mod path;
 mod set;
+mod snippet;
 
+pub use category::ErrorCategory;
 pub use kind::DeserializeErrorKind;
+pub use path::ErrorPath;
+pub use snippet::ErrorSnippet;
 
 /// The result of deserializing from JSON
 pub type Result<'de, T> = core::result::Result<T, DeserializeError<'de>>;
@@ -21,6 +30,24 @@ pub struct DeserializeError<'de> {
 
     /// Where the error occurred
     position: Option<Position>,
+
+    /// The rest of the location of the error, boxed so results stay small. It is only allocated
+    /// once part of it is set.
+    details: Option<Box<ErrorDetails>>,
+}
+
+/// The parts of the location of a [`DeserializeError`] which are rarely set and only read when
+/// it is reported
+#[derive(Debug, Default)]
+struct ErrorDetails {
+    /// Where the token or value which caused the error ends, if it is known
+    end: Option<Position>,
+
+    /// The byte offset in the input where the error occurred
+    offset: Option<usize>,
+
+    /// The location in the document of the value which caused the error
+    path: ErrorPath,
 }
 
 impl<'de> core::error::Error for DeserializeError<'de> {}
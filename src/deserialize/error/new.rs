@@ -8,9 +8,22 @@ impl<'de> DeserializeError<'de> {
         DeserializeError {
             kind: kind.into(),
             position: Some(position),
+            details: None,
         }
     }
 
+    /// Sets where the token or value which caused the error ends
+    pub(crate) fn with_end(mut self, end: Position) -> Self {
+        self.details_mut().end = Some(end);
+        self
+    }
+
+    /// Sets the byte offset of the error
+    pub(crate) fn with_offset(mut self, offset: usize) -> Self {
+        self.details_mut().offset = Some(offset);
+        self
+    }
+
     /// Creates a new [`DeserializeError`] with [`DeserializeErrorKind::Unexpected`]
     pub(crate) fn unexpected<U: Into<Unexpected>>(
         unexpected: U,
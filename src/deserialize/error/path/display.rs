@@ -0,0 +1,45 @@
+use crate::{ErrorPath, PathElement};
+use core::fmt::Write;
+
+impl core::fmt::Display for ErrorPath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.elements.is_empty() {
+            return f.write_char('.');
+        }
+
+        for element in &self.elements {
+            match element {
+                PathElement::Index(index) => write!(f, "[{}]", index)?,
+                PathElement::Name(name) if is_identifier(name) => write!(f, ".{}", name)?,
+                PathElement::Name(name) => {
+                    f.write_str("[\"")?;
+                    for c in name.chars() {
+                        match c {
+                            '"' => f.write_str("\\\"")?,
+                            '\\' => f.write_str("\\\\")?,
+                            '\n' => f.write_str("\\n")?,
+                            '\r' => f.write_str("\\r")?,
+                            '\t' => f.write_str("\\t")?,
+                            c if c < ' ' => write!(f, "\\u{:04x}", c as u32)?,
+                            c => f.write_char(c)?,
+                        }
+                    }
+                    f.write_str("\"]")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Can `name` be written after a '.' without quoting?
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+
+    chars.all(|c| c.is_alphanumeric() || c == '_')
+}
@@ -0,0 +1,13 @@
+use crate::{ErrorPath, PathElement};
+
+impl ErrorPath {
+    /// Gets the member names and array indices leading from the root to the location
+    pub fn elements(&self) -> impl DoubleEndedIterator<Item = &PathElement> + ExactSizeIterator {
+        self.elements.iter()
+    }
+
+    /// Is this the root of the document?
+    pub fn is_root(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
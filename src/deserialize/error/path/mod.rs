@@ -0,0 +1,19 @@
+use crate::PathElement;
+#[cfg(feature = "no_std")]
+use alloc::collections::VecDeque;
+#[cfg(not(feature = "no_std"))]
+use std::collections::VecDeque;
+
+mod display;
+mod get;
+mod new;
+mod set;
+
+/// The location in a JSON document where a deserialization error occurred, written like
+/// `.servers[3].port`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ErrorPath {
+    /// The member names and array indices leading from the root to the location, which are added
+    /// from the front as the error propagates out
+    elements: VecDeque<PathElement>,
+}
@@ -0,0 +1,14 @@
+use crate::ErrorPath;
+#[cfg(feature = "no_std")]
+use alloc::collections::VecDeque;
+#[cfg(not(feature = "no_std"))]
+use std::collections::VecDeque;
+
+impl ErrorPath {
+    /// Creates the path of the root of a document
+    pub(crate) const fn root() -> Self {
+        ErrorPath {
+            elements: VecDeque::new(),
+        }
+    }
+}
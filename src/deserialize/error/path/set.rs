@@ -0,0 +1,8 @@
+use crate::{ErrorPath, PathElement};
+
+impl ErrorPath {
+    /// Adds `element` to the start of the path, as the error propagates out of its parent
+    pub(crate) fn push_front(&mut self, element: PathElement) {
+        self.elements.push_front(element);
+    }
+}
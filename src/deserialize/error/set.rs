@@ -1,11 +1,119 @@
-use crate::DeserializeError;
+use super::ErrorDetails;
+use crate::{DeserializeError, PathElement};
+#[cfg(feature = "no_std")]
+use alloc::boxed::Box;
 use lct_streams::Position;
 
 impl<'de> DeserializeError<'de> {
-    /// Sets the position that the error occurred at, if one hasn't already been sets
-    pub(crate) fn set_position(&mut self, position: Position) {
+    /// Sets the span of the token or value that caused the error, if one hasn't already been set
+    /// by a more deeply nested value
+    pub(crate) fn set_span(&mut self, start: Position, end: Position) {
         if self.position.is_none() {
-            self.position = Some(position);
+            self.position = Some(start);
+            self.details_mut().end = Some(end);
         }
     }
+
+    /// Sets the byte offset of the error from its position, where `anchor` is `anchor_offset`
+    /// bytes into `input`. Only the text between the anchor and the error is scanned, so the
+    /// anchor should be where the stream stopped when the error was found.
+    pub(crate) fn set_offset_from(&mut self, input: &[u8], anchor: Position, anchor_offset: usize) {
+        let offset = self.position.and_then(|position| {
+            offset_near(input, anchor, anchor_offset.min(input.len()), position)
+        });
+
+        if offset.is_some() || self.details.is_some() {
+            self.details_mut().offset = offset;
+        }
+    }
+
+    /// Moves the error's location from being relative to a value which started at `base`, and
+    /// `base_offset` bytes into the input, to being relative to the whole input
+    pub(crate) fn rebase(&mut self, base: Position, base_offset: usize) {
+        let rebase = |position: Position| match position.line() {
+            1 => Position::new(base.line(), base.column() + position.column() - 1),
+            line => Position::new(base.line() + line - 1, position.column()),
+        };
+
+        self.position = self.position.map(rebase);
+        if let Some(details) = &mut self.details {
+            details.end = details.end.map(rebase);
+            details.offset = details.offset.map(|offset| base_offset + offset);
+        }
+    }
+
+    /// Adds `element` to the start of the error's path, as it propagates out of the element's
+    /// parent
+    pub(crate) fn push_path(&mut self, element: PathElement) {
+        self.details_mut().path.push_front(element);
+    }
+
+    /// Gets the details of the error, allocating them if they haven't been yet
+    pub(super) fn details_mut(&mut self) -> &mut ErrorDetails {
+        self.details
+            .get_or_insert_with(|| Box::new(ErrorDetails::default()))
+    }
+}
+
+/// Finds the byte offset of `position` in `input` by moving from `anchor`, which is
+/// `anchor_offset` bytes into `input`. The column after the last character of a line refers to the
+/// line break.
+fn offset_near(
+    input: &[u8],
+    anchor: Position,
+    anchor_offset: usize,
+    position: Position,
+) -> Option<usize> {
+    let is_continuation = |offset: usize| input[offset] & 0xC0 == 0x80;
+
+    let (mut offset, column) = if position.line() == anchor.line() {
+        if position.column() < anchor.column() {
+            let mut offset = anchor_offset;
+            for _ in position.column()..anchor.column() {
+                offset = offset.checked_sub(1)?;
+                while offset > 0 && is_continuation(offset) {
+                    offset -= 1;
+                }
+
+                if input[offset] == b'\n' {
+                    return None;
+                }
+            }
+            return Some(offset);
+        }
+
+        (anchor_offset, anchor.column())
+    } else if position.line() < anchor.line() {
+        let line_start = |end: usize| {
+            input[..end]
+                .iter()
+                .rposition(|byte| *byte == b'\n')
+                .map_or(0, |index| index + 1)
+        };
+
+        let mut start = line_start(anchor_offset);
+        for _ in position.line()..anchor.line() {
+            start = line_start(start.checked_sub(1)?);
+        }
+        (start, 1)
+    } else {
+        let mut start = anchor_offset;
+        for _ in anchor.line()..position.line() {
+            start += input[start..].iter().position(|byte| *byte == b'\n')? + 1;
+        }
+        (start, 1)
+    };
+
+    for _ in column..position.column() {
+        match input.get(offset) {
+            None | Some(b'\n') => return None,
+            Some(_) => {
+                offset += 1;
+                while offset < input.len() && is_continuation(offset) {
+                    offset += 1;
+                }
+            }
+        }
+    }
+    Some(offset)
 }
@@ -0,0 +1,141 @@
+use super::ErrorSnippet;
+use core::fmt::Write;
+
+/// The ANSI escape codes used to color a snippet
+struct Colors {
+    /// Marks the error
+    error: &'static str,
+
+    /// Marks the gutter
+    gutter: &'static str,
+
+    /// Marks the message
+    bold: &'static str,
+
+    /// Resets the color
+    reset: &'static str,
+}
+
+/// The colors used when coloring is enabled
+const ANSI: Colors = Colors {
+    error: "\x1B[1;31m",
+    gutter: "\x1B[1;34m",
+    bold: "\x1B[1m",
+    reset: "\x1B[0m",
+};
+
+/// The colors used when coloring is disabled
+const PLAIN: Colors = Colors {
+    error: "",
+    gutter: "",
+    bold: "",
+    reset: "",
+};
+
+/// Marks where a line has been truncated
+const ELLIPSIS: &str = "...";
+
+impl<'a, 'de> ErrorSnippet<'a, 'de> {
+    /// Gets the index of the first character shown for a line of `len` characters and if the
+    /// line is truncated at its end
+    fn window(&self, len: usize, column: usize) -> (usize, bool) {
+        if len <= self.max_width {
+            return (0, false);
+        }
+
+        let start = column
+            .saturating_sub(1 + self.max_width / 2)
+            .min(len - self.max_width);
+        (start, start + self.max_width < len)
+    }
+}
+
+impl<'a, 'de> core::fmt::Display for ErrorSnippet<'a, 'de> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let colors = if self.color { &ANSI } else { &PLAIN };
+
+        write!(
+            f,
+            "{}error{}{}: {}{}",
+            colors.error,
+            colors.reset,
+            colors.bold,
+            self.error.kind(),
+            colors.reset
+        )?;
+
+        let (position, end) = match self.error.span() {
+            Some(span) => span,
+            None => return Ok(()),
+        };
+        let line = position.line().max(1);
+        let column = position.column().max(1);
+
+        // Underline the whole span if it is on a single line
+        let underline = match end.line() == position.line() {
+            true => end.column().saturating_sub(column).max(1),
+            false => 1,
+        };
+
+        let first = line.saturating_sub(self.context).max(1);
+        let last = (line + self.context).min(self.input.split('\n').count().max(line));
+        let width = digits(last);
+
+        write!(
+            f,
+            "\n{:width$}{}-->{} {}\n{:width$} {}|{}",
+            "", colors.gutter, colors.reset, position, "", colors.gutter, colors.reset,
+        )?;
+
+        let lines = self.input.split('\n').chain(core::iter::repeat(""));
+        let lines = lines.skip(first - 1);
+        for (number, text) in (first..=last).zip(lines) {
+            let text = text.strip_suffix('\r').unwrap_or(text);
+            let (start, truncated) = self.window(text.chars().count(), column);
+
+            write!(f, "\n{}{:>width$} |{}", colors.gutter, number, colors.reset)?;
+            if !text.is_empty() {
+                f.write_char(' ')?;
+                if start > 0 {
+                    f.write_str(ELLIPSIS)?;
+                }
+                for c in text.chars().skip(start).take(self.max_width) {
+                    f.write_char(c)?;
+                }
+                if truncated {
+                    f.write_str(ELLIPSIS)?;
+                }
+            }
+
+            if number != line {
+                continue;
+            }
+
+            write!(f, "\n{:width$} {}|{} ", "", colors.gutter, colors.reset)?;
+            if start > 0 {
+                f.write_str("   ")?;
+            }
+            let padding = text.chars().chain(core::iter::repeat(' '));
+            for c in padding.skip(start).take(column - 1 - start) {
+                f.write_char(if c == '\t' { '\t' } else { ' ' })?;
+            }
+            f.write_str(colors.error)?;
+            for _ in 0..underline.min((start + self.max_width + 1).saturating_sub(column)) {
+                f.write_char('^')?;
+            }
+            f.write_str(colors.reset)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Gets the number of decimal digits in `value`
+fn digits(mut value: usize) -> usize {
+    let mut digits = 1;
+    while value >= 10 {
+        value /= 10;
+        digits += 1;
+    }
+    digits
+}
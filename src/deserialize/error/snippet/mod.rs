@@ -0,0 +1,24 @@
+use crate::DeserializeError;
+
+mod display;
+mod new;
+mod set;
+
+/// Renders a [`DeserializeError`] together with the lines of the input around it, marking the
+/// column of the error with a caret
+pub struct ErrorSnippet<'a, 'de> {
+    /// The error being rendered
+    error: &'a DeserializeError<'de>,
+
+    /// The input the error occurred in
+    input: &'a str,
+
+    /// The number of lines shown before and after the line of the error
+    context: usize,
+
+    /// Should ANSI escape codes be used to color the output?
+    color: bool,
+
+    /// The maximum number of characters of a line that are shown
+    max_width: usize,
+}
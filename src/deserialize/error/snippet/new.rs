@@ -0,0 +1,16 @@
+use super::ErrorSnippet;
+use crate::DeserializeError;
+
+impl<'de> DeserializeError<'de> {
+    /// Creates an [`ErrorSnippet`] rendering this error within `input`, the string it occurred
+    /// while deserializing
+    pub fn snippet<'a>(&'a self, input: &'a str) -> ErrorSnippet<'a, 'de> {
+        ErrorSnippet {
+            error: self,
+            input,
+            context: 2,
+            color: false,
+            max_width: 120,
+        }
+    }
+}
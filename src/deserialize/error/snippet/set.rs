@@ -0,0 +1,19 @@
+use super::ErrorSnippet;
+
+impl<'a, 'de> ErrorSnippet<'a, 'de> {
+    /// Sets the number of lines shown before and after the line of the error
+    pub fn set_context(&mut self, context: usize) {
+        self.context = context;
+    }
+
+    /// Sets if ANSI escape codes should be used to color the output
+    pub fn set_color(&mut self, color: bool) {
+        self.color = color;
+    }
+
+    /// Sets the maximum number of characters of a line that are shown. Longer lines are
+    /// truncated around the column of the error.
+    pub fn set_max_width(&mut self, max_width: usize) {
+        self.max_width = max_width.max(1);
+    }
+}
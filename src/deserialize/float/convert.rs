@@ -0,0 +1,54 @@
+use super::{lemire::compute_float, Significand};
+
+/// The powers of ten which are exactly representable as an [`f64`]
+const POWERS_OF_TEN: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+impl Significand {
+    /// Converts the number into the nearest [`f64`], given its sign and `exponent`.
+    ///
+    /// `text` is only parsed again, as the slow path, if the significant digits kept aren't enough
+    /// to decide how to round.
+    pub(in crate::deserialize) fn into_f64(self, minus: bool, exponent: i64, text: &str) -> f64 {
+        let power = self.scale.saturating_add(exponent);
+        let value = match self.fast_path(power).or_else(|| self.eisel_lemire(power)) {
+            Some(value) => value,
+            None => return text.parse().unwrap(),
+        };
+
+        if minus {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Converts the number when both its digits and `10^power` are exact as an [`f64`], so a
+    /// single correctly rounded operation gives the result
+    fn fast_path(&self, power: i64) -> Option<f64> {
+        if self.truncated || self.digits > 1 << 53 || !(-22..=22).contains(&power) {
+            return None;
+        }
+
+        let value = self.digits as f64;
+        Some(match power < 0 {
+            true => value / POWERS_OF_TEN[-power as usize],
+            false => value * POWERS_OF_TEN[power as usize],
+        })
+    }
+
+    /// Converts the number with the Eisel-Lemire algorithm
+    fn eisel_lemire(&self, power: i64) -> Option<f64> {
+        let bits = compute_float(power, self.digits)?;
+
+        // Dropped digits put the number between `digits` and `digits + 1`, which must round the
+        // same way
+        if self.truncated && compute_float(power, self.digits + 1)? != bits {
+            return None;
+        }
+
+        Some(f64::from_bits(bits))
+    }
+}
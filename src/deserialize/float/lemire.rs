@@ -0,0 +1,103 @@
+use super::table::POWERS_OF_FIVE;
+
+/// The number of explicit bits in the mantissa of an [`f64`]
+const MANTISSA_BITS: i32 = 52;
+
+/// The exponent bias of an [`f64`]
+const EXPONENT_BIAS: i32 = 1023;
+
+/// The biased exponent of infinity
+const INFINITE_POWER: i32 = 0x7FF;
+
+/// The smallest power of ten which doesn't round every mantissa to zero
+pub(super) const SMALLEST_POWER: i64 = -342;
+
+/// The largest power of ten which doesn't round every mantissa to infinity
+pub(super) const LARGEST_POWER: i64 = 308;
+
+/// Computes the bits of the [`f64`] nearest to `mantissa * 10^power` using the Eisel-Lemire
+/// algorithm, returning [`None`] if the result can't be rounded correctly this way
+pub(super) fn compute_float(power: i64, mut mantissa: u64) -> Option<u64> {
+    if mantissa == 0 || power < SMALLEST_POWER {
+        return Some(0);
+    } else if power > LARGEST_POWER {
+        return Some((INFINITE_POWER as u64) << MANTISSA_BITS);
+    }
+
+    // Normalize the mantissa so its most significant bit is set
+    let leading_zeros = mantissa.leading_zeros() as i32;
+    mantissa <<= leading_zeros;
+
+    let (low, high) = product(power, mantissa);
+    if low == u64::MAX && !(-27..=55).contains(&power) {
+        // The truncated product might have rounded the other way
+        return None;
+    }
+
+    let upper_bit = (high >> 63) as i32;
+    let shift = upper_bit + 64 - MANTISSA_BITS - 3;
+    let mut bits = high >> shift;
+    let mut exponent = binary_power(power as i32) + upper_bit - leading_zeros + EXPONENT_BIAS;
+
+    if exponent <= 0 {
+        // The value is subnormal, or rounds to zero
+        if -exponent + 1 >= 64 {
+            return Some(0);
+        }
+
+        bits >>= -exponent + 1;
+        bits += bits & 1;
+        bits >>= 1;
+
+        // Rounding up may have made it the smallest normal value
+        let exponent = (bits >= (1 << MANTISSA_BITS)) as u64;
+        return Some((exponent << MANTISSA_BITS) | (bits & ((1 << MANTISSA_BITS) - 1)));
+    }
+
+    // A value exactly halfway between two floats rounds to the even one
+    if low <= 1 && (-4..=23).contains(&power) && bits & 3 == 1 && bits << shift == high {
+        bits &= !1;
+    }
+
+    bits += bits & 1;
+    bits >>= 1;
+    if bits >= (2 << MANTISSA_BITS) {
+        bits = 1 << MANTISSA_BITS;
+        exponent += 1;
+    }
+
+    if exponent >= INFINITE_POWER {
+        return Some((INFINITE_POWER as u64) << MANTISSA_BITS);
+    }
+
+    Some(((exponent as u64) << MANTISSA_BITS) | (bits & ((1 << MANTISSA_BITS) - 1)))
+}
+
+/// Gets the power of two of `10^power`, offset by 63
+fn binary_power(power: i32) -> i32 {
+    (power.wrapping_mul(152_170 + 65536) >> 16) + 63
+}
+
+/// Multiplies `mantissa` by the 128-bit approximation of `5^power`, returning the low and high 64
+/// bits of the top of the product. Only as many bits as are needed to round are made exact.
+fn product(power: i64, mantissa: u64) -> (u64, u64) {
+    let (high5, low5) = POWERS_OF_FIVE[(power - SMALLEST_POWER) as usize];
+
+    let (mut low, mut high) = multiply(mantissa, high5);
+    let mask = u64::MAX >> (MANTISSA_BITS + 3);
+    if high & mask == mask {
+        let (_, carry) = multiply(mantissa, low5);
+        low = low.wrapping_add(carry);
+        if carry > low {
+            high += 1;
+        }
+    }
+
+    (low, high)
+}
+
+/// Multiplies `a` and `b`, returning the low and high 64 bits of the product
+fn multiply(a: u64, b: u64) -> (u64, u64) {
+    let product = a as u128 * b as u128;
+    (product as u64, (product >> 64) as u64)
+}
@@ -0,0 +1,24 @@
+mod convert;
+mod lemire;
+mod push;
+mod table;
+
+/// The most significant digits of a number as it is scanned, enough to convert it into a
+/// correctly rounded [`f64`] without scanning it again
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct Significand {
+    /// The first [`MAX_DIGITS`] significant digits
+    digits: u64,
+
+    /// The number of digits in `digits`
+    count: usize,
+
+    /// The power of ten `digits` is scaled by, not counting an exponent
+    scale: i64,
+
+    /// Were any non-zero digits dropped from `digits`?
+    truncated: bool,
+}
+
+/// The most digits which always fit in a [`u64`]
+const MAX_DIGITS: usize = 19;
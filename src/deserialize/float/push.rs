@@ -0,0 +1,24 @@
+use super::{Significand, MAX_DIGITS};
+
+impl Significand {
+    /// Adds the next `digit` of the number, which is after the decimal point if `fraction`
+    pub(in crate::deserialize) fn push(&mut self, digit: u8, fraction: bool) {
+        if self.count == MAX_DIGITS {
+            self.truncated |= digit != 0;
+            if !fraction {
+                self.scale += 1;
+            }
+            return;
+        }
+
+        // Leading zeros aren't significant
+        if self.count > 0 || digit != 0 {
+            self.digits = self.digits * 10 + digit as u64;
+            self.count += 1;
+        }
+
+        if fraction {
+            self.scale -= 1;
+        }
+    }
+}
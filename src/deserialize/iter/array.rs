@@ -0,0 +1,89 @@
+use super::{close, State};
+use crate::{
+    deserialize::{expect, skip_whitespace, CharStream, ListDeserializer, Result},
+    DeserializeOptions,
+};
+use core::marker::PhantomData;
+use data_format::{Deserialize, ListDeserializer as _};
+
+/// An iterator over the elements of a top-level JSON array, created by
+/// [`iter_array`](crate::iter_array)
+pub struct ArrayIter<'de, T> {
+    /// The stream to deserialize from
+    stream: CharStream<'de>,
+
+    /// The options controlling deserialization
+    options: DeserializeOptions,
+
+    /// The index of the next element
+    index: usize,
+
+    /// How far through the array the iterator is
+    state: State,
+
+    /// The type of the elements
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'de, T: Deserialize<'de>> ArrayIter<'de, T> {
+    /// Creates a new [`ArrayIter`] over the array in `bytes`
+    pub(super) fn new(bytes: &'de [u8]) -> Self {
+        ArrayIter {
+            stream: CharStream::new(bytes),
+            options: DeserializeOptions::new(),
+            index: 0,
+            state: State::Start,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the index of the next element
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Deserializes the next element, returning [`None`] at the end of the array
+    fn next_element(&mut self) -> Result<'de, Option<T>> {
+        if self.state == State::Start {
+            skip_whitespace(&mut self.stream)?;
+            expect(&mut self.stream, '[', "an array")?;
+            self.state = State::Items;
+        }
+
+        let mut list = ListDeserializer::resume(&mut self.stream, self.options, self.index);
+        let element = list.next_item()?;
+        self.index = list.index();
+
+        if element.is_none() {
+            close(&mut self.stream)?;
+        }
+        Ok(element)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Iterator for ArrayIter<'de, T> {
+    type Item = Result<'de, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == State::Done {
+            return None;
+        }
+
+        match self.next_element() {
+            Ok(Some(element)) => Some(Ok(element)),
+            Ok(None) => {
+                self.state = State::Done;
+                None
+            }
+            Err(mut error) => {
+                self.state = State::Done;
+                error.set_offset_from(
+                    self.stream.bytes(),
+                    self.stream.position(),
+                    self.stream.offset(),
+                );
+                Some(Err(error))
+            }
+        }
+    }
+}
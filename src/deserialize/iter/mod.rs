@@ -0,0 +1,97 @@
+use crate::{
+    deserialize::{skip_whitespace, CharStream, Result},
+    DeserializeError,
+};
+use data_format::Deserialize;
+
+mod array;
+mod object;
+#[cfg(not(feature = "no_std"))]
+mod read;
+
+pub use array::ArrayIter;
+pub use object::ObjectIter;
+#[cfg(not(feature = "no_std"))]
+pub use read::{ArrayReaderIter, ObjectReaderIter};
+
+/// Lazily deserializes each element of the top-level array in `input` as `T`, one at a time.
+///
+/// The whole input must be in memory, see [`iter_array_from_reader`] to read it as it is
+/// deserialized instead. Errors have the index of the element they occurred in at the start of
+/// their path. Iteration stops after the first error.
+pub fn iter_array<'de, T: Deserialize<'de>>(
+    input: &'de (impl AsRef<[u8]> + ?Sized),
+) -> ArrayIter<'de, T> {
+    ArrayIter::new(input.as_ref())
+}
+
+/// Lazily deserializes each entry of the top-level object in `input` as a key and a `V`, one at
+/// a time.
+///
+/// The whole input must be in memory, see [`iter_object_from_reader`] to read it as it is
+/// deserialized instead. Errors have the key of the entry they occurred in at the start of their path. Iteration stops
+/// after the first error.
+pub fn iter_object<'de, V: Deserialize<'de>>(
+    input: &'de (impl AsRef<[u8]> + ?Sized),
+) -> ObjectIter<'de, V> {
+    ObjectIter::new(input.as_ref())
+}
+
+/// Reads the top-level array in `reader` one element at a time, deserializing each as `T`.
+///
+/// Only the element being deserialized is held in memory, so arrays much larger than memory can
+/// be read. Each element is deserialized from a reused buffer, so `T` can't borrow from the input.
+/// Errors are positioned relative to the whole input and have the index of the element they
+/// occurred in at the start of their path. Iteration stops after the first error.
+#[cfg(not(feature = "no_std"))]
+pub fn iter_array_from_reader<T, R>(reader: R) -> ArrayReaderIter<R, T>
+where
+    T: for<'de> Deserialize<'de>,
+    R: std::io::BufRead,
+{
+    ArrayReaderIter::new(reader)
+}
+
+/// Reads the top-level object in `reader` one entry at a time, deserializing each value as `V`.
+///
+/// Only the entry being deserialized is held in memory, as with [`iter_array_from_reader`].
+/// Errors have the key of the entry they occurred in at the start of their path. Iteration stops
+/// after the first error.
+#[cfg(not(feature = "no_std"))]
+pub fn iter_object_from_reader<V, R>(reader: R) -> ObjectReaderIter<R, V>
+where
+    V: for<'de> Deserialize<'de>,
+    R: std::io::BufRead,
+{
+    ObjectReaderIter::new(reader)
+}
+
+/// How far through the document an iterator is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// The opening bracket hasn't been read
+    Start,
+
+    /// Elements or entries are being read
+    Items,
+
+    /// The document has ended or an error occurred
+    Done,
+}
+
+/// Moves past the closing bracket which is next on `stream`, validating that nothing but
+/// whitespace follows it
+fn close<'de>(stream: &mut CharStream<'de>) -> Result<'de, ()> {
+    stream.next().unwrap();
+    skip_whitespace(stream)?;
+
+    match stream.peek_pos() {
+        Ok(None) => Ok(()),
+        Ok(Some((c, position))) => Err(DeserializeError::unexpected(
+            c,
+            "the end of the JSON",
+            position,
+        )),
+        Err(error) => Err(DeserializeError::new(error, stream.position())),
+    }
+}
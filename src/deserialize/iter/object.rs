@@ -0,0 +1,102 @@
+use super::{close, State};
+use crate::{
+    deserialize::{expect, skip_whitespace, CharStream, MapDeserializer, Result},
+    DeserializeOptions,
+};
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, collections::BTreeMap};
+use core::marker::PhantomData;
+use data_format::{Deserialize, MapDeserializer as _};
+#[cfg(not(feature = "no_std"))]
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// An iterator over the entries of a top-level JSON object, created by
+/// [`iter_object`](crate::iter_object)
+pub struct ObjectIter<'de, V> {
+    /// The stream to deserialize from
+    stream: CharStream<'de>,
+
+    /// The options controlling deserialization
+    options: DeserializeOptions,
+
+    /// The index of the next entry
+    index: usize,
+
+    /// The keys used for enforcing the duplicate key policy
+    keys: BTreeMap<Cow<'de, str>, usize>,
+
+    /// How far through the object the iterator is
+    state: State,
+
+    /// The type of the values
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<'de, V: Deserialize<'de>> ObjectIter<'de, V> {
+    /// Creates a new [`ObjectIter`] over the object in `bytes`
+    pub(super) fn new(bytes: &'de [u8]) -> Self {
+        ObjectIter {
+            stream: CharStream::new(bytes),
+            options: DeserializeOptions::new(),
+            index: 0,
+            keys: BTreeMap::new(),
+            state: State::Start,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the index of the next entry
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Deserializes the next entry, returning [`None`] at the end of the object
+    fn next_entry(&mut self) -> Result<'de, Option<(Cow<'de, str>, V)>> {
+        if self.state == State::Start {
+            skip_whitespace(&mut self.stream)?;
+            expect(&mut self.stream, '{', "an object")?;
+            self.state = State::Items;
+        }
+
+        let keys = core::mem::take(&mut self.keys);
+        let mut map = MapDeserializer::resume(&mut self.stream, self.options, self.index, keys);
+        let entry = match map.next_key() {
+            Ok(Some(key)) => map.next_value().map(|value| Some((key, value))),
+            Ok(None) => Ok(None),
+            Err(error) => Err(error),
+        };
+        (self.index, self.keys) = map.into_state();
+
+        if matches!(entry, Ok(None)) {
+            close(&mut self.stream)?;
+        }
+        entry
+    }
+}
+
+impl<'de, V: Deserialize<'de>> Iterator for ObjectIter<'de, V> {
+    type Item = Result<'de, (Cow<'de, str>, V)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == State::Done {
+            return None;
+        }
+
+        match self.next_entry() {
+            Ok(Some(entry)) => Some(Ok(entry)),
+            Ok(None) => {
+                self.state = State::Done;
+                None
+            }
+            Err(mut error) => {
+                self.state = State::Done;
+                error.set_offset_from(
+                    self.stream.bytes(),
+                    self.stream.position(),
+                    self.stream.offset(),
+                );
+                Some(Err(error))
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use super::Source;
+use crate::{
+    deserialize::{iter::State, Result},
+    PathElement,
+};
+use core::marker::PhantomData;
+use data_format::Deserialize;
+use std::io::BufRead;
+
+/// An iterator over the elements of a top-level JSON array read from a reader, created by
+/// [`iter_array_from_reader`](crate::iter_array_from_reader)
+pub struct ArrayReaderIter<R, T> {
+    /// The source of the array
+    source: Source<R>,
+
+    /// The index of the next element
+    index: usize,
+
+    /// How far through the array the iterator is
+    state: State,
+
+    /// The type of the elements
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<R: BufRead, T: for<'de> Deserialize<'de>> ArrayReaderIter<R, T> {
+    /// Creates a new [`ArrayReaderIter`] over the array read from `reader`
+    pub(in crate::deserialize::iter) fn new(reader: R) -> Self {
+        ArrayReaderIter {
+            source: Source::new(reader),
+            index: 0,
+            state: State::Start,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the index of the next element
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Reads and deserializes the next element, returning [`None`] at the end of the array
+    fn next_element(&mut self) -> Result<'static, Option<T>> {
+        self.source.skip_whitespace()?;
+        if self.state == State::Start {
+            self.source.expect(b'[', "an array")?;
+            self.source.skip_whitespace()?;
+            self.state = State::Items;
+
+            if self.source.peek()? == Some(b']') {
+                self.source.close()?;
+                return Ok(None);
+            }
+        } else {
+            match self.source.peek()? {
+                Some(b']') => {
+                    self.source.close()?;
+                    return Ok(None);
+                }
+                Some(b',') => {
+                    self.source.bump();
+                    self.source.skip_whitespace()?;
+                }
+                _ => return Err(self.source.unexpected("',' or ']'")),
+            }
+        }
+
+        let element = self
+            .source
+            .read_value(Some(PathElement::Index(self.index)))?;
+        self.index += 1;
+        Ok(Some(element))
+    }
+}
+
+impl<R: BufRead, T: for<'de> Deserialize<'de>> Iterator for ArrayReaderIter<R, T> {
+    type Item = Result<'static, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == State::Done {
+            return None;
+        }
+
+        match self.next_element() {
+            Ok(Some(element)) => Some(Ok(element)),
+            Ok(None) => {
+                self.state = State::Done;
+                None
+            }
+            Err(error) => {
+                self.state = State::Done;
+                Some(Err(error))
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use lct_streams::Position;
+
+mod array;
+mod object;
+mod source;
+
+pub use array::ArrayReaderIter;
+pub use object::ObjectReaderIter;
+
+/// Reads a top-level array or object from a reader, copying one value at a time into a reused
+/// buffer so only the value being deserialized is held in memory
+struct Source<R> {
+    /// The source of the document
+    reader: R,
+
+    /// The bytes of the most recently read value
+    buffer: Vec<u8>,
+
+    /// The position of the next byte of the document
+    position: Position,
+
+    /// The byte offset of the next byte of the document
+    offset: usize,
+}
+
+/// What kind of value is being read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    /// A string, which ends at its closing '"'
+    String,
+
+    /// An array or object, which ends at the bracket bringing it back to a depth of 0
+    Container(usize),
+
+    /// A number or a literal, which ends before the next whitespace or delimiter
+    Scalar,
+}
@@ -0,0 +1,105 @@
+use super::Source;
+use crate::{
+    deserialize::{iter::State, Result},
+    PathElement,
+};
+use core::marker::PhantomData;
+use data_format::Deserialize;
+use std::io::BufRead;
+
+/// An iterator over the entries of a top-level JSON object read from a reader, created by
+/// [`iter_object_from_reader`](crate::iter_object_from_reader)
+pub struct ObjectReaderIter<R, V> {
+    /// The source of the object
+    source: Source<R>,
+
+    /// The index of the next entry
+    index: usize,
+
+    /// How far through the object the iterator is
+    state: State,
+
+    /// The type of the values
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<R: BufRead, V: for<'de> Deserialize<'de>> ObjectReaderIter<R, V> {
+    /// Creates a new [`ObjectReaderIter`] over the object read from `reader`
+    pub(in crate::deserialize::iter) fn new(reader: R) -> Self {
+        ObjectReaderIter {
+            source: Source::new(reader),
+            index: 0,
+            state: State::Start,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets the index of the next entry
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Reads and deserializes the next entry, returning [`None`] at the end of the object
+    fn next_entry(&mut self) -> Result<'static, Option<(String, V)>> {
+        self.source.skip_whitespace()?;
+        if self.state == State::Start {
+            self.source.expect(b'{', "an object")?;
+            self.source.skip_whitespace()?;
+            self.state = State::Items;
+
+            if self.source.peek()? == Some(b'}') {
+                self.source.close()?;
+                return Ok(None);
+            }
+        } else {
+            match self.source.peek()? {
+                Some(b'}') => {
+                    self.source.close()?;
+                    return Ok(None);
+                }
+                Some(b',') => {
+                    self.source.bump();
+                    self.source.skip_whitespace()?;
+                }
+                _ => return Err(self.source.unexpected("',' or '}'")),
+            }
+        }
+
+        if self.source.peek()? != Some(b'\"') {
+            return Err(self.source.unexpected("a string"));
+        }
+        let key: String = self.source.read_value(None)?;
+
+        self.source.skip_whitespace()?;
+        self.source.expect(b':', "':'")?;
+        self.source.skip_whitespace()?;
+
+        let value = self
+            .source
+            .read_value(Some(PathElement::Name(key.clone())))?;
+        self.index += 1;
+        Ok(Some((key, value)))
+    }
+}
+
+impl<R: BufRead, V: for<'de> Deserialize<'de>> Iterator for ObjectReaderIter<R, V> {
+    type Item = Result<'static, (String, V)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == State::Done {
+            return None;
+        }
+
+        match self.next_entry() {
+            Ok(Some(entry)) => Some(Ok(entry)),
+            Ok(None) => {
+                self.state = State::Done;
+                None
+            }
+            Err(error) => {
+                self.state = State::Done;
+                Some(Err(error))
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use super::{Source, ValueKind};
+use crate::{
+    deserialize::{advance, push::is_delimiter, scan, Result},
+    DeserializeError, DeserializeErrorKind, PathElement,
+};
+use data_format::Deserialize;
+use lct_streams::Position;
+use std::io::BufRead;
+
+impl<R: BufRead> Source<R> {
+    /// Creates a new [`Source`] at the start of `reader`
+    pub(super) fn new(reader: R) -> Self {
+        Source {
+            reader,
+            buffer: Vec::new(),
+            position: Position::new(1, 1),
+            offset: 0,
+        }
+    }
+
+    /// Gets the next byte of the document without moving past it
+    pub(super) fn peek(&mut self) -> Result<'static, Option<u8>> {
+        match self.reader.fill_buf() {
+            Ok(bytes) => Ok(bytes.first().copied()),
+            Err(error) => Err(self.io_error(error)),
+        }
+    }
+
+    /// Moves past the next byte of the document, which must be an ASCII character other than a
+    /// line break
+    pub(super) fn bump(&mut self) {
+        self.reader.consume(1);
+        self.position = Position::new(self.position.line(), self.position.column() + 1);
+        self.offset += 1;
+    }
+
+    /// Moves past any whitespace
+    pub(super) fn skip_whitespace(&mut self) -> Result<'static, ()> {
+        loop {
+            let bytes = match self.reader.fill_buf() {
+                Ok(bytes) => bytes,
+                Err(error) => return Err(self.io_error(error)),
+            };
+
+            let length = scan::whitespace_len(bytes);
+            let more = length == bytes.len() && length > 0;
+            self.position = advance(self.position, &bytes[..length]);
+            self.offset += length;
+            self.reader.consume(length);
+
+            if !more {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Moves past `expected`, which must be the next byte
+    pub(super) fn expect(&mut self, expected: u8, message: &'static str) -> Result<'static, ()> {
+        match self.peek()? {
+            Some(byte) if byte == expected => {
+                self.bump();
+                Ok(())
+            }
+            _ => Err(self.unexpected(message)),
+        }
+    }
+
+    /// Moves past the closing bracket which is next, validating that nothing but whitespace
+    /// follows it
+    pub(super) fn close(&mut self) -> Result<'static, ()> {
+        self.bump();
+        self.skip_whitespace()?;
+        match self.peek()? {
+            Some(_) => Err(self.unexpected("the end of the JSON")),
+            None => Ok(()),
+        }
+    }
+
+    /// Creates an error for the next character of the document when `expected` was expected
+    pub(super) fn unexpected(&mut self, expected: &'static str) -> DeserializeError<'static> {
+        let bytes = match self.reader.fill_buf() {
+            Ok(bytes) => bytes,
+            Err(error) => return self.io_error(error),
+        };
+
+        let error = match bytes.utf8_chunks().next() {
+            None => DeserializeError::new(DeserializeErrorKind::UnexpectedEndOfJSON, self.position),
+            Some(chunk) => DeserializeError::unexpected(
+                chunk.valid().chars().next().unwrap_or('\u{FFFD}'),
+                expected,
+                self.position,
+            ),
+        };
+        error.with_offset(self.offset)
+    }
+
+    /// Copies the next value of the document into the buffer, then deserializes it as a `T`
+    /// whose errors have `element` at the start of their path
+    pub(super) fn read_value<T: for<'de> Deserialize<'de>>(
+        &mut self,
+        element: Option<PathElement>,
+    ) -> Result<'static, T> {
+        let (start, start_offset) = (self.position, self.offset);
+        self.copy_value()?;
+
+        crate::from_bytes::<T>(&self.buffer).map_err(|error| {
+            let mut error = error.into_owned();
+            error.rebase(start, start_offset);
+            if let Some(element) = element {
+                error.push_path(element);
+            }
+            error
+        })
+    }
+
+    /// Copies the next value of the document into the buffer. A value left incomplete by the end
+    /// of the document is copied as it is, so deserializing it reports the error.
+    fn copy_value(&mut self) -> Result<'static, ()> {
+        self.buffer.clear();
+
+        let mut kind = match self.peek()? {
+            Some(b'\"') => ValueKind::String,
+            Some(b'[' | b'{') => ValueKind::Container(1),
+            Some(_) => ValueKind::Scalar,
+            None => return Ok(()),
+        };
+        let mut string = kind == ValueKind::String;
+        let mut escaped = false;
+        let mut first = true;
+
+        loop {
+            let bytes = match self.reader.fill_buf() {
+                Ok(bytes) => bytes,
+                Err(error) => return Err(self.io_error(error)),
+            };
+            if bytes.is_empty() {
+                return Ok(());
+            }
+
+            // The first byte was already looked at to find the kind of the value
+            let mut index = if first { 1 } else { 0 };
+            first = false;
+
+            let mut end = None;
+            while index < bytes.len() {
+                // Jump over the bytes in a string or container which can't change the scan
+                index += match (string, escaped, kind) {
+                    (true, false, _) => scan::string_len(&bytes[index..]),
+                    (false, _, ValueKind::Container(_)) => scan::structural_len(&bytes[index..]),
+                    _ => 0,
+                };
+                if index == bytes.len() {
+                    break;
+                }
+
+                let byte = bytes[index];
+                if string {
+                    index += 1;
+                    if escaped {
+                        escaped = false;
+                    } else if byte == b'\\' {
+                        escaped = true;
+                    } else if byte == b'\"' {
+                        string = false;
+                        if kind == ValueKind::String {
+                            end = Some(index);
+                            break;
+                        }
+                    }
+                    continue;
+                }
+
+                match (kind, byte) {
+                    (ValueKind::Scalar, _) if is_delimiter(byte) => {
+                        end = Some(index);
+                        break;
+                    }
+                    (ValueKind::Container(_), b'\"') => string = true,
+                    (ValueKind::Container(1), b']' | b'}') => {
+                        end = Some(index + 1);
+                        break;
+                    }
+                    (ValueKind::Container(depth), b'[' | b'{') => {
+                        kind = ValueKind::Container(depth + 1)
+                    }
+                    (ValueKind::Container(depth), b']' | b'}') => {
+                        kind = ValueKind::Container(depth - 1)
+                    }
+                    _ => {}
+                }
+                index += 1;
+            }
+
+            let length = end.unwrap_or(bytes.len());
+            self.buffer.extend_from_slice(&bytes[..length]);
+            self.position = advance(self.position, &bytes[..length]);
+            self.offset += length;
+            self.reader.consume(length);
+
+            if end.is_some() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Creates an error for failing to read the document
+    fn io_error(&self, error: std::io::Error) -> DeserializeError<'static> {
+        DeserializeError::new(DeserializeErrorKind::Io(error), self.position)
+            .with_offset(self.offset)
+    }
+}
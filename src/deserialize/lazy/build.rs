@@ -0,0 +1,258 @@
+use super::{
+    scan::{skip_whitespace, string_end},
+    Node,
+};
+use crate::{
+    deserialize::{advance, number, string, CharStream, Result},
+    DeserializeError, DeserializeErrorKind,
+};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use lct_streams::Position;
+
+/// Records the tape of a [`LazyDocument`](crate::LazyDocument) while validating its input
+pub(super) struct Builder<'de> {
+    /// The input being recorded
+    input: &'de str,
+
+    /// The tape recorded so far
+    tape: Vec<Node>,
+
+    /// The tape indices of the arrays and objects which haven't been closed yet
+    stack: Vec<usize>,
+
+    /// What is expected next in the input
+    expect: Expect,
+}
+
+/// What a [`Builder`] expects next in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expect {
+    /// A value
+    Value,
+
+    /// A value or the ']' ending an empty array
+    ValueOrEnd,
+
+    /// A key in an object
+    Key,
+
+    /// A key or the '}' ending an empty object
+    KeyOrEnd,
+
+    /// The ':' after a key
+    Colon,
+
+    /// The ',' after an element or the bracket closing its container
+    SeparatorOrEnd,
+
+    /// The end of the input
+    End,
+}
+
+impl<'de> Builder<'de> {
+    /// Creates a new [`Builder`] over `input`
+    pub(super) fn new(input: &'de str) -> Self {
+        Builder {
+            input,
+            tape: Vec::new(),
+            stack: Vec::new(),
+            expect: Expect::Value,
+        }
+    }
+
+    /// Records the tape over the whole input
+    pub(super) fn build(mut self) -> Result<'de, Vec<Node>> {
+        let bytes = self.input.as_bytes();
+
+        let mut offset = 0;
+        loop {
+            offset = skip_whitespace(bytes, offset);
+            let byte = match bytes.get(offset) {
+                Some(byte) => *byte,
+                None if self.expect == Expect::End => return Ok(self.tape),
+                None => {
+                    return Err(DeserializeError::new(
+                        DeserializeErrorKind::UnexpectedEndOfJSON,
+                        self.position(offset),
+                    ))
+                }
+            };
+
+            offset = match (self.expect, byte) {
+                (Expect::Value | Expect::ValueOrEnd, b'{' | b'[') => {
+                    self.stack.push(self.tape.len());
+                    self.push(offset);
+                    self.expect = match byte {
+                        b'{' => Expect::KeyOrEnd,
+                        _ => Expect::ValueOrEnd,
+                    };
+                    offset + 1
+                }
+                (Expect::ValueOrEnd | Expect::SeparatorOrEnd, b']')
+                | (Expect::KeyOrEnd | Expect::SeparatorOrEnd, b'}')
+                    if self.closes(byte) =>
+                {
+                    let open = self.stack.pop().unwrap();
+                    self.push(offset);
+                    self.tape[open].next = self.tape.len();
+                    self.after_value();
+                    offset + 1
+                }
+                (Expect::Value | Expect::ValueOrEnd | Expect::Key | Expect::KeyOrEnd, b'"') => {
+                    self.push(offset);
+                    let end = self.string(offset)?;
+                    match self.expect {
+                        Expect::Key | Expect::KeyOrEnd => self.expect = Expect::Colon,
+                        _ => self.after_value(),
+                    }
+                    end
+                }
+                (Expect::Value | Expect::ValueOrEnd, b'-' | b'0'..=b'9') => {
+                    self.push(offset);
+                    let end = self.number(offset)?;
+                    self.after_value();
+                    end
+                }
+                (Expect::Value | Expect::ValueOrEnd, b't' | b'f' | b'n') => {
+                    self.push(offset);
+                    let end = self.literal(offset)?;
+                    self.after_value();
+                    end
+                }
+                (Expect::Colon, b':') => {
+                    self.push(offset);
+                    self.expect = Expect::Value;
+                    offset + 1
+                }
+                (Expect::SeparatorOrEnd, b',') => {
+                    self.push(offset);
+                    self.expect = match self.in_object() {
+                        true => Expect::Key,
+                        false => Expect::Value,
+                    };
+                    offset + 1
+                }
+                _ => return Err(self.unexpected(offset)),
+            };
+        }
+    }
+
+    /// Adds an entry for the character at `offset` to the tape
+    fn push(&mut self, offset: usize) {
+        self.tape.push(Node {
+            offset,
+            next: self.tape.len() + 1,
+        });
+    }
+
+    /// Sets what is expected after a complete value
+    fn after_value(&mut self) {
+        self.expect = match self.stack.is_empty() {
+            true => Expect::End,
+            false => Expect::SeparatorOrEnd,
+        };
+    }
+
+    /// Checks if the innermost open container is an object
+    fn in_object(&self) -> bool {
+        match self.stack.last() {
+            Some(open) => self.input.as_bytes()[self.tape[*open].offset] == b'{',
+            None => false,
+        }
+    }
+
+    /// Checks if `close` closes the innermost open container
+    fn closes(&self, close: u8) -> bool {
+        !self.stack.is_empty() && self.in_object() == (close == b'}')
+    }
+
+    /// Validates the string starting at `offset`, returning the offset after it
+    fn string(&self, offset: usize) -> Result<'de, usize> {
+        let bytes = self.input.as_bytes();
+        let (end, escaped) = string_end(bytes, offset).ok_or_else(|| {
+            DeserializeError::new(
+                DeserializeErrorKind::UnexpectedEndOfJSON,
+                self.position(bytes.len()),
+            )
+        })?;
+
+        if escaped {
+            // Escapes are only checked here, the decoded string is thrown away
+            let mut stream = CharStream::new(&bytes[offset..]);
+            string::deserialize_string(&mut stream).map_err(|error| self.rebase(error, offset))?;
+        } else if let Some(index) = bytes[offset..end].iter().position(|byte| *byte < b' ') {
+            return Err(DeserializeError::unexpected(
+                bytes[offset + index] as char,
+                "a valid string",
+                self.position(offset + index),
+            ));
+        }
+
+        Ok(end + 1)
+    }
+
+    /// Validates the number starting at `offset`, returning the offset after it
+    fn number(&self, offset: usize) -> Result<'de, usize> {
+        let mut stream = CharStream::new(&self.input.as_bytes()[offset..]);
+        number::skip_number(&mut stream).map_err(|error| self.rebase(error, offset))?;
+        Ok(offset + stream.offset())
+    }
+
+    /// Validates the `true`, `false` or `null` starting at `offset`, returning the offset after it
+    fn literal(&self, offset: usize) -> Result<'de, usize> {
+        let word = match self.input.as_bytes()[offset] {
+            b't' => "true",
+            b'f' => "false",
+            _ => "null",
+        };
+
+        for (index, expected) in word.bytes().enumerate() {
+            match self.input.as_bytes().get(offset + index) {
+                Some(byte) if *byte == expected => {}
+                Some(_) => {
+                    let c = self.input[offset + index..].chars().next().unwrap();
+                    return Err(DeserializeError::unexpected(
+                        c,
+                        word,
+                        self.position(offset + index),
+                    ));
+                }
+                None => {
+                    return Err(DeserializeError::new(
+                        DeserializeErrorKind::UnexpectedEndOfJSON,
+                        self.position(offset + index),
+                    ))
+                }
+            }
+        }
+
+        Ok(offset + word.len())
+    }
+
+    /// Creates an error for the unexpected character at `offset`
+    fn unexpected(&self, offset: usize) -> DeserializeError<'de> {
+        let expected = match self.expect {
+            Expect::Value | Expect::ValueOrEnd => "a value",
+            Expect::Key | Expect::KeyOrEnd => "a string",
+            Expect::Colon => "a ':'",
+            Expect::SeparatorOrEnd if self.in_object() => "',' or '}'",
+            Expect::SeparatorOrEnd => "',' or ']'",
+            Expect::End => "the end of the JSON",
+        };
+
+        let c = self.input[offset..].chars().next().unwrap();
+        DeserializeError::unexpected(c, expected, self.position(offset))
+    }
+
+    /// Moves `error` from being relative to a value starting at `offset` to the whole input
+    fn rebase(&self, mut error: DeserializeError<'de>, offset: usize) -> DeserializeError<'de> {
+        error.rebase(self.position(offset), offset);
+        error
+    }
+
+    /// Gets the position of `offset` in the input
+    fn position(&self, offset: usize) -> Position {
+        advance(Position::new(1, 1), &self.input.as_bytes()[..offset])
+    }
+}
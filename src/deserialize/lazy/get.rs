@@ -0,0 +1,11 @@
+use crate::{LazyDocument, LazyValue};
+
+impl<'de> LazyDocument<'de> {
+    /// Gets the root value of the document
+    pub fn root(&self) -> LazyValue<'_, 'de> {
+        LazyValue {
+            document: self,
+            index: 0,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+mod build;
+mod get;
+mod navigate;
+mod new;
+mod scan;
+
+/// A JSON document which is only decoded as it is navigated.
+///
+/// Creating the document makes a single pass over the input, validating it and recording the
+/// offset of each structural character and value into a flat tape. Values are decoded only when
+/// they are accessed, with strings borrowed from the input unless they contain escapes.
+#[derive(Debug, Clone)]
+pub struct LazyDocument<'de> {
+    /// The validated input
+    input: &'de [u8],
+
+    /// The structural characters and values in the input, in order
+    tape: Vec<Node>,
+}
+
+/// A value in a [`LazyDocument`]
+#[derive(Debug, Clone, Copy)]
+pub struct LazyValue<'a, 'de> {
+    /// The document containing the value
+    document: &'a LazyDocument<'de>,
+
+    /// The index of the value in the document's tape
+    index: usize,
+}
+
+/// An entry on the tape of a [`LazyDocument`]
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    /// The byte offset in the input of the character starting the entry
+    offset: usize,
+
+    /// The index of the entry after this one's value, which is past the closing bracket for
+    /// arrays and objects
+    next: usize,
+}
@@ -0,0 +1,168 @@
+use super::{
+    scan::{scalar_end, string_end},
+    Node,
+};
+use crate::{
+    deserialize::{advance, from_bytes, string, CharStream, Result},
+    LazyValue,
+};
+#[cfg(feature = "no_std")]
+use alloc::borrow::Cow;
+use data_format::Deserialize;
+use lct_streams::Position;
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+impl<'a, 'de> LazyValue<'a, 'de> {
+    /// Gets the value of `key` if this is an object containing it. If `key` appears more than
+    /// once, the first value is returned.
+    pub fn get(&self, key: &str) -> Option<LazyValue<'a, 'de>> {
+        if self.first() != b'{' || self.byte(self.index + 1) == b'}' {
+            return None;
+        }
+
+        // Each entry is a key, a ':' and a value, followed by a ',' or the closing '}'
+        let mut index = self.index + 1;
+        loop {
+            let value = self.at(index + 2);
+            if self.at(index).key_is(key) {
+                return Some(value);
+            }
+
+            let next = value.node().next;
+            if self.byte(next) == b'}' {
+                return None;
+            }
+
+            index = next + 1;
+        }
+    }
+
+    /// Gets the element at `index` if this is an array with more than `index` elements
+    pub fn index(&self, index: usize) -> Option<LazyValue<'a, 'de>> {
+        if self.first() != b'[' || self.byte(self.index + 1) == b']' {
+            return None;
+        }
+
+        let mut element = self.index + 1;
+        for _ in 0..index {
+            let next = self.at(element).node().next;
+            if self.byte(next) == b']' {
+                return None;
+            }
+
+            element = next + 1;
+        }
+
+        Some(self.at(element))
+    }
+
+    /// Gets the value as a string if it is one, borrowing it from the input unless it contains
+    /// escapes
+    pub fn as_str(&self) -> Option<Cow<'de, str>> {
+        let input = self.document.input;
+        let offset = self.node().offset;
+        let (end, escaped) = match self.first() {
+            b'"' => string_end(input, offset)?,
+            _ => return None,
+        };
+
+        if !escaped {
+            return core::str::from_utf8(&input[offset + 1..end])
+                .ok()
+                .map(Cow::Borrowed);
+        }
+
+        let mut stream = CharStream::new(&input[offset..]);
+        string::deserialize_string(&mut stream)
+            .ok()
+            .map(|(string, _)| string)
+    }
+
+    /// Gets the value as a number if it is one
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.first() {
+            b'-' | b'0'..=b'9' => core::str::from_utf8(self.raw()).ok()?.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Gets the value as a boolean if it is one
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.first() {
+            b't' => Some(true),
+            b'f' => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Checks if the value is null
+    pub fn is_null(&self) -> bool {
+        self.first() == b'n'
+    }
+
+    /// Checks if the value is an object
+    pub fn is_object(&self) -> bool {
+        self.first() == b'{'
+    }
+
+    /// Checks if the value is an array
+    pub fn is_array(&self) -> bool {
+        self.first() == b'['
+    }
+
+    /// Deserializes the value into `T`, decoding all of it at once
+    pub fn deserialize<T: Deserialize<'de>>(&self) -> Result<'de, T> {
+        let input = self.document.input;
+        let offset = self.node().offset;
+        from_bytes(self.raw()).map_err(|mut error| {
+            error.rebase(advance(Position::new(1, 1), &input[..offset]), offset);
+            error
+        })
+    }
+
+    /// Gets the text of the value in the input
+    pub fn raw(&self) -> &'de [u8] {
+        let input = self.document.input;
+        let offset = self.node().offset;
+        let end = match self.first() {
+            b'{' | b'[' => self.document.tape[self.node().next - 1].offset + 1,
+            b'"' => string_end(input, offset).unwrap().0 + 1,
+            _ => scalar_end(input, offset),
+        };
+
+        &input[offset..end]
+    }
+
+    /// Gets the entry for this value on the tape
+    fn node(&self) -> Node {
+        self.document.tape[self.index]
+    }
+
+    /// Gets the first byte of this value
+    fn first(&self) -> u8 {
+        self.byte(self.index)
+    }
+
+    /// Gets the first byte of the tape entry at `index`
+    fn byte(&self, index: usize) -> u8 {
+        self.document.input[self.document.tape[index].offset]
+    }
+
+    /// Gets the value at `index` on the tape
+    fn at(&self, index: usize) -> LazyValue<'a, 'de> {
+        LazyValue {
+            document: self.document,
+            index,
+        }
+    }
+
+    /// Checks if this key is `key`, only decoding it if it contains escapes
+    fn key_is(&self, key: &str) -> bool {
+        let offset = self.node().offset;
+        match string_end(self.document.input, offset) {
+            Some((end, false)) => &self.document.input[offset + 1..end] == key.as_bytes(),
+            _ => self.as_str().as_deref() == Some(key),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::build::Builder;
+use crate::{
+    deserialize::{advance, CharStream, Result},
+    DeserializeError, LazyDocument,
+};
+use lct_streams::Position;
+
+impl<'de> LazyDocument<'de> {
+    /// Creates a new [`LazyDocument`] over the JSON document in `string`
+    pub fn new(string: &'de str) -> Result<'de, Self> {
+        let tape = Builder::new(string).build().map_err(|mut error| {
+            error.set_offset_from(string.as_bytes(), Position::new(1, 1), 0);
+            error
+        })?;
+
+        Ok(LazyDocument {
+            input: string.as_bytes(),
+            tape,
+        })
+    }
+
+    /// Creates a new [`LazyDocument`] over the JSON document in `bytes`
+    pub fn from_bytes(bytes: &'de [u8]) -> Result<'de, Self> {
+        match core::str::from_utf8(bytes) {
+            Ok(string) => LazyDocument::new(string),
+            Err(error) => {
+                let valid = &bytes[..error.valid_up_to()];
+                let position = advance(Position::new(1, 1), valid);
+
+                let mut stream = CharStream::new(&bytes[valid.len()..]);
+                Err(DeserializeError::new(stream.next().unwrap_err(), position)
+                    .with_offset(valid.len()))
+            }
+        }
+    }
+}
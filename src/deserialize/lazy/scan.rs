@@ -0,0 +1,38 @@
+use crate::deserialize::scan;
+
+/// Gets the offset of the first byte at or after `offset` which isn't whitespace
+pub(super) fn skip_whitespace(bytes: &[u8], offset: usize) -> usize {
+    offset + scan::whitespace_len(&bytes[offset..])
+}
+
+/// Gets the offset of the '"' closing the string whose opening '"' is at `offset`, along with
+/// if the string contains any escapes
+pub(super) fn string_end(bytes: &[u8], offset: usize) -> Option<(usize, bool)> {
+    let mut escaped = false;
+    let mut current = offset + 1;
+    loop {
+        current += scan::string_len(bytes.get(current..)?);
+        match bytes.get(current)? {
+            b'"' => return Some((current, escaped)),
+            b'\\' => {
+                escaped = true;
+                current += 2;
+            }
+            _ => current += 1,
+        }
+    }
+}
+
+/// Gets the offset after the number or literal starting at `offset`
+pub(super) fn scalar_end(bytes: &[u8], offset: usize) -> usize {
+    bytes[offset..]
+        .iter()
+        .position(|byte| {
+            matches!(
+                byte,
+                b' ' | b'\t' | b'\n' | b'\r' | b',' | b':' | b'[' | b']' | b'{' | b'}' | b'"'
+            )
+        })
+        .map(|index| offset + index)
+        .unwrap_or(bytes.len())
+}
@@ -0,0 +1,13 @@
+use crate::JsonLinesReader;
+
+impl<R, T> JsonLinesReader<R, T> {
+    /// Gets the line number, starting at 1, of the most recently read record
+    pub fn line(&self) -> usize {
+        self.line_number
+    }
+
+    /// Takes the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    deserialize::{from_bytes, Result},
+    DeserializeError, DeserializeErrorKind, JsonLinesReader,
+};
+use data_format::Deserialize;
+use lct_streams::Position;
+use std::io::BufRead;
+
+impl<R: BufRead, T: for<'de> Deserialize<'de>> Iterator for JsonLinesReader<R, T> {
+    type Item = Result<'static, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            let length = match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(0) => {
+                    self.done = true;
+                    return None;
+                }
+                Ok(length) => length,
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(DeserializeErrorKind::Io(error).into()));
+                }
+            };
+
+            self.line_number += 1;
+            let offset = self.offset;
+            self.offset += length;
+
+            let record = self.line.strip_suffix(b"\n").unwrap_or(&self.line);
+            let record = record.strip_suffix(b"\r").unwrap_or(record);
+            if self.skip_blank_lines && record.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+
+            match from_bytes(record) {
+                Ok(value) => return Some(Ok(value)),
+                Err(_) if self.skip_invalid => continue,
+                Err(error) => {
+                    let mut error: DeserializeError<'static> = error.into_owned();
+                    error.rebase(Position::new(self.line_number, 1), offset);
+                    return Some(Err(error));
+                }
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,36 @@
+use core::marker::PhantomData;
+
+mod get;
+mod iter;
+mod new;
+mod set;
+
+/// Reads records from newline-delimited JSON (JSON Lines), deserializing each line as a `T`.
+///
+/// Each line is deserialized from a reused buffer, so `T` can't borrow from the input. Errors are
+/// positioned relative to the whole input, so their line is the line of the record.
+pub struct JsonLinesReader<R, T> {
+    /// The source of the lines
+    reader: R,
+
+    /// The bytes of the current line
+    line: Vec<u8>,
+
+    /// The number of lines read so far
+    line_number: usize,
+
+    /// The byte offset of the start of the next line
+    offset: usize,
+
+    /// Should lines containing only whitespace be skipped?
+    skip_blank_lines: bool,
+
+    /// Should records which can't be deserialized be skipped?
+    skip_invalid: bool,
+
+    /// Has reading stopped, either at the end of the input or after failing to read it?
+    done: bool,
+
+    /// The type of the records
+    _marker: PhantomData<fn() -> T>,
+}
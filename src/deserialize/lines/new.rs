@@ -0,0 +1,19 @@
+use crate::JsonLinesReader;
+use core::marker::PhantomData;
+use std::io::BufRead;
+
+impl<R: BufRead, T> JsonLinesReader<R, T> {
+    /// Creates a new [`JsonLinesReader`] which reads records from `reader`
+    pub fn new(reader: R) -> Self {
+        JsonLinesReader {
+            reader,
+            line: Vec::new(),
+            line_number: 0,
+            offset: 0,
+            skip_blank_lines: false,
+            skip_invalid: false,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::JsonLinesReader;
+
+impl<R, T> JsonLinesReader<R, T> {
+    /// Sets if lines containing only whitespace should be skipped instead of reported as errors
+    pub fn set_skip_blank_lines(&mut self, skip_blank_lines: bool) {
+        self.skip_blank_lines = skip_blank_lines;
+    }
+
+    /// Sets if records which can't be deserialized should be skipped instead of reported as
+    /// errors. Failures to read the input are always reported.
+    pub fn set_skip_invalid(&mut self, skip_invalid: bool) {
+        self.skip_invalid = skip_invalid;
+    }
+}
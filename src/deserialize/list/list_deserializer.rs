@@ -1,6 +1,6 @@
 use crate::{
     deserialize::{peek, skip_whitespace, Deserializer, ListDeserializer},
-    DeserializeError,
+    DeserializeError, PathElement,
 };
 use data_format::Deserialize;
 
@@ -26,11 +26,15 @@ impl<'a, 'de> data_format::ListDeserializer<'de> for ListDeserializer<'a, 'de> {
             }
         }
 
+        let index = self.index;
+        self.index += 1;
+
         let position = self.stream.position();
-        T::deserialize(Deserializer::new(self.stream))
+        T::deserialize(Deserializer::new(self.stream, self.options, self.last_keys))
             .map(|ret| Some(ret))
             .map_err(|mut error| {
-                error.set_position(position);
+                error.set_span(position, self.stream.position());
+                error.push_path(PathElement::Index(index));
                 error
             })
     }
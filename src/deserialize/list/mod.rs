@@ -1,4 +1,7 @@
-use lct_streams::SliceByteCharStream;
+use crate::{
+    deserialize::{CharStream, LastKeys},
+    DeserializeOptions,
+};
 
 mod list_deserializer;
 mod new;
@@ -6,8 +9,17 @@ mod new;
 /// Deserializes a JSON array into a list
 pub(super) struct ListDeserializer<'a, 'de> {
     /// The stream to serialize from
-    stream: &'a mut SliceByteCharStream<'de>,
+    stream: &'a mut CharStream<'de>,
+
+    /// The options controlling deserialization
+    options: DeserializeOptions,
 
     /// Is the next element the first in the list?
     first: bool,
+
+    /// The index of the next element in the list
+    index: usize,
+
+    /// The keys of the objects in the list, if an enclosing object has scanned them
+    last_keys: Option<&'a LastKeys<'de>>,
 }
@@ -1,12 +1,41 @@
-use crate::deserialize::ListDeserializer;
-use lct_streams::SliceByteCharStream;
+use crate::{
+    deserialize::{CharStream, LastKeys, ListDeserializer},
+    DeserializeOptions,
+};
 
 impl<'a, 'de> ListDeserializer<'a, 'de> {
     /// Creates a new [`ListDeserializer`]
-    pub(crate) fn new(stream: &'a mut SliceByteCharStream<'de>) -> Self {
+    pub(crate) fn new(
+        stream: &'a mut CharStream<'de>,
+        options: DeserializeOptions,
+        last_keys: Option<&'a LastKeys<'de>>,
+    ) -> Self {
         ListDeserializer {
             stream,
+            options,
             first: true,
+            index: 0,
+            last_keys,
         }
     }
+
+    /// Creates a [`ListDeserializer`] which continues a list after its first `index` elements
+    pub(crate) fn resume(
+        stream: &'a mut CharStream<'de>,
+        options: DeserializeOptions,
+        index: usize,
+    ) -> Self {
+        ListDeserializer {
+            stream,
+            options,
+            first: index == 0,
+            index,
+            last_keys: None,
+        }
+    }
+
+    /// Gets the index of the next element in the list
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
 }
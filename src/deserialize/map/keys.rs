@@ -0,0 +1,68 @@
+use crate::{
+    deserialize::{
+        expect, skip_value, skip_whitespace, string::deserialize_string, CharStream,
+        MapDeserializer, Result,
+    },
+    DeserializeError, DeserializeErrorKind, DuplicateKeys,
+};
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, collections::btree_map::Entry, string::String};
+use lct_streams::Position;
+#[cfg(not(feature = "no_std"))]
+use std::{borrow::Cow, collections::btree_map::Entry};
+
+impl<'a, 'de> MapDeserializer<'a, 'de> {
+    /// Applies the duplicate key policy to the key which was just deserialized starting at `start`,
+    /// returning if its entry should be passed on or skipped
+    pub(super) fn keep_entry(&mut self, start: usize, position: Position) -> Result<'de, bool> {
+        let index = self.index;
+        self.index += 1;
+
+        let policy = self.options.duplicate_keys();
+        if policy == DuplicateKeys::Allow {
+            return Ok(true);
+        }
+
+        let name = MapDeserializer::key_name(&self.stream.bytes()[start..self.stream.offset()]);
+        match policy {
+            DuplicateKeys::Allow => Ok(true),
+            DuplicateKeys::Reject => match self.keys.entry(name) {
+                Entry::Vacant(entry) => {
+                    entry.insert(index);
+                    Ok(true)
+                }
+                Entry::Occupied(entry) => Err(DeserializeError::new(
+                    DeserializeErrorKind::DuplicateKey(entry.remove_entry().0),
+                    position,
+                )),
+            },
+            DuplicateKeys::KeepFirst => match self.keys.entry(name) {
+                Entry::Vacant(entry) => {
+                    entry.insert(index);
+                    Ok(true)
+                }
+                Entry::Occupied(_) => Ok(false),
+            },
+            DuplicateKeys::KeepLast => Ok(self
+                .keys
+                .get(&name)
+                .map(|last| *last == index)
+                .unwrap_or(true)),
+        }
+    }
+
+    /// Advances the stream past the value of an entry whose key has been skipped
+    pub(super) fn skip_entry_value(&mut self) -> Result<'de, ()> {
+        skip_whitespace(self.stream)?;
+        expect(self.stream, ':', "a ':'")?;
+        skip_value(self.stream)
+    }
+
+    /// Decodes the key found in `raw`, the exact bytes that a key was deserialized from
+    pub(super) fn key_name(raw: &'de [u8]) -> Cow<'de, str> {
+        match deserialize_string(&mut CharStream::new(raw)) {
+            Ok((name, _)) => name,
+            Err(_) => String::from_utf8_lossy(raw),
+        }
+    }
+}
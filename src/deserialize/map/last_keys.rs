@@ -0,0 +1,124 @@
+use crate::{
+    deserialize::{
+        expect, peek, skip_value, skip_whitespace, string::deserialize_string, CharStream, Result,
+    },
+    DeserializeError,
+};
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, collections::BTreeMap};
+#[cfg(not(feature = "no_std"))]
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// The keys of every object in a value mapped to the index of their last entry, for
+/// [`DuplicateKeys::KeepLast`](crate::DuplicateKeys::KeepLast). Each object is found by the offset
+/// just after its '{', so the value is only scanned once however deeply its objects are nested.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LastKeys<'de> {
+    /// The keys of each object, by the offset just after its '{'
+    objects: BTreeMap<usize, BTreeMap<Cow<'de, str>, usize>>,
+}
+
+impl<'de> LastKeys<'de> {
+    /// Scans ahead over the object whose '{' was just taken from `stream`, without consuming it,
+    /// recording the keys of it and every object inside it.
+    ///
+    /// Scanning stops quietly at the first syntax error as deserializing the object will report it
+    /// at the correct position.
+    pub(crate) fn scan(stream: &CharStream<'de>) -> Self {
+        let base = stream.offset();
+        let mut last_keys = LastKeys::default();
+        let _ = last_keys.scan_object(&mut CharStream::new(&stream.bytes()[base..]), base);
+        last_keys
+    }
+
+    /// Gets the keys of the object whose '{' ends just before `offset`
+    pub(crate) fn get(&self, offset: usize) -> Option<&BTreeMap<Cow<'de, str>, usize>> {
+        self.objects.get(&offset)
+    }
+
+    /// Scans the entries of an object whose '{' was just taken from `stream`, which starts `base`
+    /// bytes into the input. The keys found are recorded even if the object is invalid.
+    fn scan_object(&mut self, stream: &mut CharStream<'de>, base: usize) -> Result<'de, ()> {
+        let offset = base + stream.offset();
+        let mut keys = BTreeMap::new();
+        let result = self.scan_entries(stream, base, &mut keys);
+        self.objects.insert(offset, keys);
+        result
+    }
+
+    /// Scans the entries of an object into `keys`, up to and including its '}'
+    fn scan_entries(
+        &mut self,
+        stream: &mut CharStream<'de>,
+        base: usize,
+        keys: &mut BTreeMap<Cow<'de, str>, usize>,
+    ) -> Result<'de, ()> {
+        let mut index = 0;
+        loop {
+            skip_whitespace(stream)?;
+            match peek(stream)? {
+                ('}', _) => {
+                    stream.next().unwrap();
+                    return Ok(());
+                }
+                (',', _) if index > 0 => {
+                    stream.next().unwrap();
+                    skip_whitespace(stream)?;
+                }
+                (c, pos) if index > 0 => {
+                    return Err(DeserializeError::unexpected(c, "',' or '}'", pos))
+                }
+                _ => {}
+            }
+
+            let (name, _) = deserialize_string(stream)?;
+            keys.insert(name, index);
+            index += 1;
+
+            skip_whitespace(stream)?;
+            expect(stream, ':', "a ':'")?;
+            self.scan_value(stream, base)?;
+        }
+    }
+
+    /// Scans over the next value, recording the keys of any objects in it
+    fn scan_value(&mut self, stream: &mut CharStream<'de>, base: usize) -> Result<'de, ()> {
+        skip_whitespace(stream)?;
+        match peek(stream)? {
+            ('{', _) => {
+                stream.next().unwrap();
+                self.scan_object(stream, base)
+            }
+            ('[', _) => {
+                stream.next().unwrap();
+                self.scan_elements(stream, base)
+            }
+            _ => skip_value(stream),
+        }
+    }
+
+    /// Scans the elements of an array whose '[' was just taken from `stream`, up to and including
+    /// its ']'
+    fn scan_elements(&mut self, stream: &mut CharStream<'de>, base: usize) -> Result<'de, ()> {
+        let mut first = true;
+        loop {
+            skip_whitespace(stream)?;
+            match peek(stream)? {
+                (']', _) => {
+                    stream.next().unwrap();
+                    return Ok(());
+                }
+                (',', _) if !first => {
+                    stream.next().unwrap();
+                }
+                (c, pos) if !first => {
+                    return Err(DeserializeError::unexpected(c, "',' or ']'", pos))
+                }
+                _ => {}
+            }
+
+            first = false;
+            self.scan_value(stream, base)?;
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     deserialize::{expect, peek, skip_whitespace, Deserializer, MapDeserializer},
-    DeserializeError,
+    DeserializeError, PathElement,
 };
 
 impl<'a, 'de> data_format::MapDeserializer<'de> for MapDeserializer<'a, 'de> {
@@ -9,30 +9,40 @@ impl<'a, 'de> data_format::MapDeserializer<'de> for MapDeserializer<'a, 'de> {
     fn next_key<K: data_format::Deserialize<'de>>(&mut self) -> Result<Option<K>, Self::Error> {
         assert!(self.next_key);
 
-        skip_whitespace(self.stream)?;
+        loop {
+            skip_whitespace(self.stream)?;
 
-        if self.first {
-            match peek(self.stream)? {
-                ('}', _) => return Ok(None),
-                _ => {}
-            }
+            if self.first {
+                match peek(self.stream)? {
+                    ('}', _) => return Ok(None),
+                    _ => {}
+                }
 
-            self.first = false;
-        } else {
-            match peek(self.stream)? {
-                (',', _) => {
-                    self.stream.next().unwrap();
+                self.first = false;
+            } else {
+                match peek(self.stream)? {
+                    (',', _) => {
+                        self.stream.next().unwrap();
+                    }
+                    ('}', _) => return Ok(None),
+                    (c, pos) => return Err(DeserializeError::unexpected(c, "',' or '}'", pos)),
                 }
-                ('}', _) => return Ok(None),
-                (c, pos) => return Err(DeserializeError::unexpected(c, "',' or '}'", pos)),
+
+                skip_whitespace(self.stream)?;
             }
 
-            skip_whitespace(self.stream)?;
-        }
+            let position = self.stream.position();
+            let start = self.stream.offset();
+            let key = K::deserialize(Deserializer::new(self.stream, self.options, None))?;
+            self.key = start..self.stream.offset();
 
-        self.next_key = false;
+            if self.keep_entry(start, position)? {
+                self.next_key = false;
+                return Ok(Some(key));
+            }
 
-        K::deserialize(Deserializer::new(self.stream)).map(|ret| Some(ret))
+            self.skip_entry_value()?;
+        }
     }
 
     fn next_value<V: data_format::Deserialize<'de>>(&mut self) -> Result<V, Self::Error> {
@@ -46,6 +56,17 @@ impl<'a, 'de> data_format::MapDeserializer<'de> for MapDeserializer<'a, 'de> {
 
         self.next_key = true;
 
-        V::deserialize(Deserializer::new(self.stream))
+        V::deserialize(Deserializer::new(
+            self.stream,
+            self.options,
+            self.last_keys.as_deref(),
+        ))
+        .map_err(|mut error| {
+            let key = &self.stream.bytes()[self.key.clone()];
+            error.push_path(PathElement::Name(
+                MapDeserializer::key_name(key).into_owned(),
+            ));
+            error
+        })
     }
 }
@@ -1,16 +1,43 @@
-use lct_streams::SliceByteCharStream;
+use crate::{deserialize::CharStream, DeserializeOptions};
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, collections::BTreeMap};
+use core::ops::Range;
+#[cfg(not(feature = "no_std"))]
+use std::{borrow::Cow, collections::BTreeMap};
 
+mod keys;
+mod last_keys;
 mod map_deserializer;
 mod new;
 
+pub(crate) use last_keys::LastKeys;
+
 /// Deserializes a JSON object into a map
 pub(super) struct MapDeserializer<'a, 'de> {
     /// The stream to deserialize from
-    stream: &'a mut SliceByteCharStream<'de>,
+    stream: &'a mut CharStream<'de>,
+
+    /// The options controlling deserialization
+    options: DeserializeOptions,
 
     /// Is the next item the first item in the object?
     first: bool,
 
     /// Should the next call be to `next_key`?
     next_key: bool,
+
+    /// The index of the next entry in the object
+    index: usize,
+
+    /// The range of the stream holding the most recently deserialized key
+    key: Range<usize>,
+
+    /// The keys used for enforcing the duplicate key policy. For
+    /// [`DuplicateKeys::KeepLast`](crate::DuplicateKeys::KeepLast) this maps each key to the index
+    /// of its last entry, otherwise it maps each key seen so far to the index of its first entry.
+    keys: BTreeMap<Cow<'de, str>, usize>,
+
+    /// The keys of this object and the objects inside it, if they have been scanned for
+    /// [`DuplicateKeys::KeepLast`](crate::DuplicateKeys::KeepLast)
+    last_keys: Option<Cow<'a, LastKeys<'de>>>,
 }
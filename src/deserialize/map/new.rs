@@ -1,13 +1,68 @@
-use crate::deserialize::MapDeserializer;
-use lct_streams::SliceByteCharStream;
+use crate::{
+    deserialize::{CharStream, LastKeys, MapDeserializer},
+    DeserializeOptions, DuplicateKeys,
+};
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, collections::BTreeMap};
+#[cfg(not(feature = "no_std"))]
+use std::{borrow::Cow, collections::BTreeMap};
 
 impl<'a, 'de> MapDeserializer<'a, 'de> {
-    /// Creates a new [`MapDeserializer`]
-    pub(crate) fn new(stream: &'a mut SliceByteCharStream<'de>) -> Self {
+    /// Creates a new [`MapDeserializer`] for the object whose '{' was just taken from `stream`,
+    /// using `last_keys` if an enclosing object has already scanned it
+    pub(crate) fn new(
+        stream: &'a mut CharStream<'de>,
+        options: DeserializeOptions,
+        last_keys: Option<&'a LastKeys<'de>>,
+    ) -> Self {
+        let last_keys = match (options.duplicate_keys(), last_keys) {
+            (_, Some(last_keys)) => Some(Cow::Borrowed(last_keys)),
+            (DuplicateKeys::KeepLast, None) => Some(Cow::Owned(LastKeys::scan(stream))),
+            (_, None) => None,
+        };
+
+        let keys = match (options.duplicate_keys(), &last_keys) {
+            (DuplicateKeys::KeepLast, Some(last_keys)) => {
+                last_keys.get(stream.offset()).cloned().unwrap_or_default()
+            }
+            _ => BTreeMap::new(),
+        };
+
         MapDeserializer {
             stream,
+            options,
             first: true,
             next_key: true,
+            index: 0,
+            key: 0..0,
+            keys,
+            last_keys,
+        }
+    }
+
+    /// Creates a [`MapDeserializer`] which continues an object after its first `index` entries,
+    /// using the `keys` of a previous [`MapDeserializer`] for the same object
+    pub(crate) fn resume(
+        stream: &'a mut CharStream<'de>,
+        options: DeserializeOptions,
+        index: usize,
+        keys: BTreeMap<Cow<'de, str>, usize>,
+    ) -> Self {
+        MapDeserializer {
+            stream,
+            options,
+            first: index == 0,
+            next_key: true,
+            index,
+            key: 0..0,
+            keys,
+            last_keys: None,
         }
     }
+
+    /// Takes the index of the next entry and the keys used for the duplicate key policy, so the
+    /// object can be resumed later
+    pub(crate) fn into_state(self) -> (usize, BTreeMap<Cow<'de, str>, usize>) {
+        (self.index, self.keys)
+    }
 }
@@ -0,0 +1,16 @@
+use super::{Mappable, Mapped};
+
+impl<T: Mappable> Mapped<T> {
+    /// Gets the parsed value
+    pub fn get<'a>(&'a self) -> &'a T::Borrowed<'a> {
+        // SAFETY: `T::Borrowed` is covariant in its lifetime by the contract of `Mappable`, so
+        // this only shortens the lifetime of the borrows from the mapping to that of `self`
+        let value: *const T::Borrowed<'static> = &self.value;
+        unsafe { &*value.cast::<T::Borrowed<'a>>() }
+    }
+
+    /// Gets the bytes of the mapped file
+    pub fn bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    deserialize::{from_bytes, Result},
+    DeserializeError, DeserializeErrorKind, Spanned, SpannedValue, Value,
+};
+use data_format::Deserialize;
+use memmap2::Mmap;
+use std::{borrow::Cow, fs::File, path::Path};
+
+mod get;
+
+/// A type which can be deserialized while borrowing from a memory mapped file
+///
+/// # Safety
+/// [`Mappable::Borrowed`] must be covariant in `'de`, so a `Borrowed<'long>` can always be used
+/// as a `Borrowed<'short>`. [`Mapped::get`] relies on this to shorten the borrows of the value
+/// from the mapping to the lifetime of the [`Mapped`]. Types which can hold their borrows behind
+/// interior mutability, such as a `RefCell<Cow<'de, str>>`, are invariant and must not implement
+/// this trait.
+pub unsafe trait Mappable {
+    /// This type borrowing from the mapping for `'de`
+    type Borrowed<'de>: Deserialize<'de>;
+}
+
+/// A value parsed from a memory mapped file, along with the mapping it borrows from
+pub struct Mapped<T: Mappable> {
+    /// The parsed value, which must be dropped before `mmap`
+    value: T::Borrowed<'static>,
+
+    /// The mapping `value` borrows from
+    mmap: Mmap,
+}
+
+/// Maps the file at `path` into memory and deserializes it as JSON into `T`, borrowing strings
+/// from the mapping where possible
+///
+/// # Safety
+/// The file must not be modified or truncated, by this process or any other, while the returned
+/// [`Mapped`] is alive.
+pub unsafe fn from_file_mmap<T: Mappable>(path: impl AsRef<Path>) -> Result<'static, Mapped<T>> {
+    let mmap = File::open(path)
+        .and_then(|file| unsafe { Mmap::map(&file) })
+        .map_err(|error| DeserializeError::from(DeserializeErrorKind::Io(error)))?;
+
+    // The mapped memory doesn't move with `mmap` and outlives `value`, which is only ever handed
+    // out with a lifetime tied to the returned guard
+    let bytes: &'static [u8] = unsafe { core::slice::from_raw_parts(mmap.as_ptr(), mmap.len()) };
+    let value = from_bytes::<T::Borrowed<'static>>(bytes).map_err(DeserializeError::into_owned)?;
+
+    Ok(Mapped { value, mmap })
+}
+
+impl<T: Mappable> core::fmt::Debug for Mapped<T>
+where
+    T::Borrowed<'static>: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Mapped")
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+// SAFETY: `Value` only holds `'de` in `Cow`s, `Vec`s and maps, which are all covariant
+unsafe impl Mappable for Value<'_> {
+    type Borrowed<'de> = Value<'de>;
+}
+
+// SAFETY: `SpannedValue` only holds `'de` in `Cow`s, `Vec`s and maps, which are all covariant
+unsafe impl Mappable for SpannedValue<'_> {
+    type Borrowed<'de> = SpannedValue<'de>;
+}
+
+// SAFETY: `Spanned` holds its value directly, so it is covariant when `T::Borrowed` is
+unsafe impl<T: Mappable> Mappable for Spanned<T> {
+    type Borrowed<'de> = Spanned<T::Borrowed<'de>>;
+}
+
+// SAFETY: `Cow<'de, str>` is covariant in `'de`
+unsafe impl Mappable for Cow<'_, str> {
+    type Borrowed<'de> = Cow<'de, str>;
+}
@@ -1,22 +1,58 @@
+use char_stream::CharStream;
 use data_format::Deserialize;
 use deserializer::Deserializer;
 use error::Result;
-use lct_streams::SliceByteCharStream;
 use list::ListDeserializer;
-use map::MapDeserializer;
+use map::{LastKeys, MapDeserializer};
 
 use utility::*;
 
+#[cfg(all(feature = "futures-io", not(feature = "no_std")))]
+mod async_read;
+mod char_stream;
 mod deserializer;
 mod error;
+mod float;
+mod iter;
+mod lazy;
+#[cfg(not(feature = "no_std"))]
+mod lines;
 mod list;
 mod map;
+#[cfg(all(feature = "mmap", not(feature = "no_std")))]
+mod mmap;
 mod number;
+mod options;
+mod push;
+mod reader;
+mod recover;
+pub(crate) mod scan;
+mod spanned;
+mod stream;
 mod string;
 
 mod utility;
 
-pub use error::{DeserializeError, DeserializeErrorKind};
+pub(crate) use spanned::SPANNED_MAGIC;
+
+#[cfg(all(feature = "futures-io", not(feature = "no_std")))]
+pub use async_read::from_async_read;
+pub use error::{DeserializeError, DeserializeErrorKind, ErrorCategory, ErrorPath, ErrorSnippet};
+pub use iter::{iter_array, iter_object, ArrayIter, ObjectIter};
+#[cfg(not(feature = "no_std"))]
+pub use iter::{
+    iter_array_from_reader, iter_object_from_reader, ArrayReaderIter, ObjectReaderIter,
+};
+pub use lazy::{LazyDocument, LazyValue};
+#[cfg(not(feature = "no_std"))]
+pub use lines::JsonLinesReader;
+#[cfg(all(feature = "mmap", not(feature = "no_std")))]
+pub use mmap::{from_file_mmap, Mappable, Mapped};
+pub use options::{DeserializeOptions, DuplicateKeys};
+pub use push::PushParser;
+pub use reader::{Event, Reader};
+pub use recover::{from_bytes_recovering, from_str_recovering, Recovered};
+pub use stream::{StreamDeserializer, StreamMode};
 
 /// Attempts to deserialize `string` as JSON into `T`
 pub fn from_str<'de, T: Deserialize<'de>>(string: &'de str) -> Result<'de, T> {
@@ -25,10 +61,27 @@ pub fn from_str<'de, T: Deserialize<'de>>(string: &'de str) -> Result<'de, T> {
 
 /// Attempts to deserialize `bytes` as JSON into `T`
 pub fn from_bytes<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<'de, T> {
-    let mut stream = SliceByteCharStream::new(bytes);
+    from_bytes_with(bytes, DeserializeOptions::new())
+}
+
+/// Attempts to deserialize `string` as JSON into `T` using `options`
+pub fn from_str_with<'de, T: Deserialize<'de>>(
+    string: &'de str,
+    options: DeserializeOptions,
+) -> Result<'de, T> {
+    from_bytes_with(string.as_bytes(), options)
+}
+
+/// Attempts to deserialize `bytes` as JSON into `T` using `options`
+pub fn from_bytes_with<'de, T: Deserialize<'de>>(
+    bytes: &'de [u8],
+    options: DeserializeOptions,
+) -> Result<'de, T> {
+    let mut stream = CharStream::new(bytes);
 
-    T::deserialize(Deserializer::new(&mut stream)).map_err(|error| {
+    T::deserialize(Deserializer::new(&mut stream, options, None)).map_err(|mut error| {
         debug_assert!(error.position().is_some());
+        error.set_offset_from(bytes, stream.position(), stream.offset());
         error
     })
 }
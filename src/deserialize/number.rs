@@ -1,96 +1,151 @@
 use crate::{
-    deserialize::{peek, skip_whitespace, Result},
+    deserialize::{float::Significand, peek, skip_whitespace, CharStream, Result},
     DeserializeError, DeserializeErrorKind,
 };
-use lct_streams::{Position, SliceByteCharStream};
+use lct_streams::Position;
 
 /// A deserialized number
 struct Number {
     /// Did the number start with a minus? AKA Is it negative?
     pub(self) minus: bool,
 
-    /// The integer portion of the number
-    pub(self) int: usize,
+    /// The integer portion of the number, or [`None`] if it doesn't fit in a [`usize`]
+    pub(self) int: Option<usize>,
 
-    /// The fractional portion of the number
-    pub(self) frac: Option<usize>,
+    /// Did the number have a fractional portion?
+    pub(self) frac: bool,
 
     /// The exponential portion of the number
     pub(self) exp: Option<isize>,
+
+    /// The significant digits of the number, for converting it to a float
+    pub(self) significand: Significand,
 }
 
 /// Deserializes an [`f64`] from `stream`
-pub(super) fn deserialize_f64<'de>(
-    stream: &mut SliceByteCharStream<'de>,
-) -> Result<'de, (f64, Position)> {
+pub(super) fn deserialize_f64<'de>(stream: &mut CharStream<'de>) -> Result<'de, (f64, Position)> {
     skip_whitespace(stream)?;
     let pos = stream.position();
     let start_offset = stream.offset();
 
-    Number::deserialize(stream)?;
+    let number = Number::deserialize(stream)?;
+    let text =
+        unsafe { core::str::from_utf8_unchecked(&stream.bytes()[start_offset..stream.offset()]) };
 
-    // Let rust parse the float
     Ok((
-        unsafe { core::str::from_utf8_unchecked(&stream.bytes()[start_offset..stream.offset()]) }
-            .parse()
-            .unwrap(),
+        number
+            .significand
+            .into_f64(number.minus, number.exp.unwrap_or(0) as i64, text),
         pos,
     ))
 }
 
 /// Deserializes an [`isize`] from `stream`
 pub(super) fn deserialize_isize<'de>(
-    stream: &mut SliceByteCharStream<'de>,
+    stream: &mut CharStream<'de>,
 ) -> Result<'de, (isize, Position)> {
     skip_whitespace(stream)?;
     let pos = stream.position();
     let start_offset = stream.offset();
     let number = Number::deserialize(stream)?;
 
-    if number.frac.is_some() || number.exp.is_some() {
+    if number.frac || number.exp.is_some() {
         return Err(DeserializeError::new(
             DeserializeErrorKind::InvalidType {
                 unexpected: stream.bytes()[start_offset..stream.offset()].into(),
                 expected: "an integer".into(),
             },
             pos,
-        ));
+        )
+        .with_end(stream.position()));
     }
 
-    Ok((number.int as isize * if number.minus { -1 } else { 1 }, pos))
+    let value = match number.int {
+        Some(int) if number.minus => 0isize.checked_sub_unsigned(int),
+        Some(int) => isize::try_from(int).ok(),
+        None => None,
+    };
+
+    match value {
+        Some(value) => Ok((value, pos)),
+        None => Err(out_of_range(
+            stream,
+            start_offset,
+            pos,
+            "an integer which fits in an isize",
+        )),
+    }
 }
 
 /// Deserializes an [`usize`] from `stream`
 pub(super) fn deserialize_usize<'de>(
-    stream: &mut SliceByteCharStream<'de>,
+    stream: &mut CharStream<'de>,
 ) -> Result<'de, (usize, Position)> {
     skip_whitespace(stream)?;
     let pos = stream.position();
     let start_offset = stream.offset();
     let number = Number::deserialize(stream)?;
 
-    if number.frac.is_some() || number.exp.is_some() || number.minus {
+    if number.frac || number.exp.is_some() || number.minus {
         return Err(DeserializeError::new(
             DeserializeErrorKind::InvalidType {
                 unexpected: stream.bytes()[start_offset..stream.offset()].into(),
                 expected: "a positive integer".into(),
             },
             pos,
-        ));
+        )
+        .with_end(stream.position()));
+    }
+
+    match number.int {
+        Some(int) => Ok((int, pos)),
+        None => Err(out_of_range(
+            stream,
+            start_offset,
+            pos,
+            "a positive integer which fits in a usize",
+        )),
     }
+}
 
-    Ok((number.int, pos))
+/// Creates an error for the integer starting at `start_offset` and `pos`, which was just read
+/// from `stream`, being too large to be stored
+fn out_of_range<'de>(
+    stream: &CharStream<'de>,
+    start_offset: usize,
+    pos: Position,
+    expected: &'static str,
+) -> DeserializeError<'de> {
+    DeserializeError::new(
+        DeserializeErrorKind::InvalidValue {
+            unexpected: stream.bytes()[start_offset..stream.offset()].into(),
+            expected: expected.into(),
+        },
+        pos,
+    )
+    .with_end(stream.position())
+}
+
+/// Advances `stream` past a number without converting it
+pub(super) fn skip_number<'de>(stream: &mut CharStream<'de>) -> Result<'de, ()> {
+    skip_whitespace(stream)?;
+    Number::deserialize(stream).map(|_| ())
 }
 
 impl Number {
     /// Deserializes a [`Number`] from `stream`
-    pub(self) fn deserialize<'de>(stream: &mut SliceByteCharStream<'de>) -> Result<'de, Self> {
+    pub(self) fn deserialize<'de>(stream: &mut CharStream<'de>) -> Result<'de, Self> {
         let (first_digit, minus) = Number::deserialize_first_digit(stream)?;
 
+        let mut significand = Significand::default();
+        significand.push(first_digit, false);
+
         let int = if first_digit == 0 {
-            0
+            Some(0)
         } else {
-            Number::deserialize_int(stream, Some(first_digit))?
+            Number::deserialize_int(stream, Some(first_digit), |digit| {
+                significand.push(digit, false)
+            })?
         };
 
         let is_frac = match stream
@@ -109,19 +164,16 @@ impl Number {
                 return Ok(Number {
                     minus,
                     int,
-                    frac: None,
+                    frac: false,
                     exp: None,
+                    significand,
                 });
             }
         };
 
-        let frac = if is_frac {
-            Some(Number::deserialize_int(stream, None)?)
-        } else {
-            None
-        };
-
         if is_frac {
+            Number::deserialize_int(stream, None, |digit| significand.push(digit, true))?;
+
             match stream
                 .peek()
                 .map_err(|error| DeserializeError::new(error, stream.position()))?
@@ -133,8 +185,9 @@ impl Number {
                     return Ok(Number {
                         minus,
                         int,
-                        frac,
+                        frac: true,
                         exp: None,
+                        significand,
                     });
                 }
             }
@@ -155,21 +208,24 @@ impl Number {
             _ => false,
         };
 
-        let exp = Number::deserialize_int(stream, None)? as isize;
+        let exp = Number::deserialize_int(stream, None, |_| {})?;
+        // The exponent saturates, as any exponent this large is out of range for an `f64` anyway
+        let exp = exp
+            .and_then(|exp| isize::try_from(exp).ok())
+            .unwrap_or(isize::MAX);
 
         Ok(Number {
             minus,
             int,
-            frac,
+            frac: is_frac,
             exp: Some(exp * if exp_minus { -1 } else { 1 }),
+            significand,
         })
     }
 
     /// Gets the first digit of the number, returning a boolean as well indicating if there was a
     /// minus
-    fn deserialize_first_digit<'de>(
-        stream: &mut SliceByteCharStream<'de>,
-    ) -> Result<'de, (u8, bool)> {
+    fn deserialize_first_digit<'de>(stream: &mut CharStream<'de>) -> Result<'de, (u8, bool)> {
         match Number::deserialize_next_number(stream, Some('-'))? {
             Some(digit) => return Ok((digit, false)),
             None => {}
@@ -181,14 +237,16 @@ impl Number {
         ))
     }
 
-    /// Deserializes an integer from the stream
-    fn deserialize_int<'de>(
-        stream: &mut SliceByteCharStream<'de>,
+    /// Deserializes an integer from the stream, passing each digit after `first_digit` to
+    /// `on_digit`. The integer is [`None`] if it doesn't fit in a [`usize`].
+    fn deserialize_int<'de, F: FnMut(u8)>(
+        stream: &mut CharStream<'de>,
         first_digit: Option<u8>,
-    ) -> Result<'de, usize> {
+        mut on_digit: F,
+    ) -> Result<'de, Option<usize>> {
         let (mut value, mut count) = match first_digit {
-            Some(first_digit) => (first_digit as usize, 1),
-            None => (0, 0),
+            Some(first_digit) => (Some(first_digit as usize), 1),
+            None => (Some(0), 0),
         };
 
         while let Some(c) = stream
@@ -201,8 +259,12 @@ impl Number {
 
             stream.next().unwrap();
 
-            value *= 10;
-            value += (c as u8 - b'0') as usize;
+            let digit = c as u8 - b'0';
+            on_digit(digit);
+
+            value = value
+                .and_then(|value| value.checked_mul(10))
+                .and_then(|value| value.checked_add(digit as usize));
             count += 1;
         }
 
@@ -227,7 +289,7 @@ impl Number {
     /// If `other` is provided, a return of [`None`] indicates that the next character was `other`.
     /// If `other` is not provided, an [`Ok`] return will always be [`Some`] and is safe to unwrap.
     fn deserialize_next_number<'de>(
-        stream: &mut SliceByteCharStream<'de>,
+        stream: &mut CharStream<'de>,
         other: Option<char>,
     ) -> Result<'de, Option<u8>> {
         let (c, pos) = peek(stream)?;
@@ -240,7 +302,11 @@ impl Number {
         }
 
         if !c.is_ascii_digit() {
-            return Err(DeserializeError::unexpected(c, "a number", pos));
+            let error = DeserializeError::unexpected(c, "a number", pos);
+            return Err(match c {
+                '\n' => error,
+                _ => error.with_end(Position::new(pos.line(), pos.column() + 1)),
+            });
         }
 
         stream.next().unwrap();
@@ -0,0 +1,17 @@
+/// The policy for handling an object which contains the same key more than once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateKeys {
+    /// Pass every entry through to the target type, letting it decide what to do
+    #[default]
+    Allow,
+
+    /// Fail with [`DeserializeErrorKind::DuplicateKey`](crate::DeserializeErrorKind::DuplicateKey)
+    /// at the position of the repeated key
+    Reject,
+
+    /// Keep the first entry with a given key, skipping any later ones
+    KeepFirst,
+
+    /// Keep the last entry with a given key, skipping any earlier ones
+    KeepLast,
+}
@@ -0,0 +1,8 @@
+use crate::{DeserializeOptions, DuplicateKeys};
+
+impl DeserializeOptions {
+    /// Gets the policy for objects containing the same key more than once
+    pub const fn duplicate_keys(&self) -> DuplicateKeys {
+        self.duplicate_keys
+    }
+}
@@ -0,0 +1,13 @@
+mod duplicate_keys;
+mod get;
+mod new;
+mod set;
+
+pub use duplicate_keys::DuplicateKeys;
+
+/// Options which control how JSON is deserialized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeserializeOptions {
+    /// What to do when an object contains the same key more than once
+    duplicate_keys: DuplicateKeys,
+}
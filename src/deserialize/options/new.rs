@@ -0,0 +1,16 @@
+use crate::{DeserializeOptions, DuplicateKeys};
+
+impl DeserializeOptions {
+    /// Creates a new [`DeserializeOptions`] with the default settings
+    pub const fn new() -> Self {
+        DeserializeOptions {
+            duplicate_keys: DuplicateKeys::Allow,
+        }
+    }
+}
+
+impl Default for DeserializeOptions {
+    fn default() -> Self {
+        DeserializeOptions::new()
+    }
+}
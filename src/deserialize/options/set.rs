@@ -0,0 +1,8 @@
+use crate::{DeserializeOptions, DuplicateKeys};
+
+impl DeserializeOptions {
+    /// Sets the policy for objects containing the same key more than once
+    pub fn set_duplicate_keys(&mut self, duplicate_keys: DuplicateKeys) {
+        self.duplicate_keys = duplicate_keys;
+    }
+}
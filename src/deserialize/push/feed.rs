@@ -0,0 +1,25 @@
+use crate::PushParser;
+
+impl PushParser {
+    /// Adds the next chunk of the stream to be parsed
+    pub fn feed(&mut self, bytes: &[u8]) {
+        debug_assert!(!self.finished, "fed a finished push parser");
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Marks the end of the stream, allowing a trailing number or literal to complete and
+    /// reporting an error for a value which is left incomplete
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// Has the end of the stream been reached, with every value produced?
+    pub fn is_finished(&self) -> bool {
+        self.finished && self.buffer.iter().all(|byte| is_whitespace(*byte))
+    }
+}
+
+/// Is `byte` JSON whitespace?
+pub(super) fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r')
+}
@@ -0,0 +1,66 @@
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use lct_streams::Position;
+
+mod feed;
+mod new;
+mod next;
+mod scan;
+
+#[cfg(not(feature = "no_std"))]
+pub(crate) use scan::is_delimiter;
+
+/// Parses a stream of whitespace separated JSON values which arrives in chunks, producing each
+/// top-level [`Value`](crate::Value) as soon as it is complete.
+///
+/// Chunks may be split anywhere, including in the middle of a token, a UTF-8 sequence or an
+/// escape. Positions in values and errors are relative to the whole stream.
+#[derive(Debug, Clone)]
+pub struct PushParser {
+    /// The bytes which have been fed but not yet parsed
+    buffer: Vec<u8>,
+
+    /// The number of bytes at the start of `buffer` which have been scanned
+    scanned: usize,
+
+    /// The value currently being scanned, if one has started
+    value: Option<ValueScan>,
+
+    /// The position in the stream of the start of `buffer`
+    base: Position,
+
+    /// The byte offset in the stream of the start of `buffer`
+    base_offset: usize,
+
+    /// Has the end of the stream been reached?
+    finished: bool,
+}
+
+/// The progress of scanning a top-level value
+#[derive(Debug, Clone, Copy)]
+struct ValueScan {
+    /// The index in the buffer of the value's first byte
+    start: usize,
+
+    /// What kind of value is being scanned
+    kind: ScanKind,
+
+    /// Is the scan inside a string?
+    string: bool,
+
+    /// Is the scan directly after a '\' in a string?
+    escaped: bool,
+}
+
+/// The kind of a top-level value being scanned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScanKind {
+    /// A string, which ends at its closing '"'
+    String,
+
+    /// An array or object, which ends at the bracket bringing it back to a depth of 0
+    Container(usize),
+
+    /// A number or a literal, which ends before the next whitespace or delimiter
+    Scalar,
+}
@@ -0,0 +1,24 @@
+use crate::PushParser;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use lct_streams::Position;
+
+impl PushParser {
+    /// Creates a new [`PushParser`] at the start of a stream
+    pub fn new() -> Self {
+        PushParser {
+            buffer: Vec::new(),
+            scanned: 0,
+            value: None,
+            base: Position::new(1, 1),
+            base_offset: 0,
+            finished: false,
+        }
+    }
+}
+
+impl Default for PushParser {
+    fn default() -> Self {
+        PushParser::new()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    deserialize::{advance, from_bytes, Result},
+    DeserializeError, PushParser, Spanned, Value,
+};
+
+impl PushParser {
+    /// Gets the next complete top-level value, returning [`None`] if more of the stream is needed.
+    ///
+    /// A value which can't be parsed is skipped after its error is returned, so parsing can
+    /// continue with the values after it.
+    pub fn next_value(&mut self) -> Result<'static, Option<Spanned<Value<'static>>>> {
+        self.next_with(|bytes| {
+            from_bytes::<Value>(bytes)
+                .map(Value::into_owned)
+                .map_err(DeserializeError::into_owned)
+        })
+    }
+
+    /// Gets the next complete top-level value, converted from its bytes by `parse`, returning
+    /// [`None`] if more of the stream is needed
+    pub(crate) fn next_with<T, F>(&mut self, parse: F) -> Result<'static, Option<Spanned<T>>>
+    where
+        F: FnOnce(&[u8]) -> Result<'static, T>,
+    {
+        let (start, end) = match self.scan() {
+            Some(range) => range,
+            None => return Ok(None),
+        };
+
+        let start_position = advance(self.base, &self.buffer[..start]);
+        let end_position = advance(start_position, &self.buffer[start..end]);
+        let start_offset = self.base_offset + start;
+
+        let result = match parse(&self.buffer[start..end]) {
+            Ok(value) => Ok(Some(Spanned::new(value, start_position, end_position))),
+            Err(mut error) => {
+                error.rebase(start_position, start_offset);
+                Err(error)
+            }
+        };
+
+        self.buffer.drain(..end);
+        self.scanned -= end;
+        self.value = None;
+        self.base = end_position;
+        self.base_offset += end;
+
+        result
+    }
+}
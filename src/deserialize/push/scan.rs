@@ -0,0 +1,90 @@
+use super::{feed::is_whitespace, ScanKind, ValueScan};
+use crate::{deserialize::scan, PushParser};
+
+impl PushParser {
+    /// Scans the newly fed bytes, returning the range in the buffer of the next complete value
+    pub(super) fn scan(&mut self) -> Option<(usize, usize)> {
+        while self.scanned < self.buffer.len() {
+            // Jump over the bytes in a string or container which can't change the scan
+            if let Some(value) = &self.value {
+                let rest = &self.buffer[self.scanned..];
+                self.scanned += match (value.string, value.escaped, value.kind) {
+                    (true, false, _) => scan::string_len(rest),
+                    (false, _, ScanKind::Container(_)) => scan::structural_len(rest),
+                    _ => 0,
+                };
+
+                if self.scanned == self.buffer.len() {
+                    break;
+                }
+            }
+
+            let index = self.scanned;
+            let byte = self.buffer[index];
+            self.scanned += 1;
+
+            let value = match &mut self.value {
+                Some(value) => value,
+                None => {
+                    if is_whitespace(byte) {
+                        continue;
+                    }
+
+                    let kind = match byte {
+                        b'\"' => ScanKind::String,
+                        b'[' | b'{' => ScanKind::Container(1),
+                        b']' | b'}' => return Some((index, index + 1)),
+                        _ => ScanKind::Scalar,
+                    };
+                    self.value = Some(ValueScan {
+                        start: index,
+                        kind,
+                        string: kind == ScanKind::String,
+                        escaped: false,
+                    });
+                    continue;
+                }
+            };
+
+            if value.string {
+                if value.escaped {
+                    value.escaped = false;
+                } else if byte == b'\\' {
+                    value.escaped = true;
+                } else if byte == b'\"' {
+                    value.string = false;
+                    if value.kind == ScanKind::String {
+                        return Some((value.start, index + 1));
+                    }
+                }
+                continue;
+            }
+
+            match (value.kind, byte) {
+                (ScanKind::Scalar, _) if is_delimiter(byte) => {
+                    self.scanned = index;
+                    return Some((value.start, index));
+                }
+                (ScanKind::Container(_), b'\"') => value.string = true,
+                (ScanKind::Container(depth), b'[' | b'{') => {
+                    value.kind = ScanKind::Container(depth + 1)
+                }
+                (ScanKind::Container(1), b']' | b'}') => return Some((value.start, index + 1)),
+                (ScanKind::Container(depth), b']' | b'}') => {
+                    value.kind = ScanKind::Container(depth - 1)
+                }
+                _ => {}
+            }
+        }
+
+        match self.value {
+            Some(value) if self.finished => Some((value.start, self.buffer.len())),
+            _ => None,
+        }
+    }
+}
+
+/// Does `byte` end a number or literal?
+pub(crate) fn is_delimiter(byte: u8) -> bool {
+    is_whitespace(byte) || matches!(byte, b'[' | b']' | b'{' | b'}' | b'\"' | b',' | b':')
+}
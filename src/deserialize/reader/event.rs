@@ -0,0 +1,35 @@
+#[cfg(feature = "no_std")]
+use alloc::borrow::Cow;
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+/// A single token of a JSON document produced by a [`Reader`](crate::Reader)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event<'de> {
+    /// The '{' starting an object
+    StartObject,
+
+    /// The key of an object entry, which will be followed by the entry's value
+    Key(Cow<'de, str>),
+
+    /// The '}' ending an object
+    EndObject,
+
+    /// The '[' starting an array
+    StartArray,
+
+    /// The ']' ending an array
+    EndArray,
+
+    /// A string value
+    String(Cow<'de, str>),
+
+    /// A number value, exactly as it appears in the document
+    Number(&'de str),
+
+    /// A boolean value
+    Bool(bool),
+
+    /// A null value
+    Null,
+}
@@ -0,0 +1,14 @@
+use crate::Reader;
+use lct_streams::Position;
+
+impl<'de> Reader<'de> {
+    /// Gets the position of the reader in the document
+    pub fn position(&self) -> Position {
+        self.stream.position()
+    }
+
+    /// Gets the number of arrays and objects which have been started but not yet ended
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+}
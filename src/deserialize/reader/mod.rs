@@ -0,0 +1,66 @@
+use crate::{deserialize::CharStream, Spanned};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+mod event;
+mod get;
+mod new;
+mod read;
+mod skip;
+
+pub use event::Event;
+
+/// Reads a JSON document as a stream of [`Event`]s without building any data structure.
+///
+/// The document is validated as it is read. Once an error is returned or the end of the document
+/// is reached, no more events are produced.
+pub struct Reader<'de> {
+    /// The stream to read from
+    stream: CharStream<'de>,
+
+    /// The arrays and objects which have been started but not yet ended, from the outermost in
+    stack: Vec<Container>,
+
+    /// What is expected next on the stream
+    state: State,
+}
+
+/// An array or object being read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Container {
+    /// An array, ended by ']'
+    Array,
+
+    /// An object, ended by '}'
+    Object,
+}
+
+/// What a [`Reader`] expects next on its stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// A value
+    Value,
+
+    /// The first element of an array or the ']' ending it
+    FirstElement,
+
+    /// The first key of an object or the '}' ending it
+    FirstKey,
+
+    /// A key following a ','
+    Key,
+
+    /// A ',' or the end of the current container, or the end of the document at the top level
+    AfterValue,
+
+    /// Nothing, as the document has ended or an error occurred
+    Done,
+}
+
+impl<'de> Iterator for Reader<'de> {
+    type Item = crate::deserialize::Result<'de, Spanned<Event<'de>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_event().transpose()
+    }
+}
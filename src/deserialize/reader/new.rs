@@ -0,0 +1,20 @@
+use super::State;
+use crate::{deserialize::CharStream, Reader};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+impl<'de> Reader<'de> {
+    /// Creates a new [`Reader`] over the JSON document in `string`
+    pub fn new(string: &'de str) -> Self {
+        Reader::from_bytes(string.as_bytes())
+    }
+
+    /// Creates a new [`Reader`] over the JSON document in `bytes`
+    pub fn from_bytes(bytes: &'de [u8]) -> Self {
+        Reader {
+            stream: CharStream::new(bytes),
+            stack: Vec::new(),
+            state: State::Value,
+        }
+    }
+}
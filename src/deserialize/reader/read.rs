@@ -0,0 +1,158 @@
+use super::{Container, State};
+use crate::{
+    deserialize::{expect, number, peek, skip_whitespace, string, Result},
+    DeserializeError, Event, Reader, Spanned,
+};
+use lct_streams::Position;
+
+impl<'de> Reader<'de> {
+    /// Reads the next event from the document, returning [`None`] once it has ended
+    pub fn next_event(&mut self) -> Result<'de, Option<Spanned<Event<'de>>>> {
+        let result = self.read_event();
+        self.finish(result)
+    }
+
+    /// Stops the reader if `result` is an error, locating the error in the document
+    pub(super) fn finish<T>(&mut self, result: Result<'de, T>) -> Result<'de, T> {
+        result.map_err(|mut error| {
+            self.state = State::Done;
+            error.set_offset_from(
+                self.stream.bytes(),
+                self.stream.position(),
+                self.stream.offset(),
+            );
+            error
+        })
+    }
+
+    /// Reads the next event from the document
+    fn read_event(&mut self) -> Result<'de, Option<Spanned<Event<'de>>>> {
+        if self.state == State::Done {
+            return Ok(None);
+        }
+
+        skip_whitespace(&mut self.stream)?;
+        if self.state == State::AfterValue && !self.separator()? {
+            if self.state == State::Done {
+                return Ok(None);
+            }
+
+            let start = self.stream.position();
+            self.stream.next().unwrap();
+            return Ok(Some(Spanned::new(
+                self.close(),
+                start,
+                self.stream.position(),
+            )));
+        }
+
+        let start = self.stream.position();
+        let (event, end) = match (self.state, peek(&mut self.stream)?.0) {
+            (State::FirstElement, ']') | (State::FirstKey, '}') => {
+                self.stream.next().unwrap();
+                (self.close(), self.stream.position())
+            }
+            (State::FirstKey | State::Key, _) => self.read_key()?,
+            _ => (self.read_value()?, self.stream.position()),
+        };
+
+        Ok(Some(Spanned::new(event, start, end)))
+    }
+
+    /// Moves past the ',' following a value, returning false instead if the current container or
+    /// the document ends next. The character ending the container is left on the stream.
+    pub(super) fn separator(&mut self) -> Result<'de, bool> {
+        let container = match self.stack.last() {
+            Some(container) => *container,
+            None => {
+                return match self.stream.peek_pos() {
+                    Ok(None) => {
+                        self.state = State::Done;
+                        Ok(false)
+                    }
+                    Ok(Some((c, position))) => Err(DeserializeError::unexpected(
+                        c,
+                        "the end of the JSON",
+                        position,
+                    )),
+                    Err(error) => Err(DeserializeError::new(error, self.stream.position())),
+                }
+            }
+        };
+
+        match (container, peek(&mut self.stream)?) {
+            (_, (',', _)) => {
+                self.stream.next().unwrap();
+                skip_whitespace(&mut self.stream)?;
+                self.state = match container {
+                    Container::Array => State::Value,
+                    Container::Object => State::Key,
+                };
+                Ok(true)
+            }
+            (Container::Array, (']', _)) | (Container::Object, ('}', _)) => Ok(false),
+            (Container::Array, (c, position)) => {
+                Err(DeserializeError::unexpected(c, "',' or ']'", position))
+            }
+            (Container::Object, (c, position)) => {
+                Err(DeserializeError::unexpected(c, "',' or '}'", position))
+            }
+        }
+    }
+
+    /// Reads a key along with the ':' following it, returning it and where it ends
+    pub(super) fn read_key(&mut self) -> Result<'de, (Event<'de>, Position)> {
+        let (key, _) = string::deserialize_string(&mut self.stream)?;
+        let end = self.stream.position();
+
+        skip_whitespace(&mut self.stream)?;
+        expect(&mut self.stream, ':', "a ':'")?;
+        self.state = State::Value;
+
+        Ok((Event::Key(key), end))
+    }
+
+    /// Reads a value, or the start of one if it is an array or an object
+    fn read_value(&mut self) -> Result<'de, Event<'de>> {
+        let event = match peek(&mut self.stream)? {
+            ('t', _) => expect(&mut self.stream, "true", "true").map(|_| Event::Bool(true))?,
+            ('f', _) => expect(&mut self.stream, "false", "false").map(|_| Event::Bool(false))?,
+            ('n', _) => expect(&mut self.stream, "null", "null").map(|_| Event::Null)?,
+            ('\"', _) => Event::String(string::deserialize_string(&mut self.stream)?.0),
+            ('-' | '0'..='9', _) => {
+                let start = self.stream.offset();
+                number::skip_number(&mut self.stream)?;
+                Event::Number(unsafe {
+                    core::str::from_utf8_unchecked(
+                        &self.stream.bytes()[start..self.stream.offset()],
+                    )
+                })
+            }
+            ('[', _) => {
+                self.stream.next().unwrap();
+                self.stack.push(Container::Array);
+                self.state = State::FirstElement;
+                return Ok(Event::StartArray);
+            }
+            ('{', _) => {
+                self.stream.next().unwrap();
+                self.stack.push(Container::Object);
+                self.state = State::FirstKey;
+                return Ok(Event::StartObject);
+            }
+            (c, position) => return Err(DeserializeError::unexpected(c, "valid JSON", position)),
+        };
+
+        self.state = State::AfterValue;
+        Ok(event)
+    }
+
+    /// Ends the current container, whose closing character has just been read
+    fn close(&mut self) -> Event<'de> {
+        self.state = State::AfterValue;
+        match self.stack.pop() {
+            Some(Container::Array) => Event::EndArray,
+            _ => Event::EndObject,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use super::State;
+use crate::{
+    deserialize::{peek, skip_value, skip_whitespace, Result},
+    Reader,
+};
+
+impl<'de> Reader<'de> {
+    /// Skips past the next value without producing its events, including everything nested inside
+    /// it. If the next event would be a key, its whole entry is skipped.
+    ///
+    /// Returns false without moving if the current array or object, or the document, ends next,
+    /// so the event ending it can still be read.
+    pub fn skip_value(&mut self) -> Result<'de, bool> {
+        let result = self.skip();
+        self.finish(result)
+    }
+
+    /// Skips past the next value, returning false if there is none
+    fn skip(&mut self) -> Result<'de, bool> {
+        if self.state == State::Done {
+            return Ok(false);
+        }
+
+        skip_whitespace(&mut self.stream)?;
+        if self.state == State::AfterValue && !self.separator()? {
+            return Ok(false);
+        }
+
+        match (self.state, peek(&mut self.stream)?.0) {
+            (State::FirstElement, ']') | (State::FirstKey, '}') => return Ok(false),
+            (State::FirstKey | State::Key, _) => {
+                self.read_key()?;
+            }
+            _ => {}
+        }
+
+        skip_value(&mut self.stream)?;
+        self.state = State::AfterValue;
+        Ok(true)
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{DeserializeError, Recovered, Value};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+impl<'de> Recovered<'de> {
+    /// Gets the best-effort value parsed from the input
+    pub fn value(&self) -> &Value<'de> {
+        &self.value
+    }
+
+    /// Gets the errors found in the input, in the order they were found
+    pub fn errors(&self) -> &[DeserializeError<'de>] {
+        &self.errors
+    }
+
+    /// Was the input parsed without any errors?
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Splits this into the best-effort value and the errors found
+    pub fn into_parts(self) -> (Value<'de>, Vec<DeserializeError<'de>>) {
+        (self.value, self.errors)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{deserialize::CharStream, DeserializeError, ErrorCategory, PathElement, Value};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+mod get;
+mod parse;
+mod resync;
+
+/// The result of parsing JSON while recovering from syntax errors
+#[derive(Debug)]
+pub struct Recovered<'de> {
+    /// The best-effort value parsed from the input
+    value: Value<'de>,
+
+    /// The errors found in the input, in the order they were found
+    errors: Vec<DeserializeError<'de>>,
+}
+
+/// Parses JSON into a [`Value`], recovering from syntax errors inside arrays and objects
+struct Recoverer<'a, 'de> {
+    /// The stream to parse from
+    stream: &'a mut CharStream<'de>,
+
+    /// The errors found so far
+    errors: Vec<DeserializeError<'de>>,
+
+    /// The maximum number of errors to collect before giving up
+    max_errors: usize,
+
+    /// Has parsing stopped, either at the end of the input or after too many errors?
+    stopped: bool,
+
+    /// The location of the value currently being parsed
+    path: Vec<PathElement>,
+}
+
+/// Parses `string` as JSON, recovering from syntax errors to report up to `max_errors` of them
+/// along with a best-effort [`Value`]
+pub fn from_str_recovering(string: &str, max_errors: usize) -> Recovered<'_> {
+    from_bytes_recovering(string.as_bytes(), max_errors)
+}
+
+/// Parses `bytes` as JSON, recovering from syntax errors to report up to `max_errors` of them
+/// along with a best-effort [`Value`].
+///
+/// Values which can't be parsed become [`Value::Null`]. Inside arrays and objects a missing
+/// delimiter is assumed to have been left out, and anything else unexpected is skipped up to the
+/// next ',' or closing bracket at the same depth.
+pub fn from_bytes_recovering(bytes: &[u8], max_errors: usize) -> Recovered<'_> {
+    let mut stream = CharStream::new(bytes);
+    let mut recoverer = Recoverer {
+        stream: &mut stream,
+        errors: Vec::new(),
+        max_errors: max_errors.max(1),
+        stopped: false,
+        path: Vec::new(),
+    };
+
+    let value = recoverer.parse_value().unwrap_or(Value::Null);
+    Recovered {
+        value,
+        errors: recoverer.errors,
+    }
+}
+
+impl<'a, 'de> Recoverer<'a, 'de> {
+    /// Records `error` at the current path, stopping if the end of the input was reached or
+    /// there are now too many errors
+    fn record(&mut self, mut error: DeserializeError<'de>) {
+        if self.stopped {
+            return;
+        }
+
+        for element in self.path.iter().rev() {
+            error.push_path(element.clone());
+        }
+
+        if error.classify() == ErrorCategory::Eof {
+            self.stopped = true;
+        }
+
+        error.set_offset_from(
+            self.stream.bytes(),
+            self.stream.position(),
+            self.stream.offset(),
+        );
+        self.errors.push(error);
+        if self.errors.len() >= self.max_errors {
+            self.stopped = true;
+        }
+    }
+}
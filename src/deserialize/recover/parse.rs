@@ -0,0 +1,222 @@
+use super::Recoverer;
+use crate::{
+    deserialize::{number, peek, skip_whitespace, string, Result},
+    DeserializeError, PathElement, Value,
+};
+#[cfg(feature = "no_std")]
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeMap;
+
+impl<'a, 'de> Recoverer<'a, 'de> {
+    /// Parses the next value, returning [`None`] if it couldn't be parsed
+    pub(super) fn parse_value(&mut self) -> Option<Value<'de>> {
+        if self.stopped {
+            return None;
+        }
+
+        let c = match skip_whitespace(self.stream).and_then(|_| peek(self.stream)) {
+            Ok((c, _)) => c,
+            Err(error) => {
+                self.record(error);
+                return None;
+            }
+        };
+
+        let value = match c {
+            't' => self.parse_literal("true", Value::Boolean(true)),
+            'f' => self.parse_literal("false", Value::Boolean(false)),
+            'n' => self.parse_literal("null", Value::Null),
+            '\"' => string::deserialize_string(self.stream).map(|(value, _)| Value::String(value)),
+            '-' | '0'..='9' => {
+                number::deserialize_f64(self.stream).map(|(value, _)| Value::Number(value))
+            }
+            '[' => return Some(self.parse_array()),
+            '{' => return Some(self.parse_object()),
+            c => Err(DeserializeError::unexpected(
+                c,
+                "valid JSON",
+                self.stream.position(),
+            )),
+        };
+
+        match value {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.record(error);
+                self.resync();
+                None
+            }
+        }
+    }
+
+    /// Parses the literal `word`, leaving the first character that doesn't match on the stream
+    fn parse_literal(&mut self, word: &'static str, value: Value<'de>) -> Result<'de, Value<'de>> {
+        for expected in word.chars() {
+            match peek(self.stream)? {
+                (c, _) if c == expected => {
+                    self.stream.next().unwrap();
+                }
+                (c, position) => return Err(DeserializeError::unexpected(c, word, position)),
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Parses an array whose '[' is next on the stream
+    fn parse_array(&mut self) -> Value<'de> {
+        self.stream.next().unwrap();
+
+        let mut array = Vec::new();
+        if self.is_empty(']') {
+            return Value::Array(array);
+        }
+
+        while !self.stopped {
+            self.path.push(PathElement::Index(array.len()));
+            let value = self.parse_value().unwrap_or(Value::Null);
+            self.path.pop();
+            array.push(value);
+
+            if !self.separator(']', "',' or ']'") {
+                break;
+            }
+        }
+
+        Value::Array(array)
+    }
+
+    /// Parses an object whose '{' is next on the stream
+    fn parse_object(&mut self) -> Value<'de> {
+        self.stream.next().unwrap();
+
+        let mut object = BTreeMap::new();
+        if self.is_empty('}') {
+            return Value::Object(object);
+        }
+
+        while !self.stopped {
+            let key = match string::deserialize_string(self.stream) {
+                Ok((key, _)) => key,
+                Err(error) => {
+                    self.record(error);
+                    self.resync();
+                    if !self.separator('}', "',' or '}'") {
+                        break;
+                    }
+                    continue;
+                }
+            };
+
+            self.path.push(PathElement::Name(key.clone().into_owned()));
+            let value = match skip_whitespace(self.stream).and_then(|_| peek(self.stream)) {
+                Ok((':', _)) => {
+                    self.stream.next().unwrap();
+                    self.parse_value()
+                }
+                Ok((c, position)) => {
+                    self.record(DeserializeError::unexpected(c, "a ':'", position));
+                    if self.missing_delimiter() {
+                        self.parse_value()
+                    } else {
+                        self.resync();
+                        None
+                    }
+                }
+                Err(error) => {
+                    self.record(error);
+                    None
+                }
+            };
+            self.path.pop();
+            object.insert(key, value.unwrap_or(Value::Null));
+
+            if !self.separator('}', "',' or '}'") {
+                break;
+            }
+        }
+
+        Value::Object(object)
+    }
+
+    /// Consumes `end` if it is the next non-whitespace character, returning if it was
+    fn is_empty(&mut self, end: char) -> bool {
+        match skip_whitespace(self.stream).and_then(|_| peek(self.stream)) {
+            Ok((c, _)) if c == end => {
+                self.stream.next().unwrap();
+                true
+            }
+            Ok(_) => false,
+            Err(error) => {
+                self.record(error);
+                true
+            }
+        }
+    }
+
+    /// Handles the separator after an element in a container closed by `end`, returning if
+    /// another element should be parsed
+    fn separator(&mut self, end: char, expected: &'static str) -> bool {
+        loop {
+            if self.stopped {
+                return false;
+            }
+
+            let (c, position) = match skip_whitespace(self.stream).and_then(|_| peek(self.stream)) {
+                Ok(next) => next,
+                Err(error) => {
+                    self.record(error);
+                    return false;
+                }
+            };
+
+            match c {
+                ',' => {
+                    self.stream.next().unwrap();
+                    return !self.trailing_comma(end);
+                }
+                c if c == end => {
+                    self.stream.next().unwrap();
+                    return false;
+                }
+                ']' | '}' => {
+                    // A mismatched closing bracket is left for the enclosing container
+                    self.record(DeserializeError::unexpected(c, expected, position));
+                    return false;
+                }
+                c => {
+                    self.record(DeserializeError::unexpected(c, expected, position));
+                    if self.missing_delimiter() {
+                        return true;
+                    }
+
+                    self.stream.next().unwrap();
+                    self.resync();
+                }
+            }
+        }
+    }
+
+    /// Consumes `end` if it follows a ',', recording the trailing comma as an error, returning
+    /// if it did
+    fn trailing_comma(&mut self, end: char) -> bool {
+        match skip_whitespace(self.stream).and_then(|_| peek(self.stream)) {
+            Ok((c, position)) if c == end => {
+                self.record(DeserializeError::unexpected(c, "a value", position));
+                self.stream.next().unwrap();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks if the next character starts a value, in which case a delimiter before it is
+    /// assumed to be missing
+    fn missing_delimiter(&mut self) -> bool {
+        matches!(
+            skip_whitespace(self.stream).and_then(|_| peek(self.stream)),
+            Ok(('"' | '[' | '{' | '-' | '0'..='9' | 't' | 'f' | 'n', _))
+        )
+    }
+}
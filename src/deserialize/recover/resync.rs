@@ -0,0 +1,44 @@
+use super::Recoverer;
+use crate::DeserializeError;
+
+impl<'a, 'de> Recoverer<'a, 'de> {
+    /// Skips ahead to the next ',', ']' or '}' which isn't nested inside a string, array or
+    /// object, leaving it on the stream
+    pub(super) fn resync(&mut self) {
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+
+        loop {
+            let c = match self.stream.peek() {
+                Ok(Some(c)) => c,
+                Ok(None) => return,
+                Err(error) => {
+                    let position = self.stream.position();
+                    self.record(DeserializeError::new(error, position));
+                    self.stopped = true;
+                    return;
+                }
+            };
+
+            if in_string {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => in_string = false,
+                    _ => {}
+                }
+            } else {
+                match c {
+                    ',' | ']' | '}' if depth == 0 => return,
+                    '"' => in_string = true,
+                    '[' | '{' => depth += 1,
+                    ']' | '}' => depth -= 1,
+                    _ => {}
+                }
+            }
+
+            self.stream.next().unwrap();
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Finds the bytes the parsers need to look at a block at a time, like the first stage of
+//! simdjson. SIMD is used on `x86_64` and `aarch64`, with a scalar fallback for other targets and
+//! `no_std`.
+
+pub(crate) mod scalar;
+#[cfg(all(
+    not(feature = "no_std"),
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+mod simd;
+
+#[cfg(all(
+    not(feature = "no_std"),
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+use simd as imp;
+
+#[cfg(not(all(
+    not(feature = "no_std"),
+    any(target_arch = "x86_64", target_arch = "aarch64")
+)))]
+use scalar as imp;
+
+/// Gets the number of whitespace bytes at the start of `bytes`
+pub(crate) fn whitespace_len(bytes: &[u8]) -> usize {
+    imp::whitespace_len(bytes)
+}
+
+/// Gets the number of bytes at the start of `bytes` which are inside a string and need no
+/// handling, stopping at the first '"', '\' or control character
+pub(crate) fn string_len(bytes: &[u8]) -> usize {
+    imp::string_len(bytes)
+}
+
+/// Gets the number of bytes at the start of `bytes` which can be skipped inside an array or an
+/// object, stopping at the first '"', '\', '[', ']', '{' or '}'
+pub(crate) fn structural_len(bytes: &[u8]) -> usize {
+    imp::structural_len(bytes)
+}
@@ -0,0 +1,26 @@
+/// Gets the number of whitespace bytes at the start of `bytes`
+pub(crate) fn whitespace_len(bytes: &[u8]) -> usize {
+    count(bytes, |byte| matches!(byte, b' ' | b'\t' | b'\n' | b'\r'))
+}
+
+/// Gets the number of bytes at the start of `bytes` which are inside a string and need no
+/// handling
+pub(crate) fn string_len(bytes: &[u8]) -> usize {
+    count(bytes, |byte| !matches!(byte, b'\"' | b'\\' | 0x00..=0x1F))
+}
+
+/// Gets the number of bytes at the start of `bytes` which can be skipped inside an array or an
+/// object
+pub(crate) fn structural_len(bytes: &[u8]) -> usize {
+    count(bytes, |byte| {
+        !matches!(byte, b'\"' | b'\\' | b'[' | b']' | b'{' | b'}')
+    })
+}
+
+/// Counts the bytes at the start of `bytes` which match `predicate`
+fn count<F: Fn(u8) -> bool>(bytes: &[u8], predicate: F) -> usize {
+    bytes
+        .iter()
+        .position(|byte| !predicate(*byte))
+        .unwrap_or(bytes.len())
+}
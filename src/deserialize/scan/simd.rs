@@ -0,0 +1,190 @@
+use super::scalar;
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// The number of bytes checked at a time
+const BLOCK: usize = 16;
+
+/// Gets the number of whitespace bytes at the start of `bytes`
+pub(super) fn whitespace_len(bytes: &[u8]) -> usize {
+    // SAFETY: The vector helpers only need SSE2 or NEON, which every `x86_64` and `aarch64`
+    // target has
+    scan(bytes, scalar::whitespace_len, |block| unsafe {
+        let whitespace = or(
+            or(eq(block, b' '), eq(block, b'\t')),
+            or(eq(block, b'\n'), eq(block, b'\r')),
+        );
+        first(not(whitespace))
+    })
+}
+
+/// Gets the number of bytes at the start of `bytes` which are inside a string and need no
+/// handling
+pub(super) fn string_len(bytes: &[u8]) -> usize {
+    // SAFETY: The vector helpers only need SSE2 or NEON, which every `x86_64` and `aarch64`
+    // target has
+    scan(bytes, scalar::string_len, |block| unsafe {
+        first(or(
+            or(eq(block, b'\"'), eq(block, b'\\')),
+            at_most(block, 0x1F),
+        ))
+    })
+}
+
+/// Gets the number of bytes at the start of `bytes` which can be skipped inside an array or an
+/// object
+pub(super) fn structural_len(bytes: &[u8]) -> usize {
+    // SAFETY: The vector helpers only need SSE2 or NEON, which every `x86_64` and `aarch64`
+    // target has
+    scan(bytes, scalar::structural_len, |block| unsafe {
+        let brackets = or(eq(block, b'['), eq(block, b']'));
+        let braces = or(eq(block, b'{'), eq(block, b'}'));
+        first(or(
+            or(eq(block, b'\"'), eq(block, b'\\')),
+            or(brackets, braces),
+        ))
+    })
+}
+
+/// Runs `check` over each full block of `bytes`, which returns the index of the first byte which
+/// stops the scan, finishing any remaining bytes with `tail`
+fn scan<F: Fn(Vector) -> Option<usize>>(bytes: &[u8], tail: fn(&[u8]) -> usize, check: F) -> usize {
+    let mut offset = 0;
+    while offset + BLOCK <= bytes.len() {
+        // SAFETY: The loop only runs while `offset + BLOCK <= bytes.len()`, so the whole block read
+        // is inside `bytes`
+        let block = unsafe { load(bytes[offset..].as_ptr()) };
+        if let Some(index) = check(block) {
+            return offset + index;
+        }
+
+        offset += BLOCK;
+    }
+
+    offset + tail(&bytes[offset..])
+}
+
+#[cfg(target_arch = "x86_64")]
+type Vector = __m128i;
+
+#[cfg(target_arch = "aarch64")]
+type Vector = uint8x16_t;
+
+/// Loads a block from `pointer`
+///
+/// # Safety
+/// `pointer` must be valid for reads of [`BLOCK`] bytes. It doesn't need to be aligned.
+#[cfg(target_arch = "x86_64")]
+unsafe fn load(pointer: *const u8) -> Vector {
+    _mm_loadu_si128(pointer as *const __m128i)
+}
+
+/// Sets each byte of the result if the matching byte of `block` is `byte`
+///
+/// # Safety
+/// The target must support SSE2, which every `x86_64` target does.
+#[cfg(target_arch = "x86_64")]
+unsafe fn eq(block: Vector, byte: u8) -> Vector {
+    _mm_cmpeq_epi8(block, _mm_set1_epi8(byte as i8))
+}
+
+/// Sets each byte of the result if the matching byte of `block` is at most `byte`
+///
+/// # Safety
+/// The target must support SSE2, which every `x86_64` target does.
+#[cfg(target_arch = "x86_64")]
+unsafe fn at_most(block: Vector, byte: u8) -> Vector {
+    let limit = _mm_set1_epi8(byte as i8);
+    _mm_cmpeq_epi8(_mm_min_epu8(block, limit), block)
+}
+
+/// Sets each byte of the result if it is set in `a` or `b`
+///
+/// # Safety
+/// The target must support SSE2, which every `x86_64` target does.
+#[cfg(target_arch = "x86_64")]
+unsafe fn or(a: Vector, b: Vector) -> Vector {
+    _mm_or_si128(a, b)
+}
+
+/// Sets each byte of the result if it isn't set in `a`
+///
+/// # Safety
+/// The target must support SSE2, which every `x86_64` target does.
+#[cfg(target_arch = "x86_64")]
+unsafe fn not(a: Vector) -> Vector {
+    _mm_xor_si128(a, _mm_set1_epi8(-1))
+}
+
+/// Gets the index of the first set byte in `mask`
+///
+/// # Safety
+/// The target must support SSE2, which every `x86_64` target does.
+#[cfg(target_arch = "x86_64")]
+unsafe fn first(mask: Vector) -> Option<usize> {
+    match _mm_movemask_epi8(mask) {
+        0 => None,
+        bits => Some(bits.trailing_zeros() as usize),
+    }
+}
+
+/// Loads a block from `pointer`
+///
+/// # Safety
+/// `pointer` must be valid for reads of [`BLOCK`] bytes. It doesn't need to be aligned.
+#[cfg(target_arch = "aarch64")]
+unsafe fn load(pointer: *const u8) -> Vector {
+    vld1q_u8(pointer)
+}
+
+/// Sets each byte of the result if the matching byte of `block` is `byte`
+///
+/// # Safety
+/// The target must support NEON, which every `aarch64` target does.
+#[cfg(target_arch = "aarch64")]
+unsafe fn eq(block: Vector, byte: u8) -> Vector {
+    vceqq_u8(block, vdupq_n_u8(byte))
+}
+
+/// Sets each byte of the result if the matching byte of `block` is at most `byte`
+///
+/// # Safety
+/// The target must support NEON, which every `aarch64` target does.
+#[cfg(target_arch = "aarch64")]
+unsafe fn at_most(block: Vector, byte: u8) -> Vector {
+    vcleq_u8(block, vdupq_n_u8(byte))
+}
+
+/// Sets each byte of the result if it is set in `a` or `b`
+///
+/// # Safety
+/// The target must support NEON, which every `aarch64` target does.
+#[cfg(target_arch = "aarch64")]
+unsafe fn or(a: Vector, b: Vector) -> Vector {
+    vorrq_u8(a, b)
+}
+
+/// Sets each byte of the result if it isn't set in `a`
+///
+/// # Safety
+/// The target must support NEON, which every `aarch64` target does.
+#[cfg(target_arch = "aarch64")]
+unsafe fn not(a: Vector) -> Vector {
+    vmvnq_u8(a)
+}
+
+/// Gets the index of the first set byte in `mask`
+///
+/// # Safety
+/// The target must support NEON, which every `aarch64` target does.
+#[cfg(target_arch = "aarch64")]
+unsafe fn first(mask: Vector) -> Option<usize> {
+    // Narrowing each 16-bit lane by 4 leaves a nibble for each byte of the mask
+    let nibbles = vshrn_n_u16::<4>(vreinterpretq_u16_u8(mask));
+    match vget_lane_u64::<0>(vreinterpret_u64_u8(nibbles)) {
+        0 => None,
+        bits => Some(bits.trailing_zeros() as usize / 4),
+    }
+}
@@ -0,0 +1,51 @@
+use super::{PositionDeserializer, SpanMapDeserializer};
+use crate::{deserialize::Deserializer, DeserializeError, DeserializeErrorKind};
+use data_format::Deserialize;
+use lct_streams::Position;
+
+impl<'a, 'de> data_format::MapDeserializer<'de> for SpanMapDeserializer<'a, 'de> {
+    type Error = DeserializeError<'de>;
+
+    fn next_key<K: Deserialize<'de>>(&mut self) -> Result<Option<K>, Self::Error> {
+        Ok(None)
+    }
+
+    fn next_value<V: Deserialize<'de>>(&mut self) -> Result<V, Self::Error> {
+        let index = self.index;
+        self.index += 1;
+
+        match index {
+            0 => V::deserialize(PositionDeserializer(self.start.line())),
+            1 => V::deserialize(PositionDeserializer(self.start.column())),
+            2 => {
+                let value =
+                    V::deserialize(Deserializer::new(self.stream, self.options, self.last_keys))?;
+                self.end = Some(self.stream.position());
+                Ok(value)
+            }
+            3 => V::deserialize(PositionDeserializer(self.end()?.line())),
+            4 => V::deserialize(PositionDeserializer(self.end()?.column())),
+            _ => Err(DeserializeError::new(
+                DeserializeErrorKind::InvalidLength {
+                    unexpected: index + 1,
+                    expected: "a span of 5 values".into(),
+                },
+                self.start,
+            )),
+        }
+    }
+}
+
+impl<'a, 'de> SpanMapDeserializer<'a, 'de> {
+    /// Gets the end of the value, which is only known once the value has been deserialized
+    fn end(&self) -> Result<Position, DeserializeError<'de>> {
+        self.end.ok_or_else(|| {
+            DeserializeError::new(
+                DeserializeErrorKind::Custom(
+                    "the end of a span was requested before its value".into(),
+                ),
+                self.start,
+            )
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    deserialize::{CharStream, LastKeys},
+    DeserializeOptions,
+};
+use data_format::Converter;
+use lct_streams::Position;
+
+mod map;
+mod position;
+
+/// What the converter for [`Spanned`](crate::Spanned) writes in `expecting`, so the
+/// [`Deserializer`](super::Deserializer) knows to pass it positions
+///
+/// This is a private protocol between this crate's [`Deserializer`](super::Deserializer) and
+/// [`Spanned`](crate::Spanned), as `data_format` has no way to ask a converter what it is. Any
+/// other converter which writes exactly this string will be passed a [`SpanMapDeserializer`]
+/// instead of the value, and a converter which writes anything else is never passed one.
+pub(crate) const SPANNED_MAGIC: &str = "$json::private::Spanned";
+
+/// Passes a span to the converter for [`Spanned`](crate::Spanned) as a map whose values are, in
+/// order, the start line, start column, value, end line and end column
+pub(super) struct SpanMapDeserializer<'a, 'de> {
+    /// The stream to deserialize the value from
+    stream: &'a mut CharStream<'de>,
+
+    /// The options controlling deserialization
+    options: DeserializeOptions,
+
+    /// The position of the first character of the value
+    start: Position,
+
+    /// The position after the last character of the value, once it has been deserialized
+    end: Option<Position>,
+
+    /// The index of the next value to be passed
+    index: usize,
+
+    /// The keys of the objects in the value, if an enclosing object has scanned them
+    last_keys: Option<&'a LastKeys<'de>>,
+}
+
+/// Deserializes a single line or column number
+struct PositionDeserializer(usize);
+
+/// Is `converter` the converter for [`Spanned`](crate::Spanned)?
+pub(super) fn is_spanned<'de, C: Converter<'de>>(converter: &C) -> bool {
+    /// Displays the expecting message of a converter
+    struct Expecting<'c, C>(&'c C);
+
+    /// Checks if everything written matches [`SPANNED_MAGIC`]
+    struct Matcher {
+        /// The part of [`SPANNED_MAGIC`] not matched yet
+        remaining: &'static str,
+    }
+
+    impl<'c, 'de, C: Converter<'de>> core::fmt::Display for Expecting<'c, C> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            self.0.expecting(f)
+        }
+    }
+
+    impl core::fmt::Write for Matcher {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            match self.remaining.strip_prefix(s) {
+                Some(remaining) => {
+                    self.remaining = remaining;
+                    Ok(())
+                }
+                None => Err(core::fmt::Error),
+            }
+        }
+    }
+
+    let mut matcher = Matcher {
+        remaining: SPANNED_MAGIC,
+    };
+    core::fmt::write(&mut matcher, format_args!("{}", Expecting(converter))).is_ok()
+        && matcher.remaining.is_empty()
+}
+
+impl<'a, 'de> SpanMapDeserializer<'a, 'de> {
+    /// Creates a new [`SpanMapDeserializer`] for the value starting at `start`
+    pub(super) fn new(
+        stream: &'a mut CharStream<'de>,
+        options: DeserializeOptions,
+        last_keys: Option<&'a LastKeys<'de>>,
+        start: Position,
+    ) -> Self {
+        SpanMapDeserializer {
+            stream,
+            options,
+            start,
+            end: None,
+            index: 0,
+            last_keys,
+        }
+    }
+}
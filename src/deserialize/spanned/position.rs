@@ -0,0 +1,91 @@
+use super::PositionDeserializer;
+use crate::DeserializeError;
+use data_format::Converter;
+
+impl<'de> data_format::Deserializer<'de> for PositionDeserializer {
+    type Error = DeserializeError<'de>;
+
+    fn deserialize_any<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        converter.convert_usize(self.0)
+    }
+
+    fn deserialize_bool<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_i8<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_i16<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_i32<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_i64<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_i128<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_isize<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_u8<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_u16<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_u32<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_u64<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_u128<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_usize<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_f32<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_f64<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_string<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_unit<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_list<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_map<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+
+    fn deserialize_option<C: Converter<'de>>(self, converter: C) -> Result<C::Value, Self::Error> {
+        self.deserialize_any(converter)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    deserialize::{skip_whitespace, CharStream, Deserializer, Result},
+    DeserializeError, DeserializeOptions, StreamDeserializer, StreamMode,
+};
+use core::ops::Range;
+use data_format::Deserialize;
+
+impl<'de, T: Deserialize<'de>> StreamDeserializer<'de, T> {
+    /// Deserializes the next value from the stream when values are separated by whitespace or
+    /// concatenated
+    fn next_value(&mut self) -> Result<'de, Option<(T, Range<usize>)>> {
+        if self.mode == StreamMode::Whitespace && self.count > 0 {
+            match self.stream.peek_pos() {
+                Ok(Some((c, position))) if !matches!(c, ' ' | '\t' | '\n' | '\r') => {
+                    return Err(DeserializeError::unexpected(c, "whitespace", position))
+                }
+                Err(error) => return Err(DeserializeError::new(error, self.stream.position())),
+                _ => {}
+            }
+        }
+
+        skip_whitespace(&mut self.stream)?;
+        let start = self.stream.offset();
+        if start == self.stream.bytes().len() {
+            return Ok(None);
+        }
+
+        let value = deserialize(&mut self.stream, self.options)?;
+        self.count += 1;
+        Ok(Some((value, start..self.stream.offset())))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Iterator for StreamDeserializer<'de, T> {
+    type Item = Result<'de, (T, Range<usize>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = match self.mode {
+            StreamMode::RecordSeparated => self.next_record(),
+            _ => self.next_value().map_err(|mut error| {
+                self.done = true;
+                error.set_offset_from(
+                    self.stream.bytes(),
+                    self.stream.position(),
+                    self.stream.offset(),
+                );
+                error
+            }),
+        };
+
+        match result {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+/// Deserializes one value from `stream`
+pub(super) fn deserialize<'de, T: Deserialize<'de>>(
+    stream: &mut CharStream<'de>,
+    options: DeserializeOptions,
+) -> Result<'de, T> {
+    T::deserialize(Deserializer::new(stream, options, None)).inspect_err(|error| {
+        debug_assert!(error.position().is_some());
+    })
+}
@@ -0,0 +1,42 @@
+use crate::{deserialize::CharStream, DeserializeOptions};
+use core::marker::PhantomData;
+use lct_streams::Position;
+
+mod iter;
+mod mode;
+mod new;
+mod record;
+
+pub use mode::StreamMode;
+
+/// Deserializes a sequence of JSON values from one buffer as `T`s, yielding each value along with
+/// the range of bytes it was deserialized from.
+///
+/// How the values are separated is chosen by the [`StreamMode`]. In
+/// [`StreamMode::RecordSeparated`] a record which can't be deserialized is reported and skipped,
+/// otherwise iteration stops after the first error.
+pub struct StreamDeserializer<'de, T> {
+    /// The stream to deserialize from
+    stream: CharStream<'de>,
+
+    /// How values are separated
+    mode: StreamMode,
+
+    /// The options controlling deserialization
+    options: DeserializeOptions,
+
+    /// The number of values deserialized so far
+    count: usize,
+
+    /// The byte offset of the next record when the values are record separated
+    offset: usize,
+
+    /// The position of the next record when the values are record separated
+    position: Position,
+
+    /// Has the end of the input been reached or an unrecoverable error occurred?
+    done: bool,
+
+    /// The type of the values
+    _marker: PhantomData<fn() -> T>,
+}
@@ -0,0 +1,13 @@
+/// How the values read by a [`StreamDeserializer`](crate::StreamDeserializer) are separated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StreamMode {
+    /// Values are separated by at least one whitespace character, like `1 [2]\n{}`
+    #[default]
+    Whitespace,
+
+    /// Values may directly follow each other, like `{}{}[]`
+    Concatenated,
+
+    /// Each value is prefixed by the record separator 0x1E, as in an RFC 7464 JSON text sequence
+    RecordSeparated,
+}
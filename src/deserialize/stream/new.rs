@@ -0,0 +1,27 @@
+use crate::{deserialize::CharStream, DeserializeOptions, StreamDeserializer, StreamMode};
+use core::marker::PhantomData;
+use data_format::Deserialize;
+use lct_streams::Position;
+
+impl<'de, T: Deserialize<'de>> StreamDeserializer<'de, T> {
+    /// Creates a new [`StreamDeserializer`] over the values in `string`, separated as `mode`
+    /// describes
+    pub fn new(string: &'de str, mode: StreamMode) -> Self {
+        StreamDeserializer::from_bytes(string.as_bytes(), mode)
+    }
+
+    /// Creates a new [`StreamDeserializer`] over the values in `bytes`, separated as `mode`
+    /// describes
+    pub fn from_bytes(bytes: &'de [u8], mode: StreamMode) -> Self {
+        StreamDeserializer {
+            stream: CharStream::new(bytes),
+            mode,
+            options: DeserializeOptions::new(),
+            count: 0,
+            offset: 0,
+            position: Position::new(1, 1),
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+}
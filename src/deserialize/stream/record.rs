@@ -0,0 +1,110 @@
+use super::iter::deserialize;
+use crate::{
+    deserialize::{advance, peek, skip_whitespace, CharStream, Result},
+    DeserializeError, DeserializeErrorKind, DeserializeOptions, StreamDeserializer,
+};
+use core::ops::Range;
+use data_format::Deserialize;
+
+/// The byte which starts each record in a JSON text sequence
+const RECORD_SEPARATOR: u8 = 0x1E;
+
+impl<'de, T: Deserialize<'de>> StreamDeserializer<'de, T> {
+    /// Deserializes the value in the next record which isn't empty. Each record runs from a record
+    /// separator up to the next one, so an error only affects its own record.
+    pub(super) fn next_record(&mut self) -> Result<'de, Option<(T, Range<usize>)>> {
+        let bytes = self.stream.bytes();
+        loop {
+            if self.offset >= bytes.len() {
+                return Ok(None);
+            }
+
+            let start = self.offset;
+            let end = bytes[start + 1..]
+                .iter()
+                .position(|byte| *byte == RECORD_SEPARATOR)
+                .map(|index| start + 1 + index)
+                .unwrap_or(bytes.len());
+
+            let base = self.position;
+            self.offset = end;
+            self.position = advance(base, &bytes[start..end]);
+
+            // Anything before the first record separator is left unframed
+            let framed = bytes[start] == RECORD_SEPARATOR;
+            let (record_start, record_base) = match framed {
+                true => (start + 1, advance(base, &bytes[start..start + 1])),
+                false => (start, base),
+            };
+
+            let record = &bytes[record_start..end];
+            if record.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+
+            return match read_record(record, framed, self.options) {
+                Ok((value, range)) => Ok(Some((
+                    value,
+                    record_start + range.start..record_start + range.end,
+                ))),
+                Err(mut error) => {
+                    error.rebase(record_base, record_start);
+                    Err(error)
+                }
+            };
+        }
+    }
+}
+
+/// Deserializes the value in `record`, locating errors relative to the record
+fn read_record<'de, T: Deserialize<'de>>(
+    record: &'de [u8],
+    framed: bool,
+    options: DeserializeOptions,
+) -> Result<'de, (T, Range<usize>)> {
+    let mut stream = CharStream::new(record);
+    parse_record(&mut stream, framed, options).map_err(|mut error| {
+        error.set_offset_from(record, stream.position(), stream.offset());
+        error
+    })
+}
+
+/// Deserializes the value in the record on `stream`, returning it with its range in the record
+fn parse_record<'de, T: Deserialize<'de>>(
+    stream: &mut CharStream<'de>,
+    framed: bool,
+    options: DeserializeOptions,
+) -> Result<'de, (T, Range<usize>)> {
+    skip_whitespace(stream)?;
+    if !framed {
+        let (c, position) = peek(stream)?;
+        return Err(DeserializeError::unexpected(
+            c,
+            "a record separator",
+            position,
+        ));
+    }
+
+    let start = stream.offset();
+    let value = deserialize(stream, options)?;
+    let end = stream.offset();
+
+    // A number with nothing after it may have been truncated (RFC 7464 section 2.4)
+    if end == stream.bytes().len() && stream.bytes()[end - 1].is_ascii_digit() {
+        return Err(DeserializeError::new(
+            DeserializeErrorKind::UnexpectedEndOfJSON,
+            stream.position(),
+        ));
+    }
+
+    skip_whitespace(stream)?;
+    match stream.peek_pos() {
+        Ok(None) => Ok((value, start..end)),
+        Ok(Some((c, position))) => Err(DeserializeError::unexpected(
+            c,
+            "the end of the record",
+            position,
+        )),
+        Err(error) => Err(DeserializeError::new(error, stream.position())),
+    }
+}
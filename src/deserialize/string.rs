@@ -1,5 +1,5 @@
 use crate::{
-    deserialize::{expect, peek, skip_whitespace, Result},
+    deserialize::{expect, peek, scan, skip_whitespace, CharStream, Result},
     DeserializeError, DeserializeErrorKind,
 };
 #[cfg(feature = "no_std")]
@@ -7,13 +7,13 @@ use alloc::{
     borrow::{Cow, ToOwned},
     string::String,
 };
-use lct_streams::{Position, SliceByteCharStream};
+use lct_streams::Position;
 #[cfg(not(feature = "no_std"))]
 use std::borrow::Cow;
 
 /// Deserializes a string from `stream`, converting it to valid UTF-8 if needed
 pub(super) fn deserialize_string<'de>(
-    stream: &mut SliceByteCharStream<'de>,
+    stream: &mut CharStream<'de>,
 ) -> Result<'de, (Cow<'de, str>, Position)> {
     skip_whitespace(stream)?;
     expect(stream, '\"', "a string")?;
@@ -23,7 +23,12 @@ pub(super) fn deserialize_string<'de>(
     let start_offset = stream.offset();
     let mut owned = None;
 
-    while next_char(stream, start_offset, &mut owned)? {}
+    loop {
+        skip_plain(stream, &mut owned)?;
+        if !next_char(stream, start_offset, &mut owned)? {
+            break;
+        }
+    }
 
     // Get the contained string before moving over the ending '"'
     let borrowed = &stream.bytes()[start_offset..stream.offset()];
@@ -41,7 +46,7 @@ pub(super) fn deserialize_string<'de>(
 /// Creates a [`String`] from the previously parsed `stream`, starting at `start_index`, if one
 /// hasn't been already. It then returns a mutable reference to the owned string for use.
 fn create_owned<'a>(
-    stream: &mut SliceByteCharStream,
+    stream: &mut CharStream,
     start_offset: usize,
     owned: &'a mut Option<String>,
 ) -> &'a mut String {
@@ -54,9 +59,34 @@ fn create_owned<'a>(
     owned.as_mut().unwrap()
 }
 
+/// Moves over the characters at the front of the stream which need no handling, a block at a
+/// time, copying them into `owned` if needed
+fn skip_plain<'de>(stream: &mut CharStream<'de>, owned: &mut Option<String>) -> Result<'de, ()> {
+    let start = stream.offset();
+    let run = &stream.bytes()[start..start + scan::string_len(&stream.bytes()[start..])];
+
+    // The run ends at an ASCII byte, so only invalid UTF-8 can stop it inside a character. The
+    // stream is moved up to the invalid bytes so reading them reports the error.
+    let end = match core::str::from_utf8(run) {
+        Ok(_) => start + run.len(),
+        Err(error) => {
+            stream.skip(error.valid_up_to());
+            let pos = stream.position();
+            return Err(DeserializeError::new(stream.next().unwrap_err(), pos));
+        }
+    };
+    stream.skip(end - start);
+
+    if let Some(owned) = owned {
+        owned.push_str(unsafe { core::str::from_utf8_unchecked(&stream.bytes()[start..end]) });
+    }
+
+    Ok(())
+}
+
 /// Deserializes the next character from the stream
 fn next_char<'de>(
-    stream: &mut SliceByteCharStream<'de>,
+    stream: &mut CharStream<'de>,
     start_offset: usize,
     owned: &mut Option<String>,
 ) -> Result<'de, bool> {
@@ -81,7 +111,7 @@ fn next_char<'de>(
 
 /// Deserializes the next character as a '\' then an escape character
 fn escape<'de>(
-    stream: &mut SliceByteCharStream<'de>,
+    stream: &mut CharStream<'de>,
     start_offset: usize,
     owned: &mut Option<String>,
 ) -> Result<'de, ()> {
@@ -112,10 +142,7 @@ fn escape<'de>(
 }
 
 /// Deserializes a unicode escape sequence from `stream` and places it in `owned`
-fn unicode_escape<'de>(
-    stream: &mut SliceByteCharStream<'de>,
-    owned: &mut String,
-) -> Result<'de, ()> {
+fn unicode_escape<'de>(stream: &mut CharStream<'de>, owned: &mut String) -> Result<'de, ()> {
     let pos = stream.position();
     let offset = stream.offset();
     let value = get_four_hex(stream)?;
@@ -137,7 +164,7 @@ fn unicode_escape<'de>(
 /// Deserializes a high surrogate pair from the stream
 fn surrogate_pair_escape<'de>(
     high_surrogate: u32,
-    stream: &mut SliceByteCharStream<'de>,
+    stream: &mut CharStream<'de>,
     owned: &mut String,
 ) -> Result<'de, ()> {
     expect(stream, b'\\', "low UTF-16 surrogate")?;
@@ -161,7 +188,7 @@ fn surrogate_pair_escape<'de>(
 }
 
 /// Deserializes 4 hex digits from `stream` into a [`u16`]
-fn get_four_hex<'de>(stream: &mut SliceByteCharStream<'de>) -> Result<'de, u16> {
+fn get_four_hex<'de>(stream: &mut CharStream<'de>) -> Result<'de, u16> {
     let mut value = 0;
 
     for _ in 0..4 {
@@ -0,0 +1,11 @@
+use lct_streams::Position;
+
+/// Gets the position after `bytes` which start at `position`
+pub(crate) fn advance(position: Position, bytes: &[u8]) -> Position {
+    bytes.iter().fold(position, |position, byte| match byte {
+        b'\n' => Position::new(position.line() + 1, 1),
+        // UTF-8 continuation bytes are part of the previous character
+        0x80..=0xBF => position,
+        _ => Position::new(position.line(), position.column() + 1),
+    })
+}
@@ -1,9 +1,9 @@
-use crate::{DeserializeError, DeserializeErrorKind};
-use lct_streams::{CharExpect, SliceByteCharStream};
+use crate::{deserialize::CharStream, DeserializeError, DeserializeErrorKind};
+use lct_streams::CharExpect;
 
 /// Advance the stream validating it matches `predicate`
 pub(crate) fn expect<'de, E: CharExpect>(
-    stream: &mut SliceByteCharStream,
+    stream: &mut CharStream,
     expected: E,
     expected_display: &'static str,
 ) -> Result<(), DeserializeError<'de>> {
@@ -26,7 +26,8 @@ pub(crate) fn expect<'de, E: CharExpect>(
                     expected: expected_display,
                 },
                 pos,
-            ));
+            )
+            .with_end(stream.position()));
         }
     }
 
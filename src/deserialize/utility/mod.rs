@@ -1,7 +1,11 @@
+mod advance;
 mod expect;
 mod peek;
+mod skip_value;
 mod skip_whitespace;
 
+pub(crate) use advance::advance;
 pub(crate) use expect::expect;
 pub(crate) use peek::peek;
+pub(crate) use skip_value::skip_value;
 pub(crate) use skip_whitespace::skip_whitespace;
@@ -1,9 +1,9 @@
-use crate::{DeserializeError, DeserializeErrorKind};
-use lct_streams::{Position, SliceByteCharStream};
+use crate::{deserialize::CharStream, DeserializeError, DeserializeErrorKind};
+use lct_streams::Position;
 
 /// Get the next character from `stream`, without advancing it, returning an error if none is found
 pub(crate) fn peek<'de>(
-    stream: &mut SliceByteCharStream,
+    stream: &mut CharStream,
 ) -> Result<(char, Position), DeserializeError<'de>> {
     match stream.peek_pos() {
         Ok(Some(c)) => Ok(c),
@@ -0,0 +1,155 @@
+use crate::{
+    deserialize::{expect, number, peek, scan, skip_whitespace, string, CharStream},
+    DeserializeError,
+};
+
+/// Advances `stream` past the next JSON value, validating it without converting it
+pub(crate) fn skip_value<'de>(stream: &mut CharStream<'de>) -> Result<(), DeserializeError<'de>> {
+    skip_whitespace(stream)?;
+    match peek(stream)? {
+        ('t', _) => expect(stream, "true", "true"),
+        ('f', _) => expect(stream, "false", "false"),
+        ('n', _) => expect(stream, "null", "null"),
+        ('\"', _) => string::deserialize_string(stream).map(|_| ()),
+        ('[', _) => skip_container(stream, ']', false),
+        ('{', _) => skip_container(stream, '}', true),
+        ('-', _) => number::skip_number(stream),
+        (c, _) if c.is_ascii_digit() => number::skip_number(stream),
+        (c, pos) => Err(DeserializeError::unexpected(c, "valid JSON", pos)),
+    }
+}
+
+/// Advances `stream` past an array or an object, whose opening character is next on the stream
+fn skip_container<'de>(
+    stream: &mut CharStream<'de>,
+    end: char,
+    object: bool,
+) -> Result<(), DeserializeError<'de>> {
+    stream.next().unwrap();
+
+    skip_whitespace(stream)?;
+    if peek(stream)?.0 == end {
+        stream.next().unwrap();
+        return Ok(());
+    }
+
+    loop {
+        if object {
+            skip_whitespace(stream)?;
+            string::deserialize_string(stream)?;
+            skip_whitespace(stream)?;
+            expect(stream, ':', "a ':'")?;
+        }
+
+        // Scalars up to the next string or bracket are checked a block at a time, leaving anything
+        // else to be checked on the stream
+        let bytes = &stream.bytes()[stream.offset()..];
+        let (length, after_value) = flat_len(&bytes[..scan::structural_len(bytes)], object);
+        stream.skip(length);
+        if !after_value {
+            skip_value(stream)?;
+        }
+
+        skip_whitespace(stream)?;
+        match peek(stream)? {
+            (',', _) => {
+                stream.next().unwrap();
+            }
+            (c, _) if c == end => {
+                stream.next().unwrap();
+                return Ok(());
+            }
+            (c, pos) => {
+                return Err(DeserializeError::unexpected(
+                    c,
+                    if object { "',' or '}'" } else { "',' or ']'" },
+                    pos,
+                ))
+            }
+        }
+    }
+}
+
+/// Gets the length of the scalars, whitespace and separators at the start of `bytes`, where a
+/// value is expected, which are certain to be valid. Separators are only moved past in arrays, as
+/// a key follows them in objects. Also returns if the length ends after a value instead of where
+/// another value is expected.
+fn flat_len(bytes: &[u8], object: bool) -> (usize, bool) {
+    let mut valid = (0, false);
+    let mut index = 0;
+    loop {
+        index += scan::whitespace_len(&bytes[index..]);
+        match scalar_len(&bytes[index..]) {
+            Some(length) => index += length,
+            None => return valid,
+        }
+
+        index += scan::whitespace_len(&bytes[index..]);
+        valid = (index, true);
+        if object || bytes.get(index) != Some(&b',') {
+            return valid;
+        }
+
+        index += 1;
+        valid = (index, false);
+    }
+}
+
+/// Gets the length of the number or literal at the start of `bytes` if it is valid and ends where
+/// reading it from the stream would stop
+fn scalar_len(bytes: &[u8]) -> Option<usize> {
+    let length = match bytes.first()? {
+        b't' => literal_len(bytes, b"true")?,
+        b'f' => literal_len(bytes, b"false")?,
+        b'n' => literal_len(bytes, b"null")?,
+        _ => number_len(bytes)?,
+    };
+
+    match bytes.get(length) {
+        None | Some(b' ' | b'\t' | b'\n' | b'\r' | b',' | b':') => Some(length),
+        Some(_) => None,
+    }
+}
+
+/// Gets the length of `word` if `bytes` start with it
+fn literal_len(bytes: &[u8], word: &[u8]) -> Option<usize> {
+    bytes.starts_with(word).then_some(word.len())
+}
+
+/// Gets the length of the number at the start of `bytes` if it is valid
+fn number_len(bytes: &[u8]) -> Option<usize> {
+    let digits = |index: usize| {
+        bytes[index..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+    };
+
+    let mut index = usize::from(bytes.first() == Some(&b'-'));
+    match bytes.get(index)? {
+        b'0' => index += 1,
+        b'1'..=b'9' => index += digits(index),
+        _ => return None,
+    }
+
+    if bytes.get(index) == Some(&b'.') {
+        match digits(index + 1) {
+            0 => return None,
+            count => index += 1 + count,
+        }
+    }
+
+    if let Some(b'e' | b'E') = bytes.get(index) {
+        index += 1;
+        if let Some(b'+' | b'-') = bytes.get(index) {
+            index += 1;
+        }
+
+        match digits(index) {
+            0 => return None,
+            count => index += count,
+        }
+    }
+
+    Some(index)
+}
@@ -1,20 +1,14 @@
-use crate::DeserializeError;
-use lct_streams::SliceByteCharStream;
+use crate::{
+    deserialize::{scan, CharStream},
+    DeserializeError,
+};
 
 /// Skips whitespace in the stream, leaving the next non-whitespace character on the stream
-pub(crate) fn skip_whitespace<'de>(
-    stream: &mut SliceByteCharStream,
-) -> Result<(), DeserializeError<'de>> {
-    while let Some(c) = stream
-        .peek()
-        .map_err(|error| DeserializeError::new(error, stream.position()))?
-    {
-        match c {
-            ' ' | '\t' | '\n' | '\r' => {}
-            _ => break,
-        }
-        stream.next().unwrap();
-    }
+pub(crate) fn skip_whitespace<'de>(stream: &mut CharStream) -> Result<(), DeserializeError<'de>> {
+    stream.skip(scan::whitespace_len(&stream.bytes()[stream.offset()..]));
 
+    stream
+        .peek()
+        .map_err(|error| DeserializeError::new(error, stream.position()))?;
     Ok(())
 }
@@ -11,13 +11,39 @@
 extern crate alloc;
 
 mod deserialize;
+mod line_index;
+mod patch;
+mod path;
+mod pointer;
 mod serialize;
+mod spanned;
 mod value;
 
-pub use deserialize::{from_bytes, from_str, DeserializeError, DeserializeErrorKind};
+#[cfg(all(feature = "futures-io", not(feature = "no_std")))]
+pub use deserialize::from_async_read;
+pub use deserialize::{
+    from_bytes, from_bytes_recovering, from_bytes_with, from_str, from_str_recovering,
+    from_str_with, iter_array, iter_object, ArrayIter, DeserializeError, DeserializeErrorKind,
+    DeserializeOptions, DuplicateKeys, ErrorCategory, ErrorPath, ErrorSnippet, Event, LazyDocument,
+    LazyValue, ObjectIter, PushParser, Reader, Recovered, StreamDeserializer, StreamMode,
+};
+#[cfg(all(feature = "mmap", not(feature = "no_std")))]
+pub use deserialize::{from_file_mmap, Mappable, Mapped};
+#[cfg(not(feature = "no_std"))]
+pub use deserialize::{
+    iter_array_from_reader, iter_object_from_reader, ArrayReaderIter, JsonLinesReader,
+    ObjectReaderIter,
+};
+pub use line_index::LineIndex;
+pub use patch::{ArrayDiff, Patch, PatchError, PatchErrorKind, PatchOperation};
+pub use path::{JsonPath, NormalizedPath, PathElement, PathError, PathErrorKind, QueryNode};
+pub use pointer::{JsonPointer, PointerError};
+#[cfg(all(feature = "futures-io", not(feature = "no_std")))]
+pub use serialize::to_async_write;
 #[cfg(not(feature = "no_std"))]
 pub use serialize::{to_bytes, to_bytes_pretty, to_write, to_write_pretty};
-pub use serialize::{to_str, to_str_pretty, SerializeError};
+pub use serialize::{to_str, to_str_pretty, JsonLinesWriter, JsonWriter, SerializeError};
+pub use spanned::{Spanned, SpannedValue};
 pub use value::Value;
 
 pub use data_format;
@@ -0,0 +1,94 @@
+use crate::LineIndex;
+use lct_streams::Position;
+
+impl<'a> LineIndex<'a> {
+    /// Gets the number of lines in the input
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Gets the position of the character starting at `offset`. `offset` may be the length of the
+    /// input to get the position of its end.
+    pub fn position(&self, offset: usize) -> Option<Position> {
+        if offset > self.input.len() {
+            return None;
+        }
+
+        let line = self.lines.partition_point(|start| *start <= offset);
+        let prefix = &self.input[self.lines[line - 1]..offset];
+        let prefix = core::str::from_utf8(prefix).ok()?;
+        Some(Position::new(line, prefix.chars().count() + 1))
+    }
+
+    /// Gets the byte offset of `position`. The column after the last character of a line refers to
+    /// the line break, or the end of the input on the last line.
+    pub fn offset(&self, position: Position) -> Option<usize> {
+        let start = self.line_start(position.line())?;
+        let text = self.line(position.line())?;
+
+        let column = position.column().checked_sub(1)?;
+        match text.char_indices().nth(column) {
+            Some((offset, _)) => Some(start + offset),
+            None if column == text.chars().count() => Some(start + text.len()),
+            None => None,
+        }
+    }
+
+    /// Gets the column of `position` measured in UTF-16 code units, as used by LSP and most
+    /// JavaScript based editors. Like the column of a [`Position`], it starts at 1.
+    pub fn utf16_column(&self, position: Position) -> Option<usize> {
+        let text = self.line(position.line())?;
+
+        let column = position.column().checked_sub(1)?;
+        if column > text.chars().count() {
+            return None;
+        }
+        Some(
+            text.chars()
+                .take(column)
+                .map(char::len_utf16)
+                .sum::<usize>()
+                + 1,
+        )
+    }
+
+    /// Gets the position on `line` at the UTF-16 `column`, which starts at 1. Columns in the middle
+    /// of a surrogate pair don't refer to a position.
+    pub fn from_utf16(&self, line: usize, column: usize) -> Option<Position> {
+        let text = self.line(line)?;
+
+        let target = column.checked_sub(1)?;
+        let mut units = 0;
+        for (index, c) in text.chars().chain(core::iter::once('\n')).enumerate() {
+            if units == target {
+                return Some(Position::new(line, index + 1));
+            }
+            if units > target {
+                return None;
+            }
+            units += c.len_utf16();
+        }
+        None
+    }
+
+    /// Gets the byte offset of the start of `line`
+    fn line_start(&self, line: usize) -> Option<usize> {
+        self.lines.get(line.checked_sub(1)?).copied()
+    }
+
+    /// Gets the valid UTF-8 text of `line`, without its line break
+    fn line(&self, line: usize) -> Option<&'a str> {
+        let start = self.line_start(line)?;
+        let end = self
+            .lines
+            .get(line)
+            .map(|next| next - 1)
+            .unwrap_or(self.input.len());
+
+        let text = &self.input[start..end];
+        Some(match core::str::from_utf8(text) {
+            Ok(text) => text,
+            Err(error) => core::str::from_utf8(&text[..error.valid_up_to()]).unwrap(),
+        })
+    }
+}
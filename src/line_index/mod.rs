@@ -0,0 +1,19 @@
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+mod convert;
+mod new;
+
+/// Converts between byte offsets, line/column positions and UTF-16 columns in an input, so
+/// locations can be mapped into editor buffers.
+///
+/// Lines are separated by '\n' and, like [`Position`](lct_streams::Position), lines and columns
+/// start at 1 and columns count characters.
+#[derive(Debug, Clone)]
+pub struct LineIndex<'a> {
+    /// The input being indexed
+    input: &'a [u8],
+
+    /// The byte offset of the start of each line
+    lines: Vec<usize>,
+}
@@ -0,0 +1,25 @@
+use crate::LineIndex;
+#[cfg(feature = "no_std")]
+use alloc::vec;
+
+impl<'a> LineIndex<'a> {
+    /// Creates a new [`LineIndex`] over `input`
+    pub fn new(input: &'a str) -> Self {
+        LineIndex::from_bytes(input.as_bytes())
+    }
+
+    /// Creates a new [`LineIndex`] over `input`. Positions after the first invalid UTF-8
+    /// sequence on a line can't be converted.
+    pub fn from_bytes(input: &'a [u8]) -> Self {
+        let mut lines = vec![0];
+        lines.extend(
+            input
+                .iter()
+                .enumerate()
+                .filter(|(_, byte)| **byte == b'\n')
+                .map(|(offset, _)| offset + 1),
+        );
+
+        LineIndex { input, lines }
+    }
+}
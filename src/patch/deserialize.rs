@@ -0,0 +1,10 @@
+use crate::{Patch, PatchOperation};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use data_format::Deserialize;
+
+impl<'de> Deserialize<'de> for Patch<'de> {
+    fn deserialize<D: data_format::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<PatchOperation<'de>>::deserialize(deserializer).map(|operations| Patch { operations })
+    }
+}
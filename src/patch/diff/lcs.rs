@@ -0,0 +1,143 @@
+use super::{diff, ArrayDiff};
+use crate::{JsonPointer, PatchOperation, Value};
+#[cfg(feature = "no_std")]
+use alloc::{vec, vec::Vec};
+
+/// The largest table [`edit_script`] builds, in entries; larger arrays are compared by index
+const MAX_TABLE_LEN: usize = 1 << 22;
+
+/// A step in the edit script turning one array into another
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    /// The next old element is kept as the next new element
+    Keep,
+
+    /// The next old element is removed
+    Remove,
+
+    /// The next new element is inserted
+    Insert,
+}
+
+/// Appends the operations which turn the array `old` into `new` to `operations`, using the
+/// longest common subsequence of the two to detect insertions and removals
+pub(super) fn diff_lcs<'de>(
+    old: &[Value<'de>],
+    new: &[Value<'de>],
+    path: &mut JsonPointer,
+    operations: &mut Vec<PatchOperation<'de>>,
+) {
+    // Elements shared at the start and end of both arrays never need the table
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let edits = edit_script(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    );
+
+    let mut old = old[prefix..].iter();
+    let mut new = new[prefix..].iter();
+    let mut index = prefix;
+    let mut i = 0;
+    while i < edits.len() {
+        if edits[i] == Edit::Keep {
+            old.next();
+            new.next();
+            index += 1;
+            i += 1;
+            continue;
+        }
+
+        // Pair up a run of removals and insertions so changed elements are diffed in place
+        let run = edits[i..]
+            .iter()
+            .take_while(|edit| **edit != Edit::Keep)
+            .count();
+        let removed = edits[i..i + run]
+            .iter()
+            .filter(|edit| **edit == Edit::Remove)
+            .count();
+        let inserted = run - removed;
+        i += run;
+
+        for _ in 0..removed.min(inserted) {
+            path.push_index(index);
+            diff(
+                old.next().unwrap(),
+                new.next().unwrap(),
+                path,
+                ArrayDiff::Lcs,
+                operations,
+            );
+            path.pop();
+            index += 1;
+        }
+
+        for _ in inserted..removed {
+            old.next();
+            operations.push(PatchOperation::Remove {
+                path: path.join_index(index),
+            });
+        }
+
+        for _ in removed..inserted {
+            operations.push(PatchOperation::Add {
+                path: path.join_index(index),
+                value: new.next().unwrap().clone(),
+            });
+            index += 1;
+        }
+    }
+}
+
+/// Finds the shortest sequence of edits turning `old` into `new`, or pairs their elements by
+/// index if the table for that would be larger than [`MAX_TABLE_LEN`]
+fn edit_script(old: &[Value], new: &[Value]) -> Vec<Edit> {
+    let width = new.len() + 1;
+    let table_len = match (old.len() + 1).checked_mul(width) {
+        Some(table_len) if table_len <= MAX_TABLE_LEN => table_len,
+        _ => {
+            let mut edits = vec![Edit::Remove; old.len()];
+            edits.resize(old.len() + new.len(), Edit::Insert);
+            return edits;
+        }
+    };
+
+    // lengths[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lengths = vec![0usize; table_len];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::with_capacity(old.len() + new.len());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            edits.push(Edit::Keep);
+            i += 1;
+            j += 1;
+        } else if j == new.len()
+            || (i < old.len() && lengths[(i + 1) * width + j] >= lengths[i * width + j + 1])
+        {
+            edits.push(Edit::Remove);
+            i += 1;
+        } else {
+            edits.push(Edit::Insert);
+            j += 1;
+        }
+    }
+
+    edits
+}
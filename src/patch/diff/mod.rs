@@ -0,0 +1,96 @@
+use crate::{JsonPointer, PatchOperation, Value};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+mod lcs;
+
+/// How [`Value::diff_with`] compares arrays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArrayDiff {
+    /// Compare elements at the same index, replacing each element which differs and adding or
+    /// removing elements at the end
+    #[default]
+    Index,
+
+    /// Find the longest common subsequence of the two arrays, so that elements inserted or
+    /// removed in the middle of an array produce a single operation instead of changing every
+    /// element after them. Arrays too long to compare this way in bounded memory are compared
+    /// by index instead
+    Lcs,
+}
+
+/// Appends the operations which turn `old` into `new` to `operations`, where both are found at
+/// `path`
+pub(crate) fn diff<'de>(
+    old: &Value<'de>,
+    new: &Value<'de>,
+    path: &mut JsonPointer,
+    array_diff: ArrayDiff,
+    operations: &mut Vec<PatchOperation<'de>>,
+) {
+    if old == new {
+        return;
+    }
+
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for key in old.keys() {
+                if !new.contains_key(key) {
+                    operations.push(PatchOperation::Remove {
+                        path: path.join(key.as_ref()),
+                    });
+                }
+            }
+
+            for (key, new) in new {
+                path.push(key.as_ref());
+                match old.get(key) {
+                    Some(old) => diff(old, new, path, array_diff, operations),
+                    None => operations.push(PatchOperation::Add {
+                        path: path.clone(),
+                        value: new.clone(),
+                    }),
+                }
+                path.pop();
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => match array_diff {
+            ArrayDiff::Index => diff_index(old, new, path, operations),
+            ArrayDiff::Lcs => lcs::diff_lcs(old, new, path, operations),
+        },
+        _ => operations.push(PatchOperation::Replace {
+            path: path.clone(),
+            value: new.clone(),
+        }),
+    }
+}
+
+/// Appends the operations which turn the array `old` into `new` to `operations`, comparing the
+/// elements at each index
+fn diff_index<'de>(
+    old: &[Value<'de>],
+    new: &[Value<'de>],
+    path: &mut JsonPointer,
+    operations: &mut Vec<PatchOperation<'de>>,
+) {
+    let common = old.len().min(new.len());
+    for index in 0..common {
+        path.push_index(index);
+        diff(&old[index], &new[index], path, ArrayDiff::Index, operations);
+        path.pop();
+    }
+
+    // Remove from the back so earlier indices stay valid
+    for index in (common..old.len()).rev() {
+        operations.push(PatchOperation::Remove {
+            path: path.join_index(index),
+        });
+    }
+
+    for (index, value) in new.iter().enumerate().skip(common) {
+        operations.push(PatchOperation::Add {
+            path: path.join_index(index),
+            value: value.clone(),
+        });
+    }
+}
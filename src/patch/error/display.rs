@@ -0,0 +1,11 @@
+use crate::PatchError;
+
+impl core::fmt::Display for PatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "patch operation {} (\"{}\") failed at \"{}\": {}",
+            self.index, self.op, self.pointer, self.kind
+        )
+    }
+}
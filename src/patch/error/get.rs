@@ -0,0 +1,23 @@
+use crate::{JsonPointer, PatchError, PatchErrorKind};
+
+impl PatchError {
+    /// Gets the index of the operation which failed
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the name of the operation which failed
+    pub fn op(&self) -> &'static str {
+        self.op
+    }
+
+    /// Gets the pointer which couldn't be applied, either the operation's "path" or its "from"
+    pub fn pointer(&self) -> &JsonPointer {
+        &self.pointer
+    }
+
+    /// Gets why the operation failed
+    pub fn kind(&self) -> &PatchErrorKind {
+        &self.kind
+    }
+}
@@ -0,0 +1,32 @@
+use crate::PointerError;
+
+/// The reason a JSON [`Patch`](crate::Patch) operation failed
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchErrorKind {
+    /// A pointer couldn't be followed
+    Pointer(PointerError),
+
+    /// A "test" operation found a different value
+    TestFailed,
+
+    /// A "move" operation tried to move a value into one of its own children
+    MoveIntoChild,
+}
+
+impl From<PointerError> for PatchErrorKind {
+    fn from(error: PointerError) -> Self {
+        PatchErrorKind::Pointer(error)
+    }
+}
+
+impl core::fmt::Display for PatchErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PatchErrorKind::Pointer(error) => error.fmt(f),
+            PatchErrorKind::TestFailed => f.write_str("the value is not equal to the test value"),
+            PatchErrorKind::MoveIntoChild => {
+                f.write_str("a value cannot be moved into one of its children")
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::JsonPointer;
+
+mod display;
+mod get;
+mod kind;
+mod new;
+
+pub use kind::PatchErrorKind;
+
+/// An error that occurred while applying a JSON [`Patch`](crate::Patch)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatchError {
+    /// The index of the operation which failed
+    index: usize,
+
+    /// The name of the operation which failed
+    op: &'static str,
+
+    /// The pointer which couldn't be applied
+    pointer: JsonPointer,
+
+    /// Why the operation failed
+    kind: PatchErrorKind,
+}
+
+impl core::error::Error for PatchError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self.kind {
+            PatchErrorKind::Pointer(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{JsonPointer, PatchError, PatchErrorKind};
+
+impl PatchError {
+    /// Creates a new [`PatchError`]
+    pub(crate) fn new<K: Into<PatchErrorKind>>(
+        index: usize,
+        op: &'static str,
+        pointer: JsonPointer,
+        kind: K,
+    ) -> Self {
+        PatchError {
+            index,
+            op,
+            pointer,
+            kind: kind.into(),
+        }
+    }
+}
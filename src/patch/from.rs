@@ -0,0 +1,23 @@
+use crate::{Patch, PatchOperation};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+impl<'de> From<Vec<PatchOperation<'de>>> for Patch<'de> {
+    fn from(operations: Vec<PatchOperation<'de>>) -> Self {
+        Patch { operations }
+    }
+}
+
+impl<'de> From<Patch<'de>> for Vec<PatchOperation<'de>> {
+    fn from(patch: Patch<'de>) -> Self {
+        patch.operations
+    }
+}
+
+impl<'de> FromIterator<PatchOperation<'de>> for Patch<'de> {
+    fn from_iter<T: IntoIterator<Item = PatchOperation<'de>>>(iter: T) -> Self {
+        Patch {
+            operations: iter.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{Patch, PatchOperation};
+
+impl<'de> Patch<'de> {
+    /// Gets the operations making up this patch
+    pub fn operations(&self) -> &[PatchOperation<'de>] {
+        &self.operations
+    }
+
+    /// Gets the number of operations in this patch
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Does this patch contain no operations?
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+}
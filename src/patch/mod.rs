@@ -0,0 +1,22 @@
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+mod deserialize;
+mod diff;
+mod error;
+mod from;
+mod get;
+mod operation;
+mod serialize;
+
+pub(crate) use diff::diff;
+pub use diff::ArrayDiff;
+pub use error::{PatchError, PatchErrorKind};
+pub use operation::PatchOperation;
+
+/// A JSON Patch (RFC 6902) document, a sequence of operations to apply to a JSON value
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Patch<'de> {
+    /// The operations to apply, in order
+    operations: Vec<PatchOperation<'de>>,
+}
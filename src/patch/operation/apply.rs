@@ -0,0 +1,61 @@
+use crate::{JsonPointer, PatchError, PatchErrorKind, PatchOperation, Value};
+
+impl<'de> PatchOperation<'de> {
+    /// Applies this operation, the `index`th of its patch, to `document`. On failure `document`
+    /// may have been partially modified.
+    pub(crate) fn apply(&self, document: &mut Value<'de>, index: usize) -> Result<(), PatchError> {
+        let error = |pointer: &JsonPointer, kind: PatchErrorKind| {
+            PatchError::new(index, self.op(), pointer.clone(), kind)
+        };
+
+        match self {
+            PatchOperation::Add { path, value } => path
+                .insert(document, value.clone())
+                .map(|_| ())
+                .map_err(|e| error(path, e.into())),
+            PatchOperation::Remove { path } => path
+                .remove(document)
+                .map(|_| ())
+                .map_err(|e| error(path, e.into())),
+            PatchOperation::Replace { path, value } => {
+                *path
+                    .resolve_mut(document)
+                    .map_err(|e| error(path, e.into()))? = value.clone();
+                Ok(())
+            }
+            PatchOperation::Move { from, path } => {
+                if from == path {
+                    return from
+                        .resolve(document)
+                        .map(|_| ())
+                        .map_err(|e| error(from, e.into()));
+                }
+
+                if from.is_prefix_of(path) {
+                    return Err(error(path, PatchErrorKind::MoveIntoChild));
+                }
+
+                let value = from.remove(document).map_err(|e| error(from, e.into()))?;
+                path.insert(document, value)
+                    .map(|_| ())
+                    .map_err(|e| error(path, e.into()))
+            }
+            PatchOperation::Copy { from, path } => {
+                let value = from
+                    .resolve(document)
+                    .map_err(|e| error(from, e.into()))?
+                    .clone();
+                path.insert(document, value)
+                    .map(|_| ())
+                    .map_err(|e| error(path, e.into()))
+            }
+            PatchOperation::Test { path, value } => {
+                if path.resolve(document).map_err(|e| error(path, e.into()))? == value {
+                    Ok(())
+                } else {
+                    Err(error(path, PatchErrorKind::TestFailed))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{JsonPointer, PatchOperation, Value};
+#[cfg(feature = "no_std")]
+use alloc::borrow::Cow;
+use data_format::{Converter, Deserialize, DeserializeError, MapDeserializer};
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+/// Converts JSON objects into [`PatchOperation`]s
+struct PatchOperationConverter;
+
+impl<'de> Deserialize<'de> for PatchOperation<'de> {
+    fn deserialize<D: data_format::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(PatchOperationConverter)
+    }
+}
+
+impl<'de> Converter<'de> for PatchOperationConverter {
+    type Value = PatchOperation<'de>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("a JSON patch operation")
+    }
+
+    fn convert_map<M: MapDeserializer<'de>>(self, mut map: M) -> Result<Self::Value, M::Error> {
+        let mut op: Option<Cow<'de, str>> = None;
+        let mut path: Option<JsonPointer> = None;
+        let mut from: Option<JsonPointer> = None;
+        let mut value: Option<Value<'de>> = None;
+
+        while let Some(key) = map.next_key::<Cow<'de, str>>()? {
+            match key.as_ref() {
+                "op" => set_once(&mut op, "op", map.next_value()?)?,
+                "path" => set_once(&mut path, "path", map.next_value()?)?,
+                "from" => set_once(&mut from, "from", map.next_value()?)?,
+                "value" => set_once(&mut value, "value", map.next_value()?)?,
+                // Members that are not defined for an operation must be ignored
+                _ => {
+                    map.next_value::<Value<'de>>()?;
+                }
+            }
+        }
+
+        let op = op.ok_or_else(|| M::Error::missing_field("op"))?;
+        let path = path.ok_or_else(|| M::Error::missing_field("path"))?;
+        let from = || from.ok_or_else(|| M::Error::missing_field("from"));
+        let value = || value.ok_or_else(|| M::Error::missing_field("value"));
+
+        Ok(match op.as_ref() {
+            "add" => PatchOperation::Add {
+                path,
+                value: value()?,
+            },
+            "remove" => PatchOperation::Remove { path },
+            "replace" => PatchOperation::Replace {
+                path,
+                value: value()?,
+            },
+            "move" => PatchOperation::Move {
+                from: from()?,
+                path,
+            },
+            "copy" => PatchOperation::Copy {
+                from: from()?,
+                path,
+            },
+            "test" => PatchOperation::Test {
+                path,
+                value: value()?,
+            },
+            op => {
+                return Err(M::Error::custom(format_args!(
+                "unknown patch operation \"{}\", expected add, remove, replace, move, copy or test",
+                op
+            )))
+            }
+        })
+    }
+}
+
+/// Stores `value` in `slot`, returning an error if a value for `field` has already been stored
+fn set_once<'de, T, E: DeserializeError<'de>>(
+    slot: &mut Option<T>,
+    field: &'static str,
+    value: T,
+) -> Result<(), E> {
+    if slot.is_some() {
+        return Err(E::duplicate_field(field));
+    }
+
+    *slot = Some(value);
+    Ok(())
+}
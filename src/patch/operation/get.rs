@@ -0,0 +1,45 @@
+use crate::{JsonPointer, PatchOperation, Value};
+
+impl<'de> PatchOperation<'de> {
+    /// Gets the name of this operation as it appears in the "op" member
+    pub fn op(&self) -> &'static str {
+        match self {
+            PatchOperation::Add { .. } => "add",
+            PatchOperation::Remove { .. } => "remove",
+            PatchOperation::Replace { .. } => "replace",
+            PatchOperation::Move { .. } => "move",
+            PatchOperation::Copy { .. } => "copy",
+            PatchOperation::Test { .. } => "test",
+        }
+    }
+
+    /// Gets the location this operation targets
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            PatchOperation::Add { path, .. }
+            | PatchOperation::Remove { path }
+            | PatchOperation::Replace { path, .. }
+            | PatchOperation::Move { path, .. }
+            | PatchOperation::Copy { path, .. }
+            | PatchOperation::Test { path, .. } => path,
+        }
+    }
+
+    /// Gets the location this operation takes its value from, if it is a "move" or a "copy"
+    pub fn from(&self) -> Option<&JsonPointer> {
+        match self {
+            PatchOperation::Move { from, .. } | PatchOperation::Copy { from, .. } => Some(from),
+            _ => None,
+        }
+    }
+
+    /// Gets the value carried by this operation, if it is an "add", a "replace" or a "test"
+    pub fn value(&self) -> Option<&Value<'de>> {
+        match self {
+            PatchOperation::Add { value, .. }
+            | PatchOperation::Replace { value, .. }
+            | PatchOperation::Test { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+}
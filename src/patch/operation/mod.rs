@@ -0,0 +1,61 @@
+use crate::{JsonPointer, Value};
+
+mod apply;
+mod deserialize;
+mod get;
+mod serialize;
+
+/// A single operation in a JSON [`Patch`](crate::Patch)
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchOperation<'de> {
+    /// Adds `value` at `path`, inserting into arrays and replacing existing object members
+    Add {
+        /// Where to add the value
+        path: JsonPointer,
+
+        /// The value to add
+        value: Value<'de>,
+    },
+
+    /// Removes the value at `path`
+    Remove {
+        /// The value to remove
+        path: JsonPointer,
+    },
+
+    /// Replaces the existing value at `path` with `value`
+    Replace {
+        /// The value to replace
+        path: JsonPointer,
+
+        /// The replacement value
+        value: Value<'de>,
+    },
+
+    /// Removes the value at `from` and adds it at `path`
+    Move {
+        /// The value to move
+        from: JsonPointer,
+
+        /// Where to move the value to
+        path: JsonPointer,
+    },
+
+    /// Adds a copy of the value at `from` at `path`
+    Copy {
+        /// The value to copy
+        from: JsonPointer,
+
+        /// Where to add the copy
+        path: JsonPointer,
+    },
+
+    /// Checks that the value at `path` is equal to `value`
+    Test {
+        /// The value to check
+        path: JsonPointer,
+
+        /// The expected value
+        value: Value<'de>,
+    },
+}
@@ -0,0 +1,19 @@
+use crate::PatchOperation;
+use data_format::{MapSerializer, Serialize};
+
+impl<'de> Serialize for PatchOperation<'de> {
+    fn serialize<S: data_format::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = 2 + self.from().is_some() as usize + self.value().is_some() as usize;
+
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("op", self.op())?;
+        if let Some(from) = self.from() {
+            map.serialize_entry("from", from)?;
+        }
+        map.serialize_entry("path", self.path())?;
+        if let Some(value) = self.value() {
+            map.serialize_entry("value", value)?;
+        }
+        map.end()
+    }
+}
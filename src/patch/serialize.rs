@@ -0,0 +1,8 @@
+use crate::Patch;
+use data_format::Serialize;
+
+impl<'de> Serialize for Patch<'de> {
+    fn serialize<S: data_format::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.operations.serialize(serializer)
+    }
+}
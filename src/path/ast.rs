@@ -0,0 +1,158 @@
+use super::regex::Regex;
+use crate::Value;
+#[cfg(feature = "no_std")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+/// A segment of a query, selecting from the nodes produced by the previous segment
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Segment {
+    /// Applies the selectors to each node
+    Child(Vec<Selector>),
+
+    /// Applies the selectors to each node and all of its descendants
+    Descendant(Vec<Selector>),
+}
+
+/// A selector in a segment, selecting children of a node
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Selector {
+    /// Selects the member with the given name from an object
+    Name(String),
+
+    /// Selects every child of an array or object
+    Wildcard,
+
+    /// Selects an element of an array, counting from the end if negative
+    Index(i64),
+
+    /// Selects a range of elements from an array
+    Slice {
+        /// The first index of the range
+        start: Option<i64>,
+
+        /// The index after the last in the range
+        end: Option<i64>,
+
+        /// The distance between selected indices
+        step: Option<i64>,
+    },
+
+    /// Selects the children of a node for which the expression is true
+    Filter(LogicalExpr),
+}
+
+/// A query used inside of a filter expression
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct FilterQuery {
+    /// Does this query start from the root (`$`) rather than the current node (`@`)?
+    pub(super) root: bool,
+
+    /// The segments of the query
+    pub(super) segments: Vec<Segment>,
+}
+
+/// An expression producing a logical value
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum LogicalExpr {
+    /// True if any of the expressions are true
+    Or(Vec<LogicalExpr>),
+
+    /// True if all of the expressions are true
+    And(Vec<LogicalExpr>),
+
+    /// Inverts the expression
+    Not(Box<LogicalExpr>),
+
+    /// Compares two values
+    Comparison(Comparable, ComparisonOp, Comparable),
+
+    /// True if the query selects at least one node
+    Exists(FilterQuery),
+
+    /// A function producing a logical value
+    Function(Function),
+}
+
+/// A value which can be compared
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Comparable {
+    /// A literal value
+    Literal(Value<'static>),
+
+    /// A query selecting at most one node
+    Query(FilterQuery),
+
+    /// A function producing a value
+    Function(Box<Function>),
+}
+
+/// A comparison operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ComparisonOp {
+    /// `==`
+    Equal,
+
+    /// `!=`
+    NotEqual,
+
+    /// `<`
+    Less,
+
+    /// `<=`
+    LessEqual,
+
+    /// `>`
+    Greater,
+
+    /// `>=`
+    GreaterEqual,
+}
+
+/// A call to one of the standard function extensions
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Function {
+    /// `length(value)`, the length of a string, array or object
+    Length(Comparable),
+
+    /// `count(nodes)`, the number of nodes selected by a query
+    Count(FilterQuery),
+
+    /// `match(string, regex)`, does the whole string match the regular expression?
+    Match(Comparable, Pattern),
+
+    /// `search(string, regex)`, does any substring match the regular expression?
+    Search(Comparable, Pattern),
+
+    /// `value(nodes)`, the value of the only node selected by a query
+    Value(FilterQuery),
+}
+
+/// The regular expression passed to `match()` or `search()`
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Pattern {
+    /// A literal, compiled when the query is parsed, or [`None`] if it isn't a valid I-Regexp
+    Compiled(Option<Regex>),
+
+    /// A value only known once the filter is evaluated, compiled each time
+    Dynamic(Comparable),
+}
+
+impl FilterQuery {
+    /// Can this query select at most one node?
+    pub(super) fn is_singular(&self) -> bool {
+        self.segments.iter().all(|segment| match segment {
+            Segment::Child(selectors) => {
+                selectors.len() == 1
+                    && matches!(selectors[0], Selector::Name(_) | Selector::Index(_))
+            }
+            Segment::Descendant(_) => false,
+        })
+    }
+}
+
+impl Function {
+    /// Does this function produce a logical value rather than a JSON value?
+    pub(super) fn is_logical(&self) -> bool {
+        matches!(self, Function::Match(_, _) | Function::Search(_, _))
+    }
+}
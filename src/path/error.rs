@@ -0,0 +1,98 @@
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+
+/// An error that occurred while parsing a [`JsonPath`](crate::JsonPath)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathError {
+    /// The byte offset in the query where the error occurred
+    offset: usize,
+
+    /// The kind of error that occurred
+    kind: PathErrorKind,
+}
+
+/// A kind of error that can occur while parsing a [`JsonPath`](crate::JsonPath)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathErrorKind {
+    /// The query ended unexpectedly
+    UnexpectedEnd,
+
+    /// An unexpected character was encountered
+    Unexpected {
+        /// The unexpected character
+        unexpected: char,
+
+        /// What was expected instead
+        expected: &'static str,
+    },
+
+    /// An integer is outside of the range `-(2^53 - 1)..=(2^53 - 1)` or has leading zeros
+    InvalidInteger,
+
+    /// A number literal is malformed
+    InvalidNumber,
+
+    /// A string literal contains an invalid escape sequence
+    InvalidEscape,
+
+    /// A function which isn't one of the standard extensions was called
+    UnknownFunction(String),
+
+    /// A function was called with the wrong number or types of arguments
+    InvalidArguments(&'static str),
+
+    /// A query which can select more than one node was used where a single value is needed
+    NonSingularQuery,
+
+    /// A function producing a JSON value was used where a logical value is needed, or the other
+    /// way around
+    TypeMismatch,
+}
+
+impl PathError {
+    /// Creates a new [`PathError`]
+    pub(crate) fn new(offset: usize, kind: PathErrorKind) -> Self {
+        PathError { offset, kind }
+    }
+
+    /// Gets the byte offset in the query where the error occurred
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Gets the kind of error that occurred
+    pub fn kind(&self) -> &PathErrorKind {
+        &self.kind
+    }
+}
+
+impl core::error::Error for PathError {}
+
+impl core::fmt::Display for PathError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+impl core::fmt::Display for PathErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PathErrorKind::UnexpectedEnd => f.write_str("unexpected end of JSONPath query"),
+            PathErrorKind::Unexpected {
+                unexpected,
+                expected,
+            } => write!(f, "unexpected '{}', expected {}", unexpected, expected),
+            PathErrorKind::InvalidInteger => f.write_str("invalid integer"),
+            PathErrorKind::InvalidNumber => f.write_str("invalid number"),
+            PathErrorKind::InvalidEscape => f.write_str("invalid escape sequence"),
+            PathErrorKind::UnknownFunction(name) => write!(f, "unknown function \"{}\"", name),
+            PathErrorKind::InvalidArguments(name) => {
+                write!(f, "invalid arguments to function \"{}\"", name)
+            }
+            PathErrorKind::NonSingularQuery => {
+                f.write_str("a query which can select more than one node cannot be used here")
+            }
+            PathErrorKind::TypeMismatch => f.write_str("the function's result cannot be used here"),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use super::select;
+use crate::{
+    path::{
+        ast::{Comparable, ComparisonOp, FilterQuery, Function, LogicalExpr, Pattern},
+        regex::Regex,
+        QueryNode,
+    },
+    Value,
+};
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, vec::Vec};
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+/// Evaluates `expression` for the node `current`
+pub(super) fn test<'a, 'de>(
+    expression: &'a LogicalExpr,
+    root: &'a Value<'de>,
+    current: &'a Value<'de>,
+) -> bool {
+    match expression {
+        LogicalExpr::Or(expressions) => expressions
+            .iter()
+            .any(|expression| test(expression, root, current)),
+        LogicalExpr::And(expressions) => expressions
+            .iter()
+            .all(|expression| test(expression, root, current)),
+        LogicalExpr::Not(expression) => !test(expression, root, current),
+        LogicalExpr::Comparison(left, op, right) => {
+            let left = evaluate(left, root, current);
+            let right = evaluate(right, root, current);
+            compare(left.as_deref(), *op, right.as_deref())
+        }
+        LogicalExpr::Exists(query) => !nodes(query, root, current).is_empty(),
+        LogicalExpr::Function(function) => match function {
+            Function::Match(string, regex) => {
+                matches(string, regex, root, current, |regex, string| {
+                    regex.is_match(string)
+                })
+            }
+            Function::Search(string, regex) => {
+                matches(string, regex, root, current, |regex, string| {
+                    regex.find(string)
+                })
+            }
+            _ => false,
+        },
+    }
+}
+
+/// Evaluates `comparable`, returning [`None`] if it produces nothing
+fn evaluate<'a, 'de>(
+    comparable: &'a Comparable,
+    root: &'a Value<'de>,
+    current: &'a Value<'de>,
+) -> Option<Cow<'a, Value<'de>>> {
+    match comparable {
+        Comparable::Literal(value) => Some(Cow::Borrowed(value)),
+        Comparable::Query(query) => nodes(query, root, current)
+            .pop()
+            .map(|node| Cow::Borrowed(node.value())),
+        Comparable::Function(function) => match function.as_ref() {
+            Function::Length(value) => {
+                let length = match evaluate(value, root, current)?.as_ref() {
+                    Value::String(string) => string.chars().count(),
+                    Value::Array(array) => array.len(),
+                    Value::Object(object) => object.len(),
+                    _ => return None,
+                };
+                Some(Cow::Owned(Value::Number(length as f64)))
+            }
+            Function::Count(query) => Some(Cow::Owned(Value::Number(
+                nodes(query, root, current).len() as f64,
+            ))),
+            Function::Value(query) => {
+                let mut nodes = nodes(query, root, current);
+                match nodes.len() {
+                    1 => nodes.pop().map(|node| Cow::Borrowed(node.value())),
+                    _ => None,
+                }
+            }
+            Function::Match(_, _) | Function::Search(_, _) => None,
+        },
+    }
+}
+
+/// Selects the nodes of `query`
+fn nodes<'a, 'de>(
+    query: &FilterQuery,
+    root: &'a Value<'de>,
+    current: &'a Value<'de>,
+) -> Vec<QueryNode<'a, 'de>> {
+    select(
+        &query.segments,
+        root,
+        if query.root { root } else { current },
+    )
+}
+
+/// Evaluates `string` and `pattern`, checking them with `check` if `string` is a string and
+/// `pattern` is a valid I-Regexp
+fn matches<'a, 'de>(
+    string: &'a Comparable,
+    pattern: &'a Pattern,
+    root: &'a Value<'de>,
+    current: &'a Value<'de>,
+    check: impl FnOnce(&Regex, &str) -> bool,
+) -> bool {
+    let string = match evaluate(string, root, current) {
+        Some(string) => string,
+        None => return false,
+    };
+    let string = match string.as_ref() {
+        Value::String(string) => string,
+        _ => return false,
+    };
+
+    match pattern {
+        Pattern::Compiled(regex) => regex.as_ref().is_some_and(|regex| check(regex, string)),
+        Pattern::Dynamic(regex) => match evaluate(regex, root, current).as_deref() {
+            Some(Value::String(regex)) => {
+                Regex::new(regex).is_some_and(|regex| check(&regex, string))
+            }
+            _ => false,
+        },
+    }
+}
+
+/// Compares `left` and `right`, where [`None`] represents an empty result
+fn compare(left: Option<&Value>, op: ComparisonOp, right: Option<&Value>) -> bool {
+    match op {
+        ComparisonOp::Equal => equal(left, right),
+        ComparisonOp::NotEqual => !equal(left, right),
+        ComparisonOp::Less => less(left, right),
+        ComparisonOp::LessEqual => less(left, right) || equal(left, right),
+        ComparisonOp::Greater => less(right, left),
+        ComparisonOp::GreaterEqual => less(right, left) || equal(left, right),
+    }
+}
+
+/// Are `left` and `right` equal?
+fn equal(left: Option<&Value>, right: Option<&Value>) -> bool {
+    match (left, right) {
+        (None, None) => true,
+        (Some(left), Some(right)) => left == right,
+        _ => false,
+    }
+}
+
+/// Is `left` less than `right`? Only numbers and strings can be ordered.
+fn less(left: Option<&Value>, right: Option<&Value>) -> bool {
+    match (left, right) {
+        (Some(Value::Number(left)), Some(Value::Number(right))) => left < right,
+        (Some(Value::String(left)), Some(Value::String(right))) => left < right,
+        _ => false,
+    }
+}
@@ -0,0 +1,124 @@
+use super::{
+    ast::{Segment, Selector},
+    NormalizedPath, PathElement, QueryNode,
+};
+use crate::{JsonPath, Value};
+#[cfg(feature = "no_std")]
+use alloc::{string::ToString, vec, vec::Vec};
+
+mod filter;
+mod slice;
+
+impl JsonPath {
+    /// Selects the nodes of `root` matched by this query, in the order they were selected
+    pub fn query<'a, 'de>(&self, root: &'a Value<'de>) -> Vec<QueryNode<'a, 'de>> {
+        select(&self.segments, root, root)
+    }
+}
+
+/// Applies `segments` to `start`, with `root` being the value `$` refers to
+fn select<'a, 'de>(
+    segments: &[Segment],
+    root: &'a Value<'de>,
+    start: &'a Value<'de>,
+) -> Vec<QueryNode<'a, 'de>> {
+    let mut nodes = vec![QueryNode::new(NormalizedPath::default(), start)];
+
+    for segment in segments {
+        let mut selected = Vec::new();
+        for node in &nodes {
+            match segment {
+                Segment::Child(selectors) => select_children(selectors, root, node, &mut selected),
+                Segment::Descendant(selectors) => {
+                    select_descendants(selectors, root, node, &mut selected)
+                }
+            }
+        }
+        nodes = selected;
+    }
+
+    nodes
+}
+
+/// Applies `selectors` to `node` and each of its descendants, in document order
+fn select_descendants<'a, 'de>(
+    selectors: &[Selector],
+    root: &'a Value<'de>,
+    node: &QueryNode<'a, 'de>,
+    selected: &mut Vec<QueryNode<'a, 'de>>,
+) {
+    select_children(selectors, root, node, selected);
+
+    for child in children(node) {
+        select_descendants(selectors, root, &child, selected);
+    }
+}
+
+/// Applies each of `selectors` to `node`
+fn select_children<'a, 'de>(
+    selectors: &[Selector],
+    root: &'a Value<'de>,
+    node: &QueryNode<'a, 'de>,
+    selected: &mut Vec<QueryNode<'a, 'de>>,
+) {
+    for selector in selectors {
+        match (selector, node.value()) {
+            (Selector::Name(name), Value::Object(object)) => {
+                if let Some(value) = object.get(name.as_str()) {
+                    selected.push(QueryNode::new(
+                        node.path().join(PathElement::Name(name.clone())),
+                        value,
+                    ));
+                }
+            }
+            (Selector::Index(index), Value::Array(array)) => {
+                let index = match *index {
+                    index if index < 0 => array.len().checked_sub(index.unsigned_abs() as usize),
+                    index => Some(index as usize),
+                };
+
+                if let Some((index, value)) = index.and_then(|i| Some((i, array.get(i)?))) {
+                    selected.push(QueryNode::new(
+                        node.path().join(PathElement::Index(index)),
+                        value,
+                    ));
+                }
+            }
+            (Selector::Slice { start, end, step }, Value::Array(array)) => {
+                for index in slice::indices(*start, *end, *step, array.len()) {
+                    selected.push(QueryNode::new(
+                        node.path().join(PathElement::Index(index)),
+                        &array[index],
+                    ));
+                }
+            }
+            (Selector::Wildcard, _) => selected.extend(children(node)),
+            (Selector::Filter(expression), _) => selected.extend(
+                children(node).filter(|child| filter::test(expression, root, child.value())),
+            ),
+            _ => {}
+        }
+    }
+}
+
+/// Gets the children of `node` in document order
+fn children<'b, 'a, 'de>(
+    node: &'b QueryNode<'a, 'de>,
+) -> impl Iterator<Item = QueryNode<'a, 'de>> + 'b {
+    let (array, object) = match node.value() {
+        Value::Array(array) => (Some(array), None),
+        Value::Object(object) => (None, Some(object)),
+        _ => (None, None),
+    };
+
+    let elements = array
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, value)| QueryNode::new(node.path().join(PathElement::Index(index)), value));
+    let members = object.into_iter().flatten().map(|(name, value)| {
+        QueryNode::new(node.path().join(PathElement::Name(name.to_string())), value)
+    });
+
+    elements.chain(members)
+}
@@ -0,0 +1,37 @@
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+/// Gets the indices selected by a slice from an array of length `len` (RFC 9535 section 2.3.4.2)
+pub(super) fn indices(
+    start: Option<i64>,
+    end: Option<i64>,
+    step: Option<i64>,
+    len: usize,
+) -> Vec<usize> {
+    let len = len as i64;
+    let step = step.unwrap_or(1);
+    let normalize = |index: i64| if index >= 0 { index } else { len + index };
+
+    let mut indices = Vec::new();
+    if step > 0 {
+        let lower = normalize(start.unwrap_or(0)).clamp(0, len);
+        let upper = normalize(end.unwrap_or(len)).clamp(0, len);
+
+        let mut index = lower;
+        while index < upper {
+            indices.push(index as usize);
+            index += step;
+        }
+    } else if step < 0 {
+        let upper = start.map(normalize).unwrap_or(len - 1).clamp(-1, len - 1);
+        let lower = end.map(normalize).unwrap_or(-1).clamp(-1, len - 1);
+
+        let mut index = upper;
+        while lower < index {
+            indices.push(index as usize);
+            index += step;
+        }
+    }
+
+    indices
+}
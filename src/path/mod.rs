@@ -0,0 +1,20 @@
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use ast::Segment;
+
+mod ast;
+mod error;
+mod evaluate;
+mod node;
+mod parse;
+mod regex;
+
+pub use error::{PathError, PathErrorKind};
+pub use node::{NormalizedPath, PathElement, QueryNode};
+
+/// A parsed JSONPath (RFC 9535) query which selects nodes from a JSON value
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    /// The segments applied, in order, starting from the root
+    segments: Vec<Segment>,
+}
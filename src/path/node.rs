@@ -0,0 +1,105 @@
+use crate::{JsonPointer, Value};
+#[cfg(feature = "no_std")]
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+/// A node selected by a [`JsonPath`](crate::JsonPath) query
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryNode<'a, 'de> {
+    /// The location of the node in the queried value
+    path: NormalizedPath,
+
+    /// The selected value
+    value: &'a Value<'de>,
+}
+
+/// The normalized path (RFC 9535 section 2.7) which uniquely identifies a node in a value
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct NormalizedPath {
+    /// The member names and array indices leading to the node
+    elements: Vec<PathElement>,
+}
+
+/// A single step in a [`NormalizedPath`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PathElement {
+    /// A member of an object
+    Name(String),
+
+    /// An element of an array
+    Index(usize),
+}
+
+impl<'a, 'de> QueryNode<'a, 'de> {
+    /// Creates a new [`QueryNode`]
+    pub(super) fn new(path: NormalizedPath, value: &'a Value<'de>) -> Self {
+        QueryNode { path, value }
+    }
+
+    /// Gets the location of the node in the queried value
+    pub fn path(&self) -> &NormalizedPath {
+        &self.path
+    }
+
+    /// Gets the selected value
+    pub fn value(&self) -> &'a Value<'de> {
+        self.value
+    }
+}
+
+impl NormalizedPath {
+    /// Gets the member names and array indices leading to the node
+    pub fn elements(&self) -> &[PathElement] {
+        &self.elements
+    }
+
+    /// Converts this path into the equivalent [`JsonPointer`]
+    pub fn to_pointer(&self) -> JsonPointer {
+        let mut pointer = JsonPointer::new();
+        for element in &self.elements {
+            match element {
+                PathElement::Name(name) => pointer.push(name.as_str()),
+                PathElement::Index(index) => pointer.push_index(*index),
+            }
+        }
+        pointer
+    }
+
+    /// Creates a new path by appending `element` to this one
+    pub(super) fn join(&self, element: PathElement) -> NormalizedPath {
+        let mut elements = Vec::with_capacity(self.elements.len() + 1);
+        elements.extend_from_slice(&self.elements);
+        elements.push(element);
+        NormalizedPath { elements }
+    }
+}
+
+impl core::fmt::Display for NormalizedPath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_char('$')?;
+        for element in &self.elements {
+            match element {
+                PathElement::Index(index) => write!(f, "[{}]", index)?,
+                PathElement::Name(name) => {
+                    f.write_str("['")?;
+                    for c in name.chars() {
+                        match c {
+                            '\x08' => f.write_str("\\b")?,
+                            '\x0C' => f.write_str("\\f")?,
+                            '\n' => f.write_str("\\n")?,
+                            '\r' => f.write_str("\\r")?,
+                            '\t' => f.write_str("\\t")?,
+                            '\'' => f.write_str("\\'")?,
+                            '\\' => f.write_str("\\\\")?,
+                            c if c < ' ' => write!(f, "\\u{:04x}", c as u32)?,
+                            c => f.write_char(c)?,
+                        }
+                    }
+                    f.write_str("']")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
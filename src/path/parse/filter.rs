@@ -0,0 +1,271 @@
+use super::Parser;
+use crate::{
+    path::{
+        ast::{Comparable, ComparisonOp, FilterQuery, Function, LogicalExpr, Pattern},
+        regex::Regex,
+    },
+    PathError, PathErrorKind, Value,
+};
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, boxed::Box, string::ToString, vec::Vec};
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+/// An operand in a filter expression before its use is known
+enum Operand {
+    /// A relative or absolute query
+    Query(FilterQuery),
+
+    /// A function call
+    Function(Function),
+
+    /// A literal value
+    Literal(Value<'static>),
+}
+
+impl<'a> Parser<'a> {
+    /// Parses expressions separated by "||"
+    pub(super) fn parse_logical_or(&mut self) -> Result<LogicalExpr, PathError> {
+        let mut expressions = Vec::new();
+        loop {
+            expressions.push(self.parse_logical_and()?);
+
+            let end = self.offset;
+            self.skip_whitespace();
+            if !self.eat_str("||") {
+                self.offset = end;
+                break;
+            }
+            self.skip_whitespace();
+        }
+
+        Ok(match expressions.len() {
+            1 => expressions.pop().unwrap(),
+            _ => LogicalExpr::Or(expressions),
+        })
+    }
+
+    /// Parses expressions separated by "&&"
+    fn parse_logical_and(&mut self) -> Result<LogicalExpr, PathError> {
+        let mut expressions = Vec::new();
+        loop {
+            expressions.push(self.parse_basic_expr()?);
+
+            let end = self.offset;
+            self.skip_whitespace();
+            if !self.eat_str("&&") {
+                self.offset = end;
+                break;
+            }
+            self.skip_whitespace();
+        }
+
+        Ok(match expressions.len() {
+            1 => expressions.pop().unwrap(),
+            _ => LogicalExpr::And(expressions),
+        })
+    }
+
+    /// Parses a parenthesized expression, a comparison or a test expression
+    fn parse_basic_expr(&mut self) -> Result<LogicalExpr, PathError> {
+        if self.eat('!') {
+            self.skip_whitespace();
+            let expression = match self.peek() {
+                Some('(') => self.parse_paren_expr()?,
+                _ => {
+                    let start = self.offset;
+                    let operand = self.parse_operand()?;
+                    self.to_test_expr(operand, start)?
+                }
+            };
+            return Ok(LogicalExpr::Not(Box::new(expression)));
+        }
+
+        if self.peek() == Some('(') {
+            return self.parse_paren_expr();
+        }
+
+        let start = self.offset;
+        let operand = self.parse_operand()?;
+
+        let end = self.offset;
+        self.skip_whitespace();
+        let op = match self.parse_comparison_op() {
+            Some(op) => op,
+            None => {
+                self.offset = end;
+                return self.to_test_expr(operand, start);
+            }
+        };
+        self.skip_whitespace();
+
+        let left = self.to_comparable(operand, start)?;
+        let right = self.parse_comparable()?;
+        Ok(LogicalExpr::Comparison(left, op, right))
+    }
+
+    /// Parses an expression surrounded by '(' and ')'
+    fn parse_paren_expr(&mut self) -> Result<LogicalExpr, PathError> {
+        self.expect('(', "'('")?;
+        self.skip_whitespace();
+        let expression = self.parse_logical_or()?;
+        self.skip_whitespace();
+        self.expect(')', "')'")?;
+        Ok(expression)
+    }
+
+    /// Parses a comparison operator if one is next
+    fn parse_comparison_op(&mut self) -> Option<ComparisonOp> {
+        const OPS: &[(&str, ComparisonOp)] = &[
+            ("==", ComparisonOp::Equal),
+            ("!=", ComparisonOp::NotEqual),
+            ("<=", ComparisonOp::LessEqual),
+            (">=", ComparisonOp::GreaterEqual),
+            ("<", ComparisonOp::Less),
+            (">", ComparisonOp::Greater),
+        ];
+
+        OPS.iter()
+            .find(|(string, _)| self.eat_str(string))
+            .map(|(_, op)| *op)
+    }
+
+    /// Parses a query, function call or literal
+    fn parse_operand(&mut self) -> Result<Operand, PathError> {
+        match self.peek() {
+            Some(c @ ('@' | '$')) => {
+                self.next();
+                Ok(Operand::Query(FilterQuery {
+                    root: c == '$',
+                    segments: self.parse_segments()?,
+                }))
+            }
+            Some('\'' | '"') => self
+                .parse_string_literal()
+                .map(|string| Operand::Literal(Value::String(Cow::Owned(string)))),
+            Some(c) if c == '-' || c.is_ascii_digit() => self
+                .parse_number()
+                .map(|number| Operand::Literal(Value::Number(number))),
+            Some(c) if c.is_ascii_lowercase() => {
+                let start = self.offset;
+                while let Some(c) = self.peek() {
+                    if !c.is_ascii_lowercase() && !c.is_ascii_digit() && c != '_' {
+                        break;
+                    }
+                    self.next();
+                }
+                let name = &self.query[start..self.offset];
+
+                if self.peek() == Some('(') {
+                    return self.parse_function(name, start).map(Operand::Function);
+                }
+
+                Ok(Operand::Literal(match name {
+                    "true" => Value::Boolean(true),
+                    "false" => Value::Boolean(false),
+                    "null" => Value::Null,
+                    _ => return Err(self.unexpected("'('")),
+                }))
+            }
+            _ => Err(self.unexpected("a filter expression")),
+        }
+    }
+
+    /// Parses the arguments of the function named `name` which started at `start`
+    fn parse_function(&mut self, name: &str, start: usize) -> Result<Function, PathError> {
+        let name = match name {
+            "length" => "length",
+            "count" => "count",
+            "match" => "match",
+            "search" => "search",
+            "value" => "value",
+            _ => {
+                let kind = PathErrorKind::UnknownFunction(name.to_string());
+                return Err(self.error_at(start, kind));
+            }
+        };
+
+        self.expect('(', "'('")?;
+        self.skip_whitespace();
+
+        let mut arguments = Vec::new();
+        if !self.eat(')') {
+            loop {
+                let argument_start = self.offset;
+                arguments.push((self.parse_operand()?, argument_start));
+                self.skip_whitespace();
+
+                if self.eat(')') {
+                    break;
+                }
+                self.expect(',', "',' or ')'")?;
+                self.skip_whitespace();
+            }
+        }
+
+        let invalid =
+            |parser: &Parser| parser.error_at(start, PathErrorKind::InvalidArguments(name));
+        let mut arguments = arguments.into_iter();
+        let function = match (name, arguments.next(), arguments.next(), arguments.next()) {
+            ("length", Some((value, offset)), None, None) => {
+                Function::Length(self.to_comparable(value, offset)?)
+            }
+            ("count", Some((Operand::Query(query), _)), None, None) => Function::Count(query),
+            ("value", Some((Operand::Query(query), _)), None, None) => Function::Value(query),
+            (
+                "match" | "search",
+                Some((string, string_offset)),
+                Some((regex, regex_offset)),
+                None,
+            ) => {
+                let string = self.to_comparable(string, string_offset)?;
+                let regex = match self.to_comparable(regex, regex_offset)? {
+                    Comparable::Literal(Value::String(regex)) => {
+                        Pattern::Compiled(Regex::new(&regex))
+                    }
+                    Comparable::Literal(_) => Pattern::Compiled(None),
+                    regex => Pattern::Dynamic(regex),
+                };
+                match name {
+                    "match" => Function::Match(string, regex),
+                    _ => Function::Search(string, regex),
+                }
+            }
+            _ => return Err(invalid(self)),
+        };
+
+        Ok(function)
+    }
+
+    /// Parses an operand which must produce a single value
+    fn parse_comparable(&mut self) -> Result<Comparable, PathError> {
+        let start = self.offset;
+        let operand = self.parse_operand()?;
+        self.to_comparable(operand, start)
+    }
+
+    /// Converts `operand`, which started at `start`, into a single value
+    fn to_comparable(&self, operand: Operand, start: usize) -> Result<Comparable, PathError> {
+        match operand {
+            Operand::Literal(value) => Ok(Comparable::Literal(value)),
+            Operand::Query(query) if query.is_singular() => Ok(Comparable::Query(query)),
+            Operand::Query(_) => Err(self.error_at(start, PathErrorKind::NonSingularQuery)),
+            Operand::Function(function) if !function.is_logical() => {
+                Ok(Comparable::Function(Box::new(function)))
+            }
+            Operand::Function(_) => Err(self.error_at(start, PathErrorKind::TypeMismatch)),
+        }
+    }
+
+    /// Converts `operand`, which started at `start`, into an existence test or logical function
+    fn to_test_expr(&self, operand: Operand, start: usize) -> Result<LogicalExpr, PathError> {
+        match operand {
+            Operand::Query(query) => Ok(LogicalExpr::Exists(query)),
+            Operand::Function(function) if function.is_logical() => {
+                Ok(LogicalExpr::Function(function))
+            }
+            Operand::Function(_) => Err(self.error_at(start, PathErrorKind::TypeMismatch)),
+            Operand::Literal(_) => Err(self.unexpected("a comparison operator")),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use super::Parser;
+use crate::{PathError, PathErrorKind};
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+
+/// The largest magnitude of an integer in a query, as required for interoperability by I-JSON
+const MAX_INTEGER: i64 = (1 << 53) - 1;
+
+impl<'a> Parser<'a> {
+    /// Parses an integer without leading zeros whose magnitude is at most `2^53 - 1`
+    pub(super) fn parse_integer(&mut self) -> Result<i64, PathError> {
+        let start = self.offset;
+        let negative = self.eat('-');
+        let digits = self.parse_digits()?;
+
+        if (digits.len() > 1 && digits.starts_with('0')) || (negative && digits == "0") {
+            return Err(self.error_at(start, PathErrorKind::InvalidInteger));
+        }
+
+        match self.query[start..self.offset].parse::<i64>() {
+            Ok(value) if (-MAX_INTEGER..=MAX_INTEGER).contains(&value) => Ok(value),
+            _ => Err(self.error_at(start, PathErrorKind::InvalidInteger)),
+        }
+    }
+
+    /// Parses a number literal in a filter expression
+    pub(super) fn parse_number(&mut self) -> Result<f64, PathError> {
+        let start = self.offset;
+        self.eat('-');
+
+        let int = self.parse_digits()?;
+        if int.len() > 1 && int.starts_with('0') {
+            return Err(self.error_at(start, PathErrorKind::InvalidNumber));
+        }
+
+        if self.eat('.') {
+            self.parse_digits()?;
+        }
+
+        if self.eat('e') || self.eat('E') {
+            if !self.eat('-') {
+                self.eat('+');
+            }
+            self.parse_digits()?;
+        }
+
+        self.query[start..self.offset]
+            .parse()
+            .map_err(|_| self.error_at(start, PathErrorKind::InvalidNumber))
+    }
+
+    /// Parses a string literal surrounded by either single or double quotes
+    pub(super) fn parse_string_literal(&mut self) -> Result<String, PathError> {
+        let quote = match self.peek() {
+            Some(quote @ ('\'' | '"')) => quote,
+            _ => return Err(self.unexpected("a string")),
+        };
+        self.next();
+
+        let mut string = String::new();
+        loop {
+            match self.peek() {
+                Some('\\') => {
+                    self.next();
+                    string.push(self.parse_escape(quote)?);
+                }
+                Some(c) if c == quote => {
+                    self.next();
+                    return Ok(string);
+                }
+                Some(c) if c >= ' ' => {
+                    self.next();
+                    string.push(c);
+                }
+                _ => return Err(self.unexpected("a string character")),
+            }
+        }
+    }
+
+    /// Parses one or more ASCII digits, returning them
+    fn parse_digits(&mut self) -> Result<&'a str, PathError> {
+        let start = self.offset;
+        while let Some('0'..='9') = self.peek() {
+            self.offset += 1;
+        }
+
+        if start == self.offset {
+            return Err(self.unexpected("a digit"));
+        }
+
+        Ok(&self.query[start..self.offset])
+    }
+
+    /// Parses the rest of an escape sequence after the '\' in a string delimited by `quote`
+    fn parse_escape(&mut self, quote: char) -> Result<char, PathError> {
+        let start = self.offset - 1;
+        let invalid = |parser: &Parser| parser.error_at(start, PathErrorKind::InvalidEscape);
+
+        let c = match self.next() {
+            Some('b') => '\x08',
+            Some('f') => '\x0C',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('/') => '/',
+            Some('\\') => '\\',
+            Some(c) if c == quote => c,
+            Some('u') => {
+                let high = self.parse_hex4().ok_or_else(|| invalid(self))?;
+                let value = match high {
+                    0xD800..=0xDBFF => {
+                        if !self.eat_str("\\u") {
+                            return Err(invalid(self));
+                        }
+
+                        match self.parse_hex4() {
+                            Some(low @ 0xDC00..=0xDFFF) => {
+                                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                            }
+                            _ => return Err(invalid(self)),
+                        }
+                    }
+                    0xDC00..=0xDFFF => return Err(invalid(self)),
+                    value => value,
+                };
+
+                char::from_u32(value).ok_or_else(|| invalid(self))?
+            }
+            _ => return Err(invalid(self)),
+        };
+
+        Ok(c)
+    }
+
+    /// Parses 4 hexadecimal digits
+    fn parse_hex4(&mut self) -> Option<u32> {
+        let hex = self.query.get(self.offset..self.offset + 4)?;
+        if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        self.offset += 4;
+        u32::from_str_radix(hex, 16).ok()
+    }
+}
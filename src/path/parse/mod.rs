@@ -0,0 +1,106 @@
+use crate::{JsonPath, PathError, PathErrorKind};
+
+mod filter;
+mod literal;
+mod segment;
+
+/// Parses JSONPath queries
+pub(super) struct Parser<'a> {
+    /// The query being parsed
+    query: &'a str,
+
+    /// The byte offset of the next character in `query`
+    offset: usize,
+}
+
+impl JsonPath {
+    /// Parses `query` as a JSONPath query
+    pub fn parse(query: &str) -> Result<Self, PathError> {
+        let mut parser = Parser { query, offset: 0 };
+
+        parser.expect('$', "'$'")?;
+        let segments = parser.parse_segments()?;
+        if parser.peek().is_some() {
+            return Err(parser.unexpected("'.' or '['"));
+        }
+
+        Ok(JsonPath { segments })
+    }
+}
+
+impl core::str::FromStr for JsonPath {
+    type Err = PathError;
+
+    fn from_str(query: &str) -> Result<Self, Self::Err> {
+        JsonPath::parse(query)
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Gets the next character without consuming it
+    fn peek(&self) -> Option<char> {
+        self.query[self.offset..].chars().next()
+    }
+
+    /// Consumes and returns the next character
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    /// Consumes the next character if it is `c`, returning if it was
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.offset += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes `string` if the remaining query starts with it, returning if it did
+    fn eat_str(&mut self, string: &str) -> bool {
+        if self.query[self.offset..].starts_with(string) {
+            self.offset += string.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes the next character, returning an error if it isn't `c`
+    fn expect(&mut self, c: char, expected: &'static str) -> Result<(), PathError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    /// Skips any blank space
+    fn skip_whitespace(&mut self) {
+        while let Some(' ' | '\t' | '\n' | '\r') = self.peek() {
+            self.offset += 1;
+        }
+    }
+
+    /// Creates an error for the next character not being `expected`
+    fn unexpected(&self, expected: &'static str) -> PathError {
+        self.error_at(
+            self.offset,
+            match self.peek() {
+                Some(unexpected) => PathErrorKind::Unexpected {
+                    unexpected,
+                    expected,
+                },
+                None => PathErrorKind::UnexpectedEnd,
+            },
+        )
+    }
+
+    /// Creates an error which occurred at `offset`
+    fn error_at(&self, offset: usize, kind: PathErrorKind) -> PathError {
+        PathError::new(offset, kind)
+    }
+}
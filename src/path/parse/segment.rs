@@ -0,0 +1,143 @@
+use super::Parser;
+use crate::{
+    path::ast::{Segment, Selector},
+    PathError,
+};
+#[cfg(feature = "no_std")]
+use alloc::{string::String, vec, vec::Vec};
+
+impl<'a> Parser<'a> {
+    /// Parses any number of segments, stopping before anything which can't start a segment
+    pub(super) fn parse_segments(&mut self) -> Result<Vec<Segment>, PathError> {
+        let mut segments = Vec::new();
+        loop {
+            let start = self.offset;
+            self.skip_whitespace();
+            match self.peek() {
+                Some('.' | '[') => segments.push(self.parse_segment()?),
+                _ => {
+                    self.offset = start;
+                    return Ok(segments);
+                }
+            }
+        }
+    }
+
+    /// Parses a child or descendant segment
+    fn parse_segment(&mut self) -> Result<Segment, PathError> {
+        if self.eat_str("..") {
+            return Ok(Segment::Descendant(match self.peek() {
+                Some('[') => self.parse_bracketed_selection()?,
+                _ => vec![self.parse_shorthand()?],
+            }));
+        }
+
+        if self.eat('.') {
+            return Ok(Segment::Child(vec![self.parse_shorthand()?]));
+        }
+
+        self.parse_bracketed_selection().map(Segment::Child)
+    }
+
+    /// Parses the wildcard or member name following a '.'
+    fn parse_shorthand(&mut self) -> Result<Selector, PathError> {
+        if self.eat('*') {
+            return Ok(Selector::Wildcard);
+        }
+
+        let start = self.offset;
+        match self.peek() {
+            Some(c) if is_name_first(c) => {}
+            _ => return Err(self.unexpected("'*' or a member name")),
+        }
+
+        while let Some(c) = self.peek() {
+            if !is_name_first(c) && !c.is_ascii_digit() {
+                break;
+            }
+            self.next();
+        }
+
+        Ok(Selector::Name(String::from(
+            &self.query[start..self.offset],
+        )))
+    }
+
+    /// Parses a comma separated list of selectors surrounded by '[' and ']'
+    fn parse_bracketed_selection(&mut self) -> Result<Vec<Selector>, PathError> {
+        self.expect('[', "'['")?;
+
+        let mut selectors = Vec::new();
+        loop {
+            self.skip_whitespace();
+            selectors.push(self.parse_selector()?);
+            self.skip_whitespace();
+
+            if self.eat(']') {
+                return Ok(selectors);
+            }
+            self.expect(',', "',' or ']'")?;
+        }
+    }
+
+    /// Parses a single selector inside of brackets
+    fn parse_selector(&mut self) -> Result<Selector, PathError> {
+        match self.peek() {
+            Some('\'' | '"') => self.parse_string_literal().map(Selector::Name),
+            Some('*') => {
+                self.next();
+                Ok(Selector::Wildcard)
+            }
+            Some('?') => {
+                self.next();
+                self.skip_whitespace();
+                self.parse_logical_or().map(Selector::Filter)
+            }
+            Some(':') => self.parse_slice(None),
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                let index = self.parse_integer()?;
+
+                let end = self.offset;
+                self.skip_whitespace();
+                if self.peek() == Some(':') {
+                    return self.parse_slice(Some(index));
+                }
+
+                self.offset = end;
+                Ok(Selector::Index(index))
+            }
+            _ => Err(self.unexpected("a selector")),
+        }
+    }
+
+    /// Parses the rest of a slice selector after its start index
+    fn parse_slice(&mut self, start: Option<i64>) -> Result<Selector, PathError> {
+        self.expect(':', "':'")?;
+        self.skip_whitespace();
+
+        let end = self.parse_optional_integer()?;
+        self.skip_whitespace();
+
+        let step = if self.eat(':') {
+            self.skip_whitespace();
+            self.parse_optional_integer()?
+        } else {
+            None
+        };
+
+        Ok(Selector::Slice { start, end, step })
+    }
+
+    /// Parses an integer if one is next
+    fn parse_optional_integer(&mut self) -> Result<Option<i64>, PathError> {
+        match self.peek() {
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_integer().map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Can `c` start a member name shorthand?
+fn is_name_first(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || c >= '\u{80}'
+}
@@ -0,0 +1,76 @@
+use super::{Instruction, Node};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+/// The largest number of instructions a compiled pattern may have
+const MAX_INSTRUCTIONS: usize = 1 << 16;
+
+/// Compiles `node` into a program, returning [`None`] if it would be too large
+pub(super) fn compile(node: &Node) -> Option<Vec<Instruction>> {
+    let mut program = Vec::new();
+    emit(node, &mut program)?;
+    program.push(Instruction::Accept);
+    Some(program)
+}
+
+/// Appends the instructions matching `node` to `program`
+fn emit(node: &Node, program: &mut Vec<Instruction>) -> Option<()> {
+    if program.len() > MAX_INSTRUCTIONS {
+        return None;
+    }
+
+    match node {
+        Node::Char(matcher) => program.push(Instruction::Char(matcher.clone())),
+        Node::Concat(nodes) => {
+            for node in nodes {
+                emit(node, program)?;
+            }
+        }
+        Node::Alternate(branches) => {
+            let mut jumps = Vec::with_capacity(branches.len());
+            for (i, branch) in branches.iter().enumerate() {
+                if i == branches.len() - 1 {
+                    emit(branch, program)?;
+                    break;
+                }
+
+                let split = program.len();
+                program.push(Instruction::Split(split + 1, 0));
+                emit(branch, program)?;
+                jumps.push(program.len());
+                program.push(Instruction::Jump(0));
+                program[split] = Instruction::Split(split + 1, program.len());
+            }
+
+            let end = program.len();
+            for jump in jumps {
+                program[jump] = Instruction::Jump(end);
+            }
+        }
+        Node::Repeat { node, min, max } => {
+            for _ in 0..*min {
+                emit(node, program)?;
+            }
+
+            match max {
+                Some(max) => {
+                    for _ in *min..*max {
+                        let split = program.len();
+                        program.push(Instruction::Split(split + 1, 0));
+                        emit(node, program)?;
+                        program[split] = Instruction::Split(split + 1, program.len());
+                    }
+                }
+                None => {
+                    let split = program.len();
+                    program.push(Instruction::Split(split + 1, 0));
+                    emit(node, program)?;
+                    program.push(Instruction::Jump(split));
+                    program[split] = Instruction::Split(split + 1, program.len());
+                }
+            }
+        }
+    }
+
+    Some(())
+}
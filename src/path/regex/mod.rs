@@ -0,0 +1,101 @@
+#[cfg(feature = "no_std")]
+use alloc::{boxed::Box, vec::Vec};
+
+mod compile;
+mod parse;
+mod run;
+
+/// A compiled I-Regexp (RFC 9485), as used by the `match()` and `search()` functions.
+///
+/// Matching simulates the automaton directly, so it runs in time linear in the length of the
+/// string for any pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Regex {
+    /// The instructions of the automaton, starting at index 0
+    program: Vec<Instruction>,
+}
+
+/// An instruction in a compiled [`Regex`]
+#[derive(Debug, Clone, PartialEq)]
+enum Instruction {
+    /// Consumes one character if it matches, otherwise the thread dies
+    Char(CharMatcher),
+
+    /// Continues at both targets
+    Split(usize, usize),
+
+    /// Continues at the target
+    Jump(usize),
+
+    /// The pattern has matched
+    Accept,
+}
+
+/// Matches a single character
+#[derive(Debug, Clone, PartialEq)]
+enum CharMatcher {
+    /// Matches any character except line breaks
+    Any,
+
+    /// Matches exactly this character
+    Literal(char),
+
+    /// Matches any character in (or not in, if negated) the inclusive ranges
+    Class {
+        /// Are the characters outside of the ranges matched instead?
+        negated: bool,
+
+        /// The inclusive ranges of characters in the class
+        ranges: Vec<(char, char)>,
+    },
+}
+
+/// A parsed pattern before compilation
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    /// Matches a single character
+    Char(CharMatcher),
+
+    /// Matches each of the nodes in sequence
+    Concat(Vec<Node>),
+
+    /// Matches any one of the nodes
+    Alternate(Vec<Node>),
+
+    /// Matches the node repeatedly
+    Repeat {
+        /// The node to repeat
+        node: Box<Node>,
+
+        /// The minimum number of repetitions
+        min: u32,
+
+        /// The maximum number of repetitions, or unbounded if [`None`]
+        max: Option<u32>,
+    },
+}
+
+impl Regex {
+    /// Compiles `pattern`, returning [`None`] if it isn't a valid or supported I-Regexp
+    pub(super) fn new(pattern: &str) -> Option<Self> {
+        let node = parse::parse(pattern)?;
+        let program = compile::compile(&node)?;
+        Some(Regex { program })
+    }
+}
+
+impl CharMatcher {
+    /// Does this match `c`?
+    fn matches(&self, c: char) -> bool {
+        match self {
+            CharMatcher::Any => c != '\n' && c != '\r',
+            CharMatcher::Literal(literal) => c == *literal,
+            CharMatcher::Class { negated, ranges } => {
+                ranges
+                    .iter()
+                    .any(|(start, end)| (*start..=*end).contains(&c))
+                    != *negated
+            }
+        }
+    }
+}
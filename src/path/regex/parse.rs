@@ -0,0 +1,164 @@
+use super::{CharMatcher, Node};
+#[cfg(feature = "no_std")]
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{iter::Peekable, str::Chars};
+
+/// The largest repetition count accepted in a quantifier
+const MAX_REPETITIONS: u32 = 1000;
+
+/// Parses `pattern` as an I-Regexp, returning [`None`] if it is invalid or unsupported
+pub(super) fn parse(pattern: &str) -> Option<Node> {
+    let mut chars = pattern.chars().peekable();
+    let node = parse_alternation(&mut chars)?;
+    match chars.next() {
+        Some(_) => None,
+        None => Some(node),
+    }
+}
+
+/// Parses branches separated by '|'
+fn parse_alternation(chars: &mut Peekable<Chars>) -> Option<Node> {
+    let mut branches = vec![parse_branch(chars)?];
+    while chars.next_if_eq(&'|').is_some() {
+        branches.push(parse_branch(chars)?);
+    }
+
+    Some(match branches.len() {
+        1 => branches.pop().unwrap(),
+        _ => Node::Alternate(branches),
+    })
+}
+
+/// Parses a sequence of pieces
+fn parse_branch(chars: &mut Peekable<Chars>) -> Option<Node> {
+    let mut pieces = Vec::new();
+    while !matches!(chars.peek(), None | Some('|' | ')')) {
+        pieces.push(parse_piece(chars)?);
+    }
+
+    Some(Node::Concat(pieces))
+}
+
+/// Parses an atom followed by an optional quantifier
+fn parse_piece(chars: &mut Peekable<Chars>) -> Option<Node> {
+    let node = parse_atom(chars)?;
+
+    let (min, max) = match chars.peek() {
+        Some('*') => (0, None),
+        Some('+') => (1, None),
+        Some('?') => (0, Some(1)),
+        Some('{') => {
+            chars.next();
+            let min = parse_count(chars)?;
+            let max = match chars.next_if_eq(&',') {
+                Some(_) if chars.peek() == Some(&'}') => None,
+                Some(_) => Some(parse_count(chars)?),
+                None => Some(min),
+            };
+            if chars.peek() != Some(&'}') || max.is_some_and(|max| max < min) {
+                return None;
+            }
+            (min, max)
+        }
+        _ => return Some(node),
+    };
+    chars.next();
+
+    Some(Node::Repeat {
+        node: Box::new(node),
+        min,
+        max,
+    })
+}
+
+/// Parses the decimal repetition count of a range quantifier
+fn parse_count(chars: &mut Peekable<Chars>) -> Option<u32> {
+    let mut count: Option<u32> = None;
+    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+        chars.next();
+        count = Some(count.unwrap_or(0) * 10 + digit);
+        if count > Some(MAX_REPETITIONS) {
+            return None;
+        }
+    }
+    count
+}
+
+/// Parses a character, character class or parenthesized group
+fn parse_atom(chars: &mut Peekable<Chars>) -> Option<Node> {
+    let matcher = match chars.next()? {
+        '(' => {
+            let node = parse_alternation(chars)?;
+            return chars.next_if_eq(&')').map(|_| node);
+        }
+        '.' => CharMatcher::Any,
+        '[' => parse_class(chars)?,
+        '\\' => CharMatcher::Literal(parse_escape(chars)?),
+        ')' | '*' | '+' | '?' | ']' | '{' | '}' | '|' => return None,
+        c => CharMatcher::Literal(c),
+    };
+
+    Some(Node::Char(matcher))
+}
+
+/// Parses a character class expression after its '['
+fn parse_class(chars: &mut Peekable<Chars>) -> Option<CharMatcher> {
+    let negated = chars.next_if_eq(&'^').is_some();
+
+    let mut ranges = Vec::new();
+    if chars.next_if_eq(&'-').is_some() {
+        ranges.push(('-', '-'));
+    }
+
+    loop {
+        let start = match chars.next()? {
+            ']' if !ranges.is_empty() => break,
+            '-' => {
+                chars.next_if_eq(&']')?;
+                ranges.push(('-', '-'));
+                break;
+            }
+            c => parse_class_char(c, chars)?,
+        };
+
+        let mut lookahead = chars.clone();
+        let end = match (lookahead.next(), lookahead.next()) {
+            (Some('-'), Some(c)) if c != ']' => {
+                chars.next();
+                chars.next();
+                parse_class_char(c, chars)?
+            }
+            _ => start,
+        };
+
+        if end < start {
+            return None;
+        }
+        ranges.push((start, end));
+    }
+
+    Some(CharMatcher::Class { negated, ranges })
+}
+
+/// Converts `c`, which was just consumed, into a character in a class
+fn parse_class_char(c: char, chars: &mut Peekable<Chars>) -> Option<char> {
+    match c {
+        '\\' => parse_escape(chars),
+        '-' | '[' | ']' => None,
+        c => Some(c),
+    }
+}
+
+/// Parses a single character escape after its '\'. Category escapes (`\p{..}`) are unsupported.
+fn parse_escape(chars: &mut Peekable<Chars>) -> Option<char> {
+    match chars.next()? {
+        'n' => Some('\n'),
+        'r' => Some('\r'),
+        't' => Some('\t'),
+        c
+        @ ('(' | ')' | '*' | '+' | '-' | '.' | '?' | '[' | '\\' | ']' | '^' | '{' | '|' | '}') => {
+            Some(c)
+        }
+        _ => None,
+    }
+}
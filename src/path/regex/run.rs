@@ -0,0 +1,87 @@
+use super::{Instruction, Regex};
+#[cfg(feature = "no_std")]
+use alloc::{vec, vec::Vec};
+
+/// The set of instructions threads are currently at
+struct Threads {
+    /// The instructions, in the order they were added
+    pcs: Vec<usize>,
+
+    /// The generation each instruction was last added in, to avoid duplicates
+    added: Vec<usize>,
+}
+
+impl Regex {
+    /// Does the whole of `string` match this pattern?
+    pub(in crate::path) fn is_match(&self, string: &str) -> bool {
+        self.run(string, false)
+    }
+
+    /// Does any substring of `string` match this pattern?
+    pub(in crate::path) fn find(&self, string: &str) -> bool {
+        self.run(string, true)
+    }
+
+    /// Simulates the program over `string`, allowing matches to start and end anywhere if
+    /// `search` is true
+    fn run(&self, string: &str, search: bool) -> bool {
+        let mut threads = Threads {
+            pcs: Vec::new(),
+            added: vec![0; self.program.len()],
+        };
+        let mut generation = 1;
+        self.add(&mut threads, 0, generation);
+
+        for c in string.chars() {
+            if search && self.accepts(&threads) {
+                return true;
+            }
+
+            generation += 1;
+            let current = core::mem::take(&mut threads.pcs);
+            for pc in current {
+                if let Instruction::Char(matcher) = &self.program[pc] {
+                    if matcher.matches(c) {
+                        self.add(&mut threads, pc + 1, generation);
+                    }
+                }
+            }
+
+            if search {
+                self.add(&mut threads, 0, generation);
+            } else if threads.pcs.is_empty() {
+                return false;
+            }
+        }
+
+        self.accepts(&threads)
+    }
+
+    /// Has any thread reached [`Instruction::Accept`]?
+    fn accepts(&self, threads: &Threads) -> bool {
+        threads
+            .pcs
+            .iter()
+            .any(|pc| self.program[*pc] == Instruction::Accept)
+    }
+
+    /// Adds a thread at `pc`, following jumps and splits
+    fn add(&self, threads: &mut Threads, pc: usize, generation: usize) {
+        let mut stack = vec![pc];
+        while let Some(pc) = stack.pop() {
+            if threads.added[pc] == generation {
+                continue;
+            }
+            threads.added[pc] = generation;
+
+            match self.program[pc] {
+                Instruction::Jump(target) => stack.push(target),
+                Instruction::Split(first, second) => {
+                    stack.push(second);
+                    stack.push(first);
+                }
+                _ => threads.pcs.push(pc),
+            }
+        }
+    }
+}
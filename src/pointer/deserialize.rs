@@ -0,0 +1,44 @@
+use crate::JsonPointer;
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, string::String};
+use data_format::{Converter, Deserialize};
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+/// Converts JSON strings into [`JsonPointer`]s
+struct JsonPointerConverter;
+
+impl<'de> Deserialize<'de> for JsonPointer {
+    fn deserialize<D: data_format::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_string(JsonPointerConverter)
+    }
+}
+
+impl<'de> Converter<'de> for JsonPointerConverter {
+    type Value = JsonPointer;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("a JSON pointer")
+    }
+
+    fn convert_str<E: data_format::DeserializeError<'de>>(
+        self,
+        value: &str,
+    ) -> Result<Self::Value, E> {
+        JsonPointer::parse(value).map_err(E::custom)
+    }
+
+    fn convert_str_borrow<E: data_format::DeserializeError<'de>>(
+        self,
+        value: Cow<'de, str>,
+    ) -> Result<Self::Value, E> {
+        self.convert_str(&value)
+    }
+
+    fn convert_string<E: data_format::DeserializeError<'de>>(
+        self,
+        value: String,
+    ) -> Result<Self::Value, E> {
+        self.convert_str(&value)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::JsonPointer;
+use core::fmt::Write;
+
+impl core::fmt::Display for JsonPointer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for token in &self.tokens {
+            f.write_char('/')?;
+            for c in token.chars() {
+                match c {
+                    '~' => f.write_str("~0")?,
+                    '/' => f.write_str("~1")?,
+                    c => f.write_char(c)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
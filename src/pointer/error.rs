@@ -0,0 +1,63 @@
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+
+/// An error that can occur while parsing or following a [`JsonPointer`](crate::JsonPointer)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointerError {
+    /// A non-empty pointer doesn't start with a '/'
+    MissingLeadingSlash,
+
+    /// A '~' at the given byte offset isn't followed by a '0' or a '1'
+    InvalidEscape(usize),
+
+    /// A token refers to a member or element which doesn't exist
+    NotFound(String),
+
+    /// A token used on an array isn't a valid array index
+    InvalidIndex(String),
+
+    /// An array index is past the end of the array
+    IndexOutOfBounds {
+        /// The index in the token
+        index: usize,
+
+        /// The length of the array
+        len: usize,
+    },
+
+    /// A token is used on a value which is neither an array nor an object
+    NotContainer(String),
+
+    /// The root of a document cannot be removed
+    RemoveRoot,
+}
+
+impl core::error::Error for PointerError {}
+
+impl core::fmt::Display for PointerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PointerError::MissingLeadingSlash => f.write_str("JSON pointer must start with '/'"),
+            PointerError::InvalidEscape(offset) => {
+                write!(f, "invalid escape in JSON pointer at offset {}", offset)
+            }
+            PointerError::NotFound(token) => write!(f, "\"{}\" does not exist", token),
+            PointerError::InvalidIndex(token) => {
+                write!(f, "\"{}\" is not a valid array index", token)
+            }
+            PointerError::IndexOutOfBounds { index, len } => write!(
+                f,
+                "index {} is out of bounds for an array of length {}",
+                index, len
+            ),
+            PointerError::NotContainer(token) => {
+                write!(
+                    f,
+                    "\"{}\" refers into a value which is not an array or object",
+                    token
+                )
+            }
+            PointerError::RemoveRoot => f.write_str("the root of a document cannot be removed"),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::JsonPointer;
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+
+impl JsonPointer {
+    /// Gets the unescaped reference tokens making up this pointer
+    pub fn tokens(&self) -> &[String] {
+        &self.tokens
+    }
+
+    /// Gets the final reference token of this pointer, or [`None`] if it refers to the root
+    pub fn last(&self) -> Option<&str> {
+        self.tokens.last().map(String::as_str)
+    }
+
+    /// Is this pointer referring to the root of a document?
+    pub fn is_root(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Gets a pointer to the parent of the value this pointer refers to, or [`None`] if it refers
+    /// to the root
+    pub fn parent(&self) -> Option<JsonPointer> {
+        self.tokens.split_last().map(|(_, parent)| JsonPointer {
+            tokens: parent.to_vec(),
+        })
+    }
+
+    /// Is this pointer equal to or a prefix of `other`?
+    pub fn is_prefix_of(&self, other: &JsonPointer) -> bool {
+        other.tokens.starts_with(&self.tokens)
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{JsonPointer, PointerError};
+#[cfg(feature = "no_std")]
+use alloc::string::ToString;
+
+impl JsonPointer {
+    /// Parses `token` as an array index, which must be a decimal number without leading zeros
+    pub(crate) fn parse_index(token: &str) -> Result<usize, PointerError> {
+        let valid = !token.is_empty()
+            && token.bytes().all(|c| c.is_ascii_digit())
+            && (token == "0" || !token.starts_with('0'));
+
+        if !valid {
+            return Err(PointerError::InvalidIndex(token.to_string()));
+        }
+
+        token
+            .parse()
+            .map_err(|_| PointerError::InvalidIndex(token.to_string()))
+    }
+}
@@ -0,0 +1,22 @@
+#[cfg(feature = "no_std")]
+use alloc::{string::String, vec::Vec};
+
+mod deserialize;
+mod display;
+mod error;
+mod get;
+mod index;
+mod new;
+mod parse;
+mod resolve;
+mod serialize;
+mod set;
+
+pub use error::PointerError;
+
+/// A JSON Pointer (RFC 6901) which refers to a specific value within a JSON document
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct JsonPointer {
+    /// The unescaped reference tokens making up the pointer, from the root down
+    tokens: Vec<String>,
+}
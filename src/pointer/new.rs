@@ -0,0 +1,10 @@
+use crate::JsonPointer;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+impl JsonPointer {
+    /// Creates a new [`JsonPointer`] referring to the root of a document
+    pub const fn new() -> Self {
+        JsonPointer { tokens: Vec::new() }
+    }
+}
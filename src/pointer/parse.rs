@@ -0,0 +1,53 @@
+use crate::{JsonPointer, PointerError};
+#[cfg(feature = "no_std")]
+use alloc::{string::String, vec::Vec};
+
+impl JsonPointer {
+    /// Parses `pointer` from its string representation, unescaping each reference token
+    pub fn parse(pointer: &str) -> Result<Self, PointerError> {
+        if pointer.is_empty() {
+            return Ok(JsonPointer::new());
+        }
+
+        if !pointer.starts_with('/') {
+            return Err(PointerError::MissingLeadingSlash);
+        }
+
+        let mut tokens = Vec::new();
+        let mut offset = 1;
+        for raw in pointer[1..].split('/') {
+            tokens.push(unescape(raw, offset)?);
+            offset += raw.len() + 1;
+        }
+
+        Ok(JsonPointer { tokens })
+    }
+}
+
+impl core::str::FromStr for JsonPointer {
+    type Err = PointerError;
+
+    fn from_str(pointer: &str) -> Result<Self, Self::Err> {
+        JsonPointer::parse(pointer)
+    }
+}
+
+/// Unescapes `~0` and `~1` in the reference token `raw`, which starts at `offset` in the pointer
+fn unescape(raw: &str, offset: usize) -> Result<String, PointerError> {
+    let mut token = String::with_capacity(raw.len());
+    let mut chars = raw.char_indices();
+    while let Some((index, c)) = chars.next() {
+        if c != '~' {
+            token.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some((_, '0')) => token.push('~'),
+            Some((_, '1')) => token.push('/'),
+            _ => return Err(PointerError::InvalidEscape(offset + index)),
+        }
+    }
+
+    Ok(token)
+}
@@ -0,0 +1,124 @@
+use crate::{JsonPointer, PointerError, Value};
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, string::String};
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+impl JsonPointer {
+    /// Follows this pointer from `root`, returning the value it refers to
+    pub fn resolve<'a, 'de>(&self, root: &'a Value<'de>) -> Result<&'a Value<'de>, PointerError> {
+        let mut value = root;
+        for token in &self.tokens {
+            value = match value {
+                Value::Object(object) => object
+                    .get(token.as_str())
+                    .ok_or_else(|| PointerError::NotFound(token.clone()))?,
+                Value::Array(array) => {
+                    let index = JsonPointer::parse_existing_index(token, array.len())?;
+                    &array[index]
+                }
+                _ => return Err(PointerError::NotContainer(token.clone())),
+            };
+        }
+
+        Ok(value)
+    }
+
+    /// Follows this pointer from `root`, returning a mutable reference to the value it refers to
+    pub fn resolve_mut<'a, 'de>(
+        &self,
+        root: &'a mut Value<'de>,
+    ) -> Result<&'a mut Value<'de>, PointerError> {
+        JsonPointer::walk_mut(&self.tokens, root)
+    }
+
+    /// Inserts `value` at the location this pointer refers to in `root`, following the semantics
+    /// of the JSON Patch "add" operation.
+    ///
+    /// Object members are inserted or replaced, returning any previous value. Array elements are
+    /// inserted before the indexed element, shifting the rest, with `-` appending to the array. A
+    /// pointer to the root replaces the whole document.
+    pub fn insert<'de>(
+        &self,
+        root: &mut Value<'de>,
+        value: Value<'de>,
+    ) -> Result<Option<Value<'de>>, PointerError> {
+        let (last, parent) = match self.tokens.split_last() {
+            Some(split) => split,
+            None => return Ok(Some(core::mem::replace(root, value))),
+        };
+
+        match JsonPointer::walk_mut(parent, root)? {
+            Value::Object(object) => Ok(object.insert(Cow::Owned(last.clone()), value)),
+            Value::Array(array) => {
+                let index = if last == "-" {
+                    array.len()
+                } else {
+                    JsonPointer::parse_index(last)?
+                };
+
+                if index > array.len() {
+                    return Err(PointerError::IndexOutOfBounds {
+                        index,
+                        len: array.len(),
+                    });
+                }
+
+                array.insert(index, value);
+                Ok(None)
+            }
+            _ => Err(PointerError::NotContainer(last.clone())),
+        }
+    }
+
+    /// Removes the value this pointer refers to from `root`, returning it
+    pub fn remove<'de>(&self, root: &mut Value<'de>) -> Result<Value<'de>, PointerError> {
+        let (last, parent) = self.tokens.split_last().ok_or(PointerError::RemoveRoot)?;
+
+        match JsonPointer::walk_mut(parent, root)? {
+            Value::Object(object) => object
+                .remove(last.as_str())
+                .ok_or_else(|| PointerError::NotFound(last.clone())),
+            Value::Array(array) => {
+                let index = JsonPointer::parse_existing_index(last, array.len())?;
+                Ok(array.remove(index))
+            }
+            _ => Err(PointerError::NotContainer(last.clone())),
+        }
+    }
+
+    /// Follows `tokens` from `value`, returning a mutable reference to the value they refer to
+    fn walk_mut<'a, 'de>(
+        tokens: &[String],
+        mut value: &'a mut Value<'de>,
+    ) -> Result<&'a mut Value<'de>, PointerError> {
+        for token in tokens {
+            value = match value {
+                Value::Object(object) => object
+                    .get_mut(token.as_str())
+                    .ok_or_else(|| PointerError::NotFound(token.clone()))?,
+                Value::Array(array) => {
+                    let index = JsonPointer::parse_existing_index(token, array.len())?;
+                    &mut array[index]
+                }
+                _ => return Err(PointerError::NotContainer(token.clone())),
+            };
+        }
+
+        Ok(value)
+    }
+
+    /// Parses `token` as the index of an existing element in an array of length `len`
+    fn parse_existing_index(token: &str, len: usize) -> Result<usize, PointerError> {
+        if token == "-" {
+            return Err(PointerError::NotFound(token.into()));
+        }
+
+        let index = JsonPointer::parse_index(token)?;
+        if index >= len {
+            return Err(PointerError::IndexOutOfBounds { index, len });
+        }
+
+        Ok(index)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::JsonPointer;
+#[cfg(feature = "no_std")]
+use alloc::string::ToString;
+use data_format::Serialize;
+
+impl Serialize for JsonPointer {
+    fn serialize<S: data_format::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_string(&self.to_string())
+    }
+}
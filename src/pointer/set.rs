@@ -0,0 +1,34 @@
+use crate::JsonPointer;
+#[cfg(feature = "no_std")]
+use alloc::string::{String, ToString};
+
+impl JsonPointer {
+    /// Appends an unescaped reference token to the end of this pointer
+    pub fn push<S: Into<String>>(&mut self, token: S) {
+        self.tokens.push(token.into());
+    }
+
+    /// Appends an array index to the end of this pointer
+    pub fn push_index(&mut self, index: usize) {
+        self.tokens.push(index.to_string());
+    }
+
+    /// Removes the final reference token from this pointer, returning it
+    pub fn pop(&mut self) -> Option<String> {
+        self.tokens.pop()
+    }
+
+    /// Creates a new pointer by appending `token` to this one
+    pub fn join<S: Into<String>>(&self, token: S) -> JsonPointer {
+        let mut pointer = self.clone();
+        pointer.push(token);
+        pointer
+    }
+
+    /// Creates a new pointer by appending the array index `index` to this one
+    pub fn join_index(&self, index: usize) -> JsonPointer {
+        let mut pointer = self.clone();
+        pointer.push_index(index);
+        pointer
+    }
+}
@@ -0,0 +1,124 @@
+use super::{to_write, Result};
+use crate::SerializeError;
+use core::{
+    future::poll_fn,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use data_format::Serialize;
+use futures_io::AsyncWrite;
+use std::io::{Error, ErrorKind, Write};
+
+/// The largest number of bytes written to the output at a time
+const CHUNK_SIZE: usize = 8192;
+
+/// Serializes `value` into compact JSON and asynchronously writes it to `output`.
+///
+/// The JSON is written and flushed a chunk of a bounded size at a time as it is serialized, so the
+/// whole document is never held in memory. Serializing can't be suspended, so if `output` isn't
+/// ready for a chunk, serializing starts over once it is and skips what has already been written.
+/// `value` must serialize the same way each time.
+pub async fn to_async_write<T, W>(value: &T, mut output: W) -> Result<()>
+where
+    T: Serialize + ?Sized,
+    W: AsyncWrite + Unpin,
+{
+    let mut buffer = Vec::with_capacity(CHUNK_SIZE);
+    let mut written = 0;
+
+    poll_fn(|cx| {
+        buffer.clear();
+        let mut writer = ChunkWriter {
+            output: Pin::new(&mut output),
+            cx,
+            buffer: &mut buffer,
+            skip: written,
+            written: &mut written,
+            pending: false,
+        };
+
+        let result = to_write(value, &mut writer)
+            .and_then(|_| writer.write_buffer().map_err(SerializeError::io));
+        if writer.pending {
+            return Poll::Pending;
+        }
+
+        Poll::Ready(result)
+    })
+    .await
+}
+
+/// Collects serialized JSON into chunks, writing each one to an [`AsyncWrite`] once it is full
+struct ChunkWriter<'a, 'b, W> {
+    /// The output to write to
+    output: Pin<&'a mut W>,
+
+    /// The context of the task writing
+    cx: &'a mut Context<'b>,
+
+    /// The bytes of the current chunk which haven't been written yet
+    buffer: &'a mut Vec<u8>,
+
+    /// The number of bytes still to be skipped, as they were written before serializing started
+    /// over
+    skip: usize,
+
+    /// The number of bytes written to the output
+    written: &'a mut usize,
+
+    /// Did writing stop because the output wasn't ready?
+    pending: bool,
+}
+
+impl<'a, 'b, W: AsyncWrite> ChunkWriter<'a, 'b, W> {
+    /// Writes and flushes the bytes in the buffer
+    fn write_buffer(&mut self) -> std::io::Result<()> {
+        while !self.buffer.is_empty() {
+            let length = match self.output.as_mut().poll_write(self.cx, self.buffer) {
+                Poll::Ready(result) => result?,
+                Poll::Pending => return Err(self.stop()),
+            };
+            if length == 0 {
+                return Err(Error::from(ErrorKind::WriteZero));
+            }
+
+            self.buffer.drain(..length);
+            *self.written += length;
+        }
+
+        match self.output.as_mut().poll_flush(self.cx) {
+            Poll::Ready(result) => result,
+            Poll::Pending => Err(self.stop()),
+        }
+    }
+
+    /// Marks that the output isn't ready, returning the error which stops serializing
+    fn stop(&mut self) -> Error {
+        self.pending = true;
+        Error::from(ErrorKind::WouldBlock)
+    }
+}
+
+impl<'a, 'b, W: AsyncWrite> Write for ChunkWriter<'a, 'b, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let skipped = self.skip.min(buf.len());
+        self.skip -= skipped;
+
+        let mut rest = &buf[skipped..];
+        while !rest.is_empty() {
+            let length = rest.len().min(CHUNK_SIZE - self.buffer.len());
+            self.buffer.extend_from_slice(&rest[..length]);
+            rest = &rest[length..];
+
+            if self.buffer.len() == CHUNK_SIZE {
+                self.write_buffer()?;
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
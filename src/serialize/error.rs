@@ -15,6 +15,9 @@ pub enum SerializeError {
 
     /// A custom error
     Custom(String),
+
+    /// A [`JsonWriter`](crate::JsonWriter) was called out of sequence
+    InvalidCall(&'static str),
 }
 
 impl SerializeError {
@@ -35,7 +38,7 @@ impl core::error::Error for SerializeError {
         match self {
             SerializeError::IO(error) => Some(error),
 
-            SerializeError::Custom(_) => None,
+            SerializeError::Custom(_) | SerializeError::InvalidCall(_) => None,
         }
     }
 }
@@ -45,6 +48,7 @@ impl core::fmt::Display for SerializeError {
         match self {
             SerializeError::IO(error) => error.fmt(f),
             SerializeError::Custom(error) => f.write_str(error),
+            SerializeError::InvalidCall(error) => f.write_str(error),
         }
     }
 }
@@ -0,0 +1,97 @@
+use super::{CompactFormatter, Formatter, PrettyFormatter};
+#[cfg(feature = "no_std")]
+use core::fmt::{Error, Write};
+#[cfg(not(feature = "no_std"))]
+use std::io::{Error, Write};
+
+/// A [`Formatter`] which is chosen when it is created rather than by type
+pub(in crate::serialize) enum AnyFormatter {
+    /// Outputs JSON with minimal spacing
+    Compact(CompactFormatter),
+
+    /// Outputs JSON with spacing to make it easy to read
+    Pretty(PrettyFormatter),
+}
+
+impl Formatter for AnyFormatter {
+    fn write_array_begin<W: Write + ?Sized>(
+        &mut self,
+        output: &mut W,
+        len: Option<usize>,
+    ) -> Result<(), Error> {
+        match self {
+            AnyFormatter::Compact(formatter) => formatter.write_array_begin(output, len),
+            AnyFormatter::Pretty(formatter) => formatter.write_array_begin(output, len),
+        }
+    }
+
+    fn write_before_array_item<W: Write + ?Sized>(&mut self, output: &mut W) -> Result<(), Error> {
+        match self {
+            AnyFormatter::Compact(formatter) => formatter.write_before_array_item(output),
+            AnyFormatter::Pretty(formatter) => formatter.write_before_array_item(output),
+        }
+    }
+
+    fn write_after_array_item<W: Write + ?Sized>(&mut self, output: &mut W) -> Result<(), Error> {
+        match self {
+            AnyFormatter::Compact(formatter) => formatter.write_after_array_item(output),
+            AnyFormatter::Pretty(formatter) => formatter.write_after_array_item(output),
+        }
+    }
+
+    fn write_array_end<W: Write + ?Sized>(&mut self, output: &mut W) -> Result<(), Error> {
+        match self {
+            AnyFormatter::Compact(formatter) => formatter.write_array_end(output),
+            AnyFormatter::Pretty(formatter) => formatter.write_array_end(output),
+        }
+    }
+
+    fn write_object_begin<W: Write + ?Sized>(
+        &mut self,
+        output: &mut W,
+        len: Option<usize>,
+    ) -> Result<(), Error> {
+        match self {
+            AnyFormatter::Compact(formatter) => formatter.write_object_begin(output, len),
+            AnyFormatter::Pretty(formatter) => formatter.write_object_begin(output, len),
+        }
+    }
+
+    fn write_before_object_entry<W: Write + ?Sized>(
+        &mut self,
+        output: &mut W,
+    ) -> Result<(), Error> {
+        match self {
+            AnyFormatter::Compact(formatter) => formatter.write_before_object_entry(output),
+            AnyFormatter::Pretty(formatter) => formatter.write_before_object_entry(output),
+        }
+    }
+
+    fn write_after_object_entry<W: Write + ?Sized>(&mut self, output: &mut W) -> Result<(), Error> {
+        match self {
+            AnyFormatter::Compact(formatter) => formatter.write_after_object_entry(output),
+            AnyFormatter::Pretty(formatter) => formatter.write_after_object_entry(output),
+        }
+    }
+
+    fn write_before_object_key<W: Write + ?Sized>(&mut self, output: &mut W) -> Result<(), Error> {
+        match self {
+            AnyFormatter::Compact(formatter) => formatter.write_before_object_key(output),
+            AnyFormatter::Pretty(formatter) => formatter.write_before_object_key(output),
+        }
+    }
+
+    fn write_after_object_key<W: Write + ?Sized>(&mut self, output: &mut W) -> Result<(), Error> {
+        match self {
+            AnyFormatter::Compact(formatter) => formatter.write_after_object_key(output),
+            AnyFormatter::Pretty(formatter) => formatter.write_after_object_key(output),
+        }
+    }
+
+    fn write_object_end<W: Write + ?Sized>(&mut self, output: &mut W) -> Result<(), Error> {
+        match self {
+            AnyFormatter::Compact(formatter) => formatter.write_object_end(output),
+            AnyFormatter::Pretty(formatter) => formatter.write_object_end(output),
+        }
+    }
+}
@@ -4,9 +4,11 @@ use core::fmt::{Error, Write};
 #[cfg(not(feature = "no_std"))]
 use std::io::{Error, Write};
 
+mod any;
 mod compact;
 mod pretty;
 
+pub(super) use any::AnyFormatter;
 pub(super) use compact::CompactFormatter;
 pub(super) use pretty::PrettyFormatter;
 
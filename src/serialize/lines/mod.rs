@@ -0,0 +1,31 @@
+use super::{CompactFormatter, Serializer};
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+#[cfg(feature = "no_std")]
+use core::fmt::Write;
+#[cfg(not(feature = "no_std"))]
+use std::io::Write;
+
+mod new;
+mod write;
+
+/// Writes values as newline-delimited JSON (JSON Lines), each as compact JSON followed by '\n'.
+///
+/// Line breaks inside strings are always escaped and compact JSON has no other line breaks, so
+/// each record is exactly one line. Each record is serialized in full before any of it is
+/// written, so a record which fails to serialize leaves the output unchanged.
+pub struct JsonLinesWriter<W: Write> {
+    /// The output the records are written to
+    output: W,
+
+    /// The serializer for each record, which writes into a buffer reused between records
+    serializer: Serializer<Buffer, CompactFormatter>,
+}
+
+/// The buffer records are serialized into
+#[cfg(not(feature = "no_std"))]
+type Buffer = Vec<u8>;
+
+/// The buffer records are serialized into
+#[cfg(feature = "no_std")]
+type Buffer = String;
@@ -0,0 +1,21 @@
+use super::{Buffer, JsonLinesWriter};
+use crate::serialize::Serializer;
+#[cfg(feature = "no_std")]
+use core::fmt::Write;
+#[cfg(not(feature = "no_std"))]
+use std::io::Write;
+
+impl<W: Write> JsonLinesWriter<W> {
+    /// Creates a new [`JsonLinesWriter`] which writes records to `output`
+    pub fn new(output: W) -> Self {
+        JsonLinesWriter {
+            output,
+            serializer: Serializer::compact(Buffer::new()),
+        }
+    }
+
+    /// Takes the underlying output
+    pub fn into_inner(self) -> W {
+        self.output
+    }
+}
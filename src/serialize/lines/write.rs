@@ -0,0 +1,32 @@
+use super::JsonLinesWriter;
+use crate::{
+    serialize::{CompactFormatter, Result},
+    SerializeError,
+};
+#[cfg(feature = "no_std")]
+use core::fmt::Write;
+use data_format::Serialize;
+#[cfg(not(feature = "no_std"))]
+use std::io::Write;
+
+impl<W: Write> JsonLinesWriter<W> {
+    /// Writes `value` as the next record. Nothing is written if `value` fails to serialize.
+    pub fn write<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.serializer.output.clear();
+        self.serializer.formatter = CompactFormatter::new();
+        value.serialize(&mut self.serializer)?;
+        writeln!(self.serializer.output).map_err(SerializeError::io)?;
+
+        #[cfg(not(feature = "no_std"))]
+        let result = self.output.write_all(&self.serializer.output);
+        #[cfg(feature = "no_std")]
+        let result = self.output.write_str(&self.serializer.output);
+        result.map_err(SerializeError::io)
+    }
+
+    /// Flushes the underlying output
+    #[cfg(not(feature = "no_std"))]
+    pub fn flush(&mut self) -> Result<()> {
+        self.output.flush().map_err(SerializeError::io)
+    }
+}
@@ -3,21 +3,29 @@ use alloc::string::String;
 use data_format::Serialize;
 use error::Result;
 use escape::Escape;
-use formatter::{CompactFormatter, Formatter, PrettyFormatter};
+use formatter::{AnyFormatter, CompactFormatter, Formatter, PrettyFormatter};
 use list::ListSerializer;
 use map::MapSerializer;
 use serializer::Serializer;
 #[cfg(not(feature = "no_std"))]
 use std::io::Write;
 
+#[cfg(all(feature = "futures-io", not(feature = "no_std")))]
+mod async_write;
 mod error;
 mod escape;
 mod formatter;
+mod lines;
 mod list;
 mod map;
 mod serializer;
+mod writer;
 
+#[cfg(all(feature = "futures-io", not(feature = "no_std")))]
+pub use async_write::to_async_write;
 pub use error::SerializeError;
+pub use lines::JsonLinesWriter;
+pub use writer::JsonWriter;
 
 /// Serializes `value` into a compact JSON [`String`]
 #[cfg(not(feature = "no_std"))]
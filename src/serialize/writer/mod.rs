@@ -0,0 +1,39 @@
+use super::{AnyFormatter, Serializer};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+#[cfg(feature = "no_std")]
+use core::fmt::Write;
+#[cfg(not(feature = "no_std"))]
+use std::io::Write;
+
+mod new;
+mod write;
+
+/// Writes JSON a piece at a time, for output which is too large to build up front or which
+/// doesn't have a [`Serialize`](data_format::Serialize) type.
+///
+/// The order of calls is checked so the output is always valid JSON, with misuse returning
+/// [`SerializeError::InvalidCall`](crate::SerializeError::InvalidCall).
+pub struct JsonWriter<W: Write> {
+    /// The serializer writing the output
+    serializer: Serializer<W, AnyFormatter>,
+
+    /// The arrays and objects which have been begun but not ended, from the outermost in
+    stack: Vec<Frame>,
+
+    /// Has the top-level value been started?
+    started: bool,
+}
+
+/// An array or object being written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frame {
+    /// An array
+    Array,
+
+    /// An object, which needs a value if a key was just written
+    Object {
+        /// Has a key been written without its value?
+        key: bool,
+    },
+}
@@ -0,0 +1,29 @@
+use super::JsonWriter;
+use crate::serialize::{AnyFormatter, CompactFormatter, PrettyFormatter, Serializer};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+#[cfg(feature = "no_std")]
+use core::fmt::Write;
+#[cfg(not(feature = "no_std"))]
+use std::io::Write;
+
+impl<W: Write> JsonWriter<W> {
+    /// Creates a new [`JsonWriter`] which writes compact JSON to `output`
+    pub fn new(output: W) -> Self {
+        JsonWriter::with_formatter(output, AnyFormatter::Compact(CompactFormatter::new()))
+    }
+
+    /// Creates a new [`JsonWriter`] which writes pretty JSON to `output`
+    pub fn pretty(output: W) -> Self {
+        JsonWriter::with_formatter(output, AnyFormatter::Pretty(PrettyFormatter::new()))
+    }
+
+    /// Creates a new [`JsonWriter`] which writes to `output` using `formatter`
+    fn with_formatter(output: W, formatter: AnyFormatter) -> Self {
+        JsonWriter {
+            serializer: Serializer::with_formatter(output, formatter),
+            stack: Vec::new(),
+            started: false,
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use super::{Frame, JsonWriter};
+use crate::{
+    serialize::{Formatter, Result},
+    SerializeError,
+};
+#[cfg(feature = "no_std")]
+use core::fmt::Write;
+use data_format::Serialize;
+#[cfg(not(feature = "no_std"))]
+use std::io::Write;
+
+impl<W: Write> JsonWriter<W> {
+    /// Begins an object, whose entries are written with [`JsonWriter::key`] followed by a value
+    pub fn begin_object(&mut self) -> Result<()> {
+        self.before_value()?;
+        self.serializer
+            .formatter
+            .write_object_begin(&mut self.serializer.output, None)
+            .map_err(SerializeError::io)?;
+        self.stack.push(Frame::Object { key: false });
+        Ok(())
+    }
+
+    /// Begins an array
+    pub fn begin_array(&mut self) -> Result<()> {
+        self.before_value()?;
+        self.serializer
+            .formatter
+            .write_array_begin(&mut self.serializer.output, None)
+            .map_err(SerializeError::io)?;
+        self.stack.push(Frame::Array);
+        Ok(())
+    }
+
+    /// Writes the key of the next entry in the current object
+    pub fn key(&mut self, key: &str) -> Result<()> {
+        match self.stack.last_mut() {
+            Some(Frame::Object { key: pending }) if !*pending => *pending = true,
+            Some(Frame::Object { .. }) => {
+                return Err(SerializeError::InvalidCall(
+                    "a key was written instead of the previous key's value",
+                ))
+            }
+            _ => {
+                return Err(SerializeError::InvalidCall(
+                    "a key can only be written in an object",
+                ))
+            }
+        }
+
+        let serializer = &mut self.serializer;
+        serializer
+            .formatter
+            .write_before_object_entry(&mut serializer.output)
+            .map_err(SerializeError::io)?;
+        serializer
+            .formatter
+            .write_before_object_key(&mut serializer.output)
+            .map_err(SerializeError::io)?;
+
+        key.serialize(&mut *serializer)?;
+
+        serializer
+            .formatter
+            .write_after_object_key(&mut serializer.output)
+            .map_err(SerializeError::io)?;
+        serializer
+            .formatter
+            .write_before_object_value(&mut serializer.output)
+            .map_err(SerializeError::io)
+    }
+
+    /// Writes `value` as the next value
+    pub fn value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.before_value()?;
+        value.serialize(&mut self.serializer)?;
+        self.after_value()
+    }
+
+    /// Ends the current array or object
+    pub fn end(&mut self) -> Result<()> {
+        match self.stack.last() {
+            Some(Frame::Array) => self
+                .serializer
+                .formatter
+                .write_array_end(&mut self.serializer.output),
+            Some(Frame::Object { key: false }) => self
+                .serializer
+                .formatter
+                .write_object_end(&mut self.serializer.output),
+            Some(Frame::Object { key: true }) => {
+                return Err(SerializeError::InvalidCall(
+                    "an object was ended after a key without a value",
+                ))
+            }
+            None => {
+                return Err(SerializeError::InvalidCall(
+                    "there is no array or object to end",
+                ))
+            }
+        }
+        .map_err(SerializeError::io)?;
+
+        self.stack.pop();
+        self.after_value()
+    }
+
+    /// Checks the top-level value has been completely written, returning the output
+    pub fn finish(self) -> Result<W> {
+        if !self.started {
+            return Err(SerializeError::InvalidCall("no value was written"));
+        }
+
+        if !self.stack.is_empty() {
+            return Err(SerializeError::InvalidCall(
+                "an array or object was not ended",
+            ));
+        }
+
+        Ok(self.serializer.output)
+    }
+
+    /// Checks a value can be written next, writing anything needed before it
+    fn before_value(&mut self) -> Result<()> {
+        match self.stack.last() {
+            None if self.started => Err(SerializeError::InvalidCall(
+                "only one top-level value can be written",
+            )),
+            None => {
+                self.started = true;
+                Ok(())
+            }
+            Some(Frame::Array) => self
+                .serializer
+                .formatter
+                .write_before_array_item(&mut self.serializer.output)
+                .map_err(SerializeError::io),
+            Some(Frame::Object { key: true }) => Ok(()),
+            Some(Frame::Object { key: false }) => Err(SerializeError::InvalidCall(
+                "a value in an object must follow a key",
+            )),
+        }
+    }
+
+    /// Writes anything needed after a value has been completely written
+    fn after_value(&mut self) -> Result<()> {
+        let serializer = &mut self.serializer;
+        match self.stack.last_mut() {
+            None => Ok(()),
+            Some(Frame::Array) => serializer
+                .formatter
+                .write_after_array_item(&mut serializer.output)
+                .map_err(SerializeError::io),
+            Some(Frame::Object { key }) => {
+                *key = false;
+                serializer
+                    .formatter
+                    .write_after_object_value(&mut serializer.output)
+                    .map_err(SerializeError::io)?;
+                serializer
+                    .formatter
+                    .write_after_object_entry(&mut serializer.output)
+                    .map_err(SerializeError::io)
+            }
+        }
+    }
+}
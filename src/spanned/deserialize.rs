@@ -0,0 +1,33 @@
+use crate::{deserialize::SPANNED_MAGIC, Spanned};
+use core::marker::PhantomData;
+use data_format::{Converter, Deserialize, DeserializeError, MapDeserializer};
+use lct_streams::Position;
+
+/// Converts the span and value passed by the JSON deserializer into a [`Spanned`]
+struct SpannedConverter<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Spanned<T> {
+    fn deserialize<D: data_format::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(SpannedConverter(PhantomData))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Converter<'de> for SpannedConverter<T> {
+    type Value = Spanned<T>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(SPANNED_MAGIC)
+    }
+
+    fn convert_map<M: MapDeserializer<'de>>(self, mut map: M) -> Result<Self::Value, M::Error> {
+        if map.next_key::<()>()?.is_some() {
+            return Err(M::Error::custom("spans can only be deserialized from JSON"));
+        }
+
+        let start = Position::new(map.next_value()?, map.next_value()?);
+        let value = map.next_value()?;
+        let end = Position::new(map.next_value()?, map.next_value()?);
+
+        Ok(Spanned::new(value, start, end))
+    }
+}
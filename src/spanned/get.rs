@@ -0,0 +1,29 @@
+use crate::Spanned;
+use lct_streams::Position;
+
+impl<T> Spanned<T> {
+    /// Gets the deserialized value
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Gets a mutable reference to the deserialized value
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Gets the position of the first character of the value
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Gets the position after the last character of the value
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Takes the deserialized value, discarding its span
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
@@ -0,0 +1,25 @@
+use lct_streams::Position;
+
+mod deserialize;
+mod get;
+mod new;
+mod serialize;
+mod value;
+
+pub use value::SpannedValue;
+
+/// A value along with the span of the JSON it was deserialized from.
+///
+/// The span is only known when deserializing from JSON with this crate; other deserializers will
+/// produce an error.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spanned<T> {
+    /// The deserialized value
+    value: T,
+
+    /// The position of the first character of the value
+    start: Position,
+
+    /// The position after the last character of the value
+    end: Position,
+}
@@ -0,0 +1,9 @@
+use crate::Spanned;
+use lct_streams::Position;
+
+impl<T> Spanned<T> {
+    /// Creates a new [`Spanned`] for `value` which was read from `start` up to `end`
+    pub(crate) fn new(value: T, start: Position, end: Position) -> Self {
+        Spanned { value, start, end }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::Spanned;
+use data_format::Serialize;
+
+impl<T: Serialize> Serialize for Spanned<T> {
+    fn serialize<S: data_format::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{Spanned, Value};
+#[cfg(feature = "no_std")]
+use alloc::{
+    borrow::{Cow, ToOwned},
+    collections::BTreeMap,
+    string::String,
+    vec::Vec,
+};
+use data_format::{
+    deserialize::{BTreeMapConverter, VecConverter},
+    Converter, Deserialize,
+};
+#[cfg(not(feature = "no_std"))]
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// A JSON value where every element of an array and member of an object carries its span.
+///
+/// Deserialize a `Spanned<SpannedValue>` to also get the span of the root value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpannedValue<'de> {
+    #[allow(missing_docs)]
+    Null,
+
+    #[allow(missing_docs)]
+    Boolean(bool),
+
+    #[allow(missing_docs)]
+    Number(f64),
+
+    #[allow(missing_docs)]
+    String(Cow<'de, str>),
+
+    #[allow(missing_docs)]
+    Array(Vec<Spanned<SpannedValue<'de>>>),
+
+    #[allow(missing_docs)]
+    Object(BTreeMap<Cow<'de, str>, Spanned<SpannedValue<'de>>>),
+}
+
+/// Converts JSON into a [`SpannedValue`]
+struct SpannedValueConverter;
+
+impl<'de> SpannedValue<'de> {
+    /// Converts this into a [`Value`], discarding all of the spans
+    pub fn into_value(self) -> Value<'de> {
+        match self {
+            SpannedValue::Null => Value::Null,
+            SpannedValue::Boolean(value) => Value::Boolean(value),
+            SpannedValue::Number(value) => Value::Number(value),
+            SpannedValue::String(value) => Value::String(value),
+            SpannedValue::Array(array) => Value::Array(
+                array
+                    .into_iter()
+                    .map(|value| value.into_inner().into_value())
+                    .collect(),
+            ),
+            SpannedValue::Object(object) => Value::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (key, value.into_inner().into_value()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl<'de> From<SpannedValue<'de>> for Value<'de> {
+    fn from(value: SpannedValue<'de>) -> Self {
+        value.into_value()
+    }
+}
+
+impl<'de> Deserialize<'de> for SpannedValue<'de> {
+    fn deserialize<D: data_format::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SpannedValueConverter)
+    }
+}
+
+impl<'de> Converter<'de> for SpannedValueConverter {
+    type Value = SpannedValue<'de>;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn convert_unit<E: data_format::DeserializeError<'de>>(self) -> Result<Self::Value, E> {
+        Ok(SpannedValue::Null)
+    }
+
+    fn convert_bool<E: data_format::DeserializeError<'de>>(
+        self,
+        value: bool,
+    ) -> Result<Self::Value, E> {
+        Ok(SpannedValue::Boolean(value))
+    }
+
+    fn convert_i128<E: data_format::DeserializeError<'de>>(
+        self,
+        value: i128,
+    ) -> Result<Self::Value, E> {
+        Ok(SpannedValue::Number(value as f64))
+    }
+
+    fn convert_u128<E: data_format::DeserializeError<'de>>(
+        self,
+        value: u128,
+    ) -> Result<Self::Value, E> {
+        Ok(SpannedValue::Number(value as f64))
+    }
+
+    fn convert_f64<E: data_format::DeserializeError<'de>>(
+        self,
+        value: f64,
+    ) -> Result<Self::Value, E> {
+        Ok(SpannedValue::Number(value))
+    }
+
+    fn convert_str_borrow<E: data_format::DeserializeError<'de>>(
+        self,
+        value: Cow<'de, str>,
+    ) -> Result<Self::Value, E> {
+        Ok(SpannedValue::String(value))
+    }
+
+    fn convert_string<E: data_format::DeserializeError<'de>>(
+        self,
+        value: String,
+    ) -> Result<Self::Value, E> {
+        Ok(SpannedValue::String(value.into()))
+    }
+
+    fn convert_str<E: data_format::DeserializeError<'de>>(
+        self,
+        value: &str,
+    ) -> Result<Self::Value, E> {
+        Ok(SpannedValue::String(value.to_owned().into()))
+    }
+
+    fn convert_list<L: data_format::ListDeserializer<'de>>(
+        self,
+        list: L,
+    ) -> Result<Self::Value, L::Error> {
+        Ok(SpannedValue::Array(VecConverter::new().convert_list(list)?))
+    }
+
+    fn convert_map<M: data_format::MapDeserializer<'de>>(
+        self,
+        map: M,
+    ) -> Result<Self::Value, M::Error> {
+        Ok(SpannedValue::Object(
+            BTreeMapConverter::new().convert_map(map)?,
+        ))
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{from_async_read, to_async_write, DeserializeErrorKind, SerializeError};
+use core::{
+    future::Future,
+    pin::{pin, Pin},
+    task::{Context, Poll, Waker},
+};
+use data_format::{Serialize, SerializeError as _, Serializer};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::{cell::RefCell, collections::VecDeque, io::Result, rc::Rc};
+
+/// The shared buffer of an in-memory pipe
+struct Pipe {
+    buffer: VecDeque<u8>,
+    capacity: usize,
+    closed: bool,
+}
+
+/// The writing end of an in-memory pipe, which closes it when dropped
+struct PipeWriter(Rc<RefCell<Pipe>>);
+
+/// The reading end of an in-memory pipe
+struct PipeReader(Rc<RefCell<Pipe>>);
+
+/// Creates an in-memory pipe which holds at most `capacity` bytes at once
+fn pipe(capacity: usize) -> (PipeWriter, PipeReader) {
+    let pipe = Rc::new(RefCell::new(Pipe {
+        buffer: VecDeque::new(),
+        capacity,
+        closed: false,
+    }));
+    (PipeWriter(pipe.clone()), PipeReader(pipe))
+}
+
+impl AsyncWrite for PipeWriter {
+    fn poll_write(self: Pin<&mut Self>, _: &mut Context, buf: &[u8]) -> Poll<Result<usize>> {
+        let mut pipe = self.0.borrow_mut();
+        let length = buf.len().min(pipe.capacity - pipe.buffer.len());
+        if length == 0 && !buf.is_empty() {
+            return Poll::Pending;
+        }
+
+        pipe.buffer.extend(&buf[..length]);
+        Poll::Ready(Ok(length))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context) -> Poll<Result<()>> {
+        self.0.borrow_mut().closed = true;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Drop for PipeWriter {
+    fn drop(&mut self) {
+        self.0.borrow_mut().closed = true;
+    }
+}
+
+impl AsyncRead for PipeReader {
+    fn poll_read(self: Pin<&mut Self>, _: &mut Context, buf: &mut [u8]) -> Poll<Result<usize>> {
+        let mut pipe = self.0.borrow_mut();
+        if pipe.buffer.is_empty() && !pipe.closed {
+            return Poll::Pending;
+        }
+
+        let length = buf.len().min(pipe.buffer.len());
+        for (byte, value) in buf.iter_mut().zip(pipe.buffer.drain(..length)) {
+            *byte = value;
+        }
+        Poll::Ready(Ok(length))
+    }
+}
+
+/// Drives `write` and `read` together until `read` completes, dropping `write` once it is done
+fn run<W, R>(write: W, read: R) -> R::Output
+where
+    W: Future<Output = core::result::Result<(), SerializeError>>,
+    R: Future,
+{
+    let mut context = Context::from_waker(Waker::noop());
+    let mut write = Some(Box::pin(write));
+    let mut read = pin!(read);
+
+    loop {
+        if let Some(future) = &mut write {
+            if let Poll::Ready(result) = future.as_mut().poll(&mut context) {
+                result.unwrap();
+                write = None;
+            }
+        }
+
+        if let Poll::Ready(output) = read.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn async_round_trip() {
+    let value: Vec<String> = (0..2000).map(|i| format!("item {}", i)).collect();
+
+    let (writer, reader) = pipe(100);
+    let result: Vec<String> =
+        run(to_async_write(&value[..], writer), from_async_read(reader)).unwrap();
+    assert_eq!(result, value);
+
+    let (writer, reader) = pipe(1);
+    let result: f64 = run(to_async_write(&1.5, writer), from_async_read(reader)).unwrap();
+    assert_eq!(result, 1.5);
+}
+
+/// A list element which either serializes as a string or fails to serialize
+enum Element {
+    Text(String),
+    Fail,
+}
+
+impl Serialize for Element {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        match self {
+            Element::Text(text) => text.serialize(serializer),
+            Element::Fail => Err(S::Error::custom("failed")),
+        }
+    }
+}
+
+#[test]
+fn async_write_chunks() {
+    let value = [Element::Text("a".repeat(20000)), Element::Fail];
+    let (writer, reader) = pipe(usize::MAX);
+    let mut context = Context::from_waker(Waker::noop());
+    let result = pin!(to_async_write(&value[..], writer)).poll(&mut context);
+
+    // The chunks filled before the error were already written
+    assert!(matches!(
+        result,
+        Poll::Ready(Err(SerializeError::Custom(_)))
+    ));
+    let written = reader.0.borrow().buffer.len();
+    assert_eq!(written, 16384);
+
+    let (writer, reader) = pipe(3000);
+    let value: Vec<String> = (0..3000).map(|i| "x".repeat(i % 50)).collect();
+    let result: Vec<String> =
+        run(to_async_write(&value[..], writer), from_async_read(reader)).unwrap();
+    assert_eq!(result, value);
+}
+
+#[test]
+fn async_read_errors() {
+    let (writer, reader) = pipe(4);
+    let error = run(
+        to_async_write(&[1, 2][..], writer),
+        from_async_read::<String, _>(reader),
+    )
+    .unwrap_err();
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (1, 1));
+
+    let (mut writer, reader) = pipe(4);
+    let write = async move {
+        let chunk = b"\n  [1, }";
+        let mut written = 0;
+        while written < chunk.len() {
+            written +=
+                core::future::poll_fn(|cx| Pin::new(&mut writer).poll_write(cx, &chunk[written..]))
+                    .await
+                    .unwrap();
+        }
+        Ok(())
+    };
+    let error = run(write, from_async_read::<Vec<usize>, _>(reader)).unwrap_err();
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (2, 7));
+    assert_eq!(error.offset(), Some(7));
+
+    let (writer, reader) = pipe(4);
+    let error = run(
+        async move {
+            drop(writer);
+            Ok(())
+        },
+        from_async_read::<usize, _>(reader),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        DeserializeErrorKind::UnexpectedEndOfJSON
+    ));
+}
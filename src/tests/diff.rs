@@ -0,0 +1,115 @@
+use crate::{ArrayDiff, Patch, PatchOperation, Value};
+#[cfg(feature = "no_std")]
+use alloc::{string::ToString, vec::Vec};
+
+const PAIRS: &[(&str, &str)] = &[
+    ("null", "null"),
+    ("1", "2"),
+    ("{}", "[]"),
+    (r#"{"a": 1, "b": 2}"#, r#"{"b": 3, "c": 4}"#),
+    (
+        r#"{"a": {"b": [1, 2]}}"#,
+        r#"{"a": {"b": [1, 2, 3], "c": true}}"#,
+    ),
+    ("[1, 2, 3, 4, 5]", "[1, 3, 4]"),
+    ("[1, 2, 3]", "[0, 1, 2, 2.5, 3, 4]"),
+    ("[1, 2, 3]", "[3, 2, 1]"),
+    (
+        r#"[{"id": 1}, {"id": 2}, {"id": 3}]"#,
+        r#"[{"id": 1}, {"id": 3, "x": 1}]"#,
+    ),
+    (
+        r#"["a", "b", "c", "d"]"#,
+        r#"["x", "b", "y", "z", "d", "e"]"#,
+    ),
+    ("[]", "[[], {}, null]"),
+    (r#"{"a/b": ["~"]}"#, r#"{"a/b": ["~", "/"]}"#),
+];
+
+/// Checks that the diff between each pair turns the first into the second
+fn run_pairs(array_diff: ArrayDiff) {
+    for (old, new) in PAIRS {
+        let mut old: Value = crate::from_str(old).unwrap();
+        let new: Value = crate::from_str(new).unwrap();
+
+        let patch: Patch = old.diff_with(&new, array_diff).into();
+        let json = crate::to_str(&patch).unwrap();
+        let patch: Patch = crate::from_str(&json).unwrap();
+
+        old.apply_patch(&patch).unwrap();
+        assert_eq!(old, new, "{}", json);
+    }
+}
+
+#[test]
+fn diff_index_pairs() {
+    run_pairs(ArrayDiff::Index)
+}
+
+#[test]
+fn diff_lcs_pairs() {
+    run_pairs(ArrayDiff::Lcs)
+}
+
+#[test]
+fn diff_equal() {
+    let value: Value = crate::from_str(r#"{"a": [1, {"b": null}]}"#).unwrap();
+    assert!(value.diff(&value.clone()).is_empty());
+}
+
+#[test]
+fn diff_object() {
+    let old: Value = crate::from_str(r#"{"a": 1, "b": {"c": 2}}"#).unwrap();
+    let new: Value = crate::from_str(r#"{"b": {"c": 3}, "d": 4}"#).unwrap();
+
+    let operations = old.diff(&new);
+    let paths: Vec<_> = operations
+        .iter()
+        .map(|operation| (operation.op(), operation.path().to_string()))
+        .collect();
+    assert_eq!(
+        paths,
+        [("remove", "/a"), ("replace", "/b/c"), ("add", "/d")].map(|(op, path)| (op, path.into()))
+    );
+}
+
+#[test]
+fn diff_array_insert() {
+    let old: Value = crate::from_str("[1, 2, 3, 4]").unwrap();
+    let new: Value = crate::from_str("[1, 5, 2, 3, 4]").unwrap();
+
+    assert_eq!(old.diff(&new).len(), 4);
+    assert_eq!(
+        old.diff_with(&new, ArrayDiff::Lcs),
+        [PatchOperation::Add {
+            path: "/1".parse().unwrap(),
+            value: Value::Number(5.0),
+        }]
+    );
+}
+
+#[test]
+fn diff_array_remove() {
+    let old: Value = crate::from_str("[1, 2, 3, 4]").unwrap();
+    let new: Value = crate::from_str("[2, 3, 4]").unwrap();
+
+    assert_eq!(
+        old.diff_with(&new, ArrayDiff::Lcs),
+        [PatchOperation::Remove {
+            path: "/0".parse().unwrap(),
+        }]
+    );
+}
+
+#[test]
+fn diff_lcs_large_arrays() {
+    let mut old = Value::Array((0..100_000).map(|i| Value::Number(i as f64)).collect());
+    let new = Value::Array((1..=100_001).map(|i| Value::Number(i as f64)).collect());
+
+    let operations = old.diff_with(&new, ArrayDiff::Lcs);
+    assert_eq!(operations.len(), 100_001);
+    assert_eq!(operations[100_000].op(), "add");
+
+    old.apply_patch(&operations.into()).unwrap();
+    assert_eq!(old, new);
+}
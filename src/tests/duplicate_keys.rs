@@ -0,0 +1,86 @@
+use crate::{DeserializeErrorKind, DeserializeOptions, DuplicateKeys, Value};
+#[cfg(feature = "no_std")]
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeMap;
+
+const JSON: &str = "{\"a\": 1, \"b\": 2, \"a\": 3, \"\\u0061\": 4}";
+
+fn run_policy(duplicate_keys: DuplicateKeys) -> BTreeMap<String, usize> {
+    let mut options = DeserializeOptions::new();
+    options.set_duplicate_keys(duplicate_keys);
+    crate::from_str_with(JSON, options).unwrap()
+}
+
+#[test]
+fn duplicate_keys_allow() {
+    let map = run_policy(DuplicateKeys::Allow);
+    assert_eq!(map, BTreeMap::from([("a".into(), 4), ("b".into(), 2)]));
+}
+
+#[test]
+fn duplicate_keys_keep_first() {
+    let map = run_policy(DuplicateKeys::KeepFirst);
+    assert_eq!(map, BTreeMap::from([("a".into(), 1), ("b".into(), 2)]));
+}
+
+#[test]
+fn duplicate_keys_keep_last() {
+    let map = run_policy(DuplicateKeys::KeepLast);
+    assert_eq!(map, BTreeMap::from([("a".into(), 4), ("b".into(), 2)]));
+}
+
+#[test]
+fn duplicate_keys_keep_first_nested() {
+    let mut options = DeserializeOptions::new();
+    options.set_duplicate_keys(DuplicateKeys::KeepFirst);
+    let value: Value = crate::from_str_with(
+        "{\"a\": {\"x\": [1, {\"y\": null}]}, \"a\": {\"z\": true}, \"b\": \"a\"}",
+        options,
+    )
+    .unwrap();
+
+    let Value::Object(object) = value else {
+        panic!("expected an object");
+    };
+    assert_eq!(object.len(), 2);
+    assert!(matches!(&object["a"], Value::Object(a) if a.contains_key("x")));
+}
+
+#[test]
+fn duplicate_keys_keep_last_nested() {
+    let mut options = DeserializeOptions::new();
+    options.set_duplicate_keys(DuplicateKeys::KeepLast);
+    let value: Value = crate::from_str_with(
+        "{\"a\": [{\"x\": 1, \"x\": {\"y\": 2, \"y\": 3}}], \"b\": {\"z\": 4, \"z\": 5}, \"a\": [{\"x\": {\"y\": 6, \"y\": 7}, \"x\": {\"y\": 8}}]}",
+        options,
+    )
+    .unwrap();
+
+    let expected: Value =
+        crate::from_str("{\"a\": [{\"x\": {\"y\": 8}}], \"b\": {\"z\": 5}}").unwrap();
+    assert_eq!(value, expected);
+}
+
+#[test]
+fn duplicate_keys_reject() {
+    let mut options = DeserializeOptions::new();
+    options.set_duplicate_keys(DuplicateKeys::Reject);
+    let error = crate::from_str_with::<BTreeMap<String, usize>>(JSON, options).unwrap_err();
+
+    match error.kind() {
+        DeserializeErrorKind::DuplicateKey(key) => assert_eq!(key, "a"),
+        kind => panic!("unexpected error kind {}", kind),
+    }
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (1, 18));
+}
+
+#[test]
+fn duplicate_keys_reject_separate_objects() {
+    let mut options = DeserializeOptions::new();
+    options.set_duplicate_keys(DuplicateKeys::Reject);
+    let list: Vec<BTreeMap<String, usize>> =
+        crate::from_str_with("[{\"a\": 1}, {\"a\": 2}]", options).unwrap();
+    assert_eq!(list.len(), 2);
+}
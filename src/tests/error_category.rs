@@ -0,0 +1,64 @@
+use crate::{
+    DeserializeError, DeserializeErrorKind, DeserializeOptions, DuplicateKeys, ErrorCategory, Value,
+};
+#[cfg(feature = "no_std")]
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+#[test]
+fn error_category_classify() {
+    let classify = |json: &str| crate::from_str::<Value>(json).unwrap_err().classify();
+
+    assert_eq!(classify("[1 2]"), ErrorCategory::Syntax);
+    assert_eq!(classify("{\"a\" 1}"), ErrorCategory::Syntax);
+    assert_eq!(classify("[1, 2"), ErrorCategory::Eof);
+    assert_eq!(classify(""), ErrorCategory::Eof);
+
+    let mut options = DeserializeOptions::new();
+    options.set_duplicate_keys(DuplicateKeys::Reject);
+    assert_eq!(
+        crate::from_str_with::<Value>(r#"{"a": 1, "a": 2}"#, options)
+            .unwrap_err()
+            .classify(),
+        ErrorCategory::Data
+    );
+}
+
+#[test]
+fn error_category_into_owned() {
+    fn assert_static_send_sync<T: 'static + Send + Sync + core::error::Error>(_: &T) {}
+
+    let input = String::from(r#"{"a": [true, {"b": 1}]}"#);
+    let error = crate::from_str::<Value>(&input[..input.len() - 3])
+        .unwrap_err()
+        .into_owned();
+    drop(input);
+
+    assert_static_send_sync(&error);
+    assert_eq!(error.classify(), ErrorCategory::Eof);
+    assert!(error.position().is_some());
+    assert_eq!(error.path().to_string(), ".a[1]");
+
+    let key = String::from("field");
+    let error = DeserializeError::from(DeserializeErrorKind::UnknownField {
+        field: Cow::Borrowed(key.as_str()),
+        expected: &["a", "b"],
+    })
+    .into_owned();
+    drop(key);
+    assert!(matches!(
+        error.kind(),
+        DeserializeErrorKind::UnknownField { field: Cow::Owned(field), .. } if field == "field"
+    ));
+}
+
+#[test]
+fn error_category_display_without_position() {
+    let error = DeserializeError::from(DeserializeErrorKind::MissingField("port"));
+    assert_eq!(error.position(), None);
+    assert_eq!(error.to_string(), "missing field \"port\"");
+}
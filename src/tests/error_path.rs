@@ -0,0 +1,48 @@
+use crate::{PathElement, Value};
+#[cfg(feature = "no_std")]
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeMap;
+
+#[test]
+fn error_path_root() {
+    let error = crate::from_str::<Value>("tru").unwrap_err();
+    assert!(error.path().is_root());
+    assert_eq!(error.path().to_string(), ".");
+    assert!(!error.to_string().contains(" ("));
+}
+
+#[test]
+fn error_path_syntax() {
+    let error =
+        crate::from_str::<Value>(r#"{"servers": [{"port": 80}, {"host": "a"}, {"port": tru}]}"#)
+            .unwrap_err();
+
+    assert_eq!(
+        error.path().elements().cloned().collect::<Vec<_>>(),
+        [
+            PathElement::Name("servers".to_string()),
+            PathElement::Index(2),
+            PathElement::Name("port".to_string()),
+        ]
+    );
+    assert_eq!(error.path().to_string(), ".servers[2].port");
+    assert!(error.to_string().contains(" at .servers[2].port ("));
+}
+
+#[test]
+fn error_path_data() {
+    let error = crate::from_str::<BTreeMap<String, Vec<bool>>>(r#"{"a": [true], "b": [false, 1]}"#)
+        .unwrap_err();
+    assert_eq!(error.path().to_string(), ".b[1]");
+}
+
+#[test]
+fn error_path_quoted_names() {
+    let error = crate::from_str::<Value>(r#"{"a b": {"\"": [nul]}}"#).unwrap_err();
+    assert_eq!(error.path().to_string(), r#"["a b"]["\""][0]"#);
+}
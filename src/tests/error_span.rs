@@ -0,0 +1,44 @@
+#[cfg(feature = "no_std")]
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeMap;
+
+/// Gets the (line, column) of the start and end of the error from deserializing `json` as `T`
+fn span<'de, T: data_format::Deserialize<'de>>(json: &'de str) -> ((usize, usize), (usize, usize)) {
+    let (start, end) = match crate::from_str::<T>(json) {
+        Ok(_) => panic!("{} deserialized", json),
+        Err(error) => error.span().unwrap(),
+    };
+    ((start.line(), start.column()), (end.line(), end.column()))
+}
+
+#[test]
+fn error_span_token() {
+    assert_eq!(span::<usize>("  1.5"), ((1, 3), (1, 6)));
+    assert_eq!(span::<isize>("1e3"), ((1, 1), (1, 4)));
+    assert_eq!(span::<Vec<isize>>("[1e3]"), ((1, 2), (1, 5)));
+    assert_eq!(span::<isize>("[1e3]"), ((1, 1), (1, 2)));
+    assert_eq!(span::<Vec<isize>>("[1, 2e3]"), ((1, 5), (1, 8)));
+    assert_eq!(span::<bool>("trve"), ((1, 3), (1, 4)));
+}
+
+#[test]
+fn error_span_innermost() {
+    assert_eq!(
+        span::<BTreeMap<String, Vec<usize>>>("{\"a\": [1],\n \"b\": [2, -3]}"),
+        ((2, 11), (2, 13))
+    );
+    assert_eq!(span::<Vec<Vec<u8>>>("[[1], [2, 300]]"), ((1, 11), (1, 14)));
+}
+
+#[test]
+fn error_span_snippet() {
+    let json = "[1, 2.25]";
+    let error = crate::from_str::<Vec<usize>>(json).unwrap_err();
+    let rendered = error.snippet(json).to_string();
+    assert!(rendered.ends_with("\n  |     ^^^^"), "{}", rendered);
+}
@@ -0,0 +1,123 @@
+use super::Random;
+#[cfg(feature = "no_std")]
+use alloc::{format, string::String};
+
+/// Checks that `text` deserializes to exactly the same float as [`str::parse`] gives
+fn check(text: &str) {
+    let expected: f64 = text.parse().unwrap();
+    let value: f64 = crate::from_str(text).unwrap();
+    assert_eq!(value.to_bits(), expected.to_bits(), "{}", text);
+}
+
+/// Creates a random string of between `min` and `max` digits, which starts with a non-zero digit
+/// if `leading` is set
+fn digits(random: &mut Random, min: u64, max: u64, leading: bool) -> String {
+    let length = min + random.below(max - min + 1);
+    (0..length)
+        .map(|index| match (index, leading) {
+            (0, true) => (b'1' + random.below(9) as u8) as char,
+            // Runs of 0s and 9s make numbers close to halfway between floats more likely
+            _ => match random.below(4) {
+                0 => '0',
+                1 => '9',
+                _ => (b'0' + random.below(10) as u8) as char,
+            },
+        })
+        .collect()
+}
+
+#[test]
+fn float_edge_cases() {
+    for text in [
+        "0",
+        "-0",
+        "0.0",
+        "-0.0e-5",
+        "1e-400",
+        "-1e400",
+        "1e99999999999999999999999",
+        "1e-99999999999999999999999",
+        "9007199254740993",
+        "9007199254740992.000000000000000000001",
+        "2.2250738585072011e-308",
+        "2.2250738585072012e-308",
+        "4.9406564584124654e-324",
+        "2.4703282292062327e-324",
+        "2.4703282292062328e-324",
+        "1.7976931348623157e308",
+        "1.7976931348623158e308",
+        "1.7976931348623159e308",
+        "0.1",
+        "0.30000000000000004",
+        "123456789012345678901234567890",
+        "0.000000000000000000000000000000000001",
+        "7.038531e-26",
+        "1448997445238699",
+        "8.988465674311579e307",
+    ] {
+        check(text);
+    }
+}
+
+#[test]
+fn float_random_decimals() {
+    let mut random = Random::new(0x5EED_F10A);
+    for _ in 0..100_000 {
+        let mut text = String::new();
+        if random.below(2) == 0 {
+            text.push('-');
+        }
+
+        match random.below(3) {
+            0 => text.push('0'),
+            _ => text.push_str(&digits(&mut random, 1, 25, true)),
+        }
+
+        if random.below(3) != 0 {
+            text.push('.');
+            text.push_str(&digits(&mut random, 1, 25, false));
+        }
+
+        if random.below(3) != 0 {
+            text.push(if random.below(2) == 0 { 'e' } else { 'E' });
+            match random.below(3) {
+                0 => text.push('-'),
+                1 => text.push('+'),
+                _ => {}
+            }
+            text.push_str(&format!("{}", random.below(360)));
+        }
+
+        check(&text);
+    }
+}
+
+#[test]
+fn float_random_bits() {
+    let mut random = Random::new(0x5EED_B175);
+    for _ in 0..50_000 {
+        let value = f64::from_bits(random.next_u64());
+        if !value.is_finite() {
+            continue;
+        }
+
+        check(&format!("{}", value));
+        check(&format!("{:e}", value));
+        check(&format!("{:.17e}", value));
+    }
+}
+
+#[test]
+fn float_random_halfway() {
+    let mut random = Random::new(0x5EED_4A1F);
+    for _ in 0..50_000 {
+        // An odd 54 bit integer is exactly halfway between two floats, as is any multiple of it by
+        // a power of two
+        let halfway = ((random.next_u64() >> 11) | (1 << 53) | 1) as u128;
+        let text = format!("{}", halfway << random.below(74));
+
+        check(&text);
+        check(&format!("{}.{}1", text, "0".repeat(random.below(30) as usize)));
+        check(&format!("{}e-{}", text, random.below(40)));
+    }
+}
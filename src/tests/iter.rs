@@ -0,0 +1,187 @@
+use crate::{iter_array, iter_object, PathElement, Value};
+#[cfg(not(feature = "no_std"))]
+use crate::{iter_array_from_reader, iter_object_from_reader};
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec::Vec};
+#[cfg(not(feature = "no_std"))]
+use std::{borrow::Cow, collections::BTreeMap};
+
+#[test]
+fn iter_array_elements() {
+    let json = " [ {\"a\": 1}, [2], \"three\", 4 ] ";
+    let elements: Vec<Value> = iter_array(json).map(Result::unwrap).collect();
+    assert_eq!(elements, crate::from_str::<Vec<Value>>(json).unwrap());
+
+    let mut iter = iter_array::<usize>(b"[1, 2]\n");
+    assert_eq!(iter.next().unwrap().unwrap(), 1);
+    assert_eq!(iter.index(), 1);
+    assert_eq!(iter.next().unwrap().unwrap(), 2);
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+
+    assert_eq!(iter_array::<usize>("[]").count(), 0);
+}
+
+#[test]
+fn iter_array_errors() {
+    let json = "[\n  {\"port\": 1},\n  {\"port\": 2},\n  {\"port\": -3}\n]";
+    let mut iter = iter_array::<Value>(json);
+    assert!(iter.next().unwrap().is_ok());
+
+    let results: Vec<_> = iter_array::<BTreeMap<String, usize>>(json).collect();
+    assert_eq!(results.len(), 3);
+    let error = results[2].as_ref().unwrap_err();
+    assert_eq!(
+        error.path().elements().cloned().collect::<Vec<_>>(),
+        [PathElement::Index(2), PathElement::Name("port".into())]
+    );
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (4, 12));
+    assert_eq!(error.offset(), Some(json.find("-3").unwrap()));
+
+    for json in ["", "{}", "[1 2]", "[1,]", "[1] 2", "[1"] {
+        assert!(
+            iter_array::<Value>(json).any(|result| result.is_err()),
+            "{}",
+            json
+        );
+    }
+}
+
+#[test]
+fn iter_object_entries() {
+    let json = r#"{"a": 1, "b\n": [2], "c": null}"#;
+    let entries: Vec<(Cow<str>, Value)> = iter_object(json).map(Result::unwrap).collect();
+    assert_eq!(
+        entries,
+        [
+            ("a".into(), Value::Number(1.0)),
+            ("b\n".into(), Value::Array([Value::Number(2.0)].into())),
+            ("c".into(), Value::Null),
+        ]
+    );
+    assert_eq!(iter_object::<Value>(" {} ").count(), 0);
+
+    let error = iter_object::<usize>(r#"{"a": 1, "b": true}"#)
+        .nth(1)
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(
+        error.path().elements().cloned().collect::<Vec<_>>(),
+        [PathElement::Name("b".into())]
+    );
+
+    for json in ["[]", "{\"a\" 1}", "{\"a\": 1,}", "{\"a\": 1} {}"] {
+        assert!(
+            iter_object::<Value>(json).any(|result| result.is_err()),
+            "{}",
+            json
+        );
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn iter_array_from_reader_elements() {
+    let json = "\n [ {\"a]\": 1}, {\"b\": 2, \"c{\": 3} ,{}, {\"d\\\"]\": 4, \"é\": 5} ] ";
+    let expected: Vec<BTreeMap<String, usize>> = crate::from_str(json).unwrap();
+    for capacity in [1, 2, 3, 7, 64] {
+        let reader = std::io::BufReader::with_capacity(capacity, json.as_bytes());
+        let elements: Vec<BTreeMap<String, usize>> =
+            iter_array_from_reader(reader).map(Result::unwrap).collect();
+        assert_eq!(elements, expected, "{}", capacity);
+    }
+
+    let reader = std::io::BufReader::with_capacity(2, "[\"1\",\"22\" ,\"3\\\"\"]".as_bytes());
+    let elements: Vec<String> = iter_array_from_reader(reader).map(Result::unwrap).collect();
+    assert_eq!(elements, ["1", "22", "3\""]);
+
+    let mut iter = iter_array_from_reader::<usize, _>(&b"[1, 2]\n"[..]);
+    assert_eq!(iter.next().unwrap().unwrap(), 1);
+    assert_eq!(iter.index(), 1);
+    assert_eq!(iter.next().unwrap().unwrap(), 2);
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+
+    assert_eq!(iter_array_from_reader::<usize, _>(&b"[]"[..]).count(), 0);
+
+    // Nothing but the current element is kept, so long runs of input are read through
+    let reader = std::io::Read::chain(
+        &b"["[..],
+        std::io::Read::take(std::io::repeat(b' '), 1 << 24),
+    );
+    let reader = std::io::BufReader::new(std::io::Read::chain(reader, &b"7]"[..]));
+    let elements: Vec<usize> = iter_array_from_reader(reader).map(Result::unwrap).collect();
+    assert_eq!(elements, [7]);
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn iter_array_from_reader_errors() {
+    let json = "[\n  {\"port\": 1},\n  {\"port\": 2},\n  {\"port\": -3}\n]";
+    for capacity in [1, 5, 64] {
+        let reader = std::io::BufReader::with_capacity(capacity, json.as_bytes());
+        let results: Vec<_> =
+            iter_array_from_reader::<BTreeMap<String, usize>, _>(reader).collect();
+        assert_eq!(results.len(), 3);
+        let error = results[2].as_ref().unwrap_err();
+        assert_eq!(
+            error.path().elements().cloned().collect::<Vec<_>>(),
+            [PathElement::Index(2), PathElement::Name("port".into())]
+        );
+        let position = error.position().unwrap();
+        assert_eq!((position.line(), position.column()), (4, 12));
+        assert_eq!(error.offset(), Some(json.find("-3").unwrap()));
+    }
+
+    let error = iter_array_from_reader::<usize, _>(&b"[1,\n 2 3]"[..])
+        .nth(2)
+        .unwrap()
+        .unwrap_err();
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (2, 4));
+    assert_eq!(error.offset(), Some(7));
+
+    for json in ["", "{}", "[1 2]", "[1,]", "[1] 2", "[1", "[\"a"] {
+        assert!(
+            iter_array_from_reader::<usize, _>(json.as_bytes()).any(|result| result.is_err()),
+            "{}",
+            json
+        );
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn iter_object_from_reader_entries() {
+    let json = r#"{"a": 1, "b\n" :22 , "c": 3}"#;
+    for capacity in [1, 3, 64] {
+        let reader = std::io::BufReader::with_capacity(capacity, json.as_bytes());
+        let entries: Vec<(String, usize)> = iter_object_from_reader(reader)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            entries,
+            [("a".into(), 1), ("b\n".into(), 22), ("c".into(), 3)]
+        );
+    }
+    assert_eq!(iter_object_from_reader::<usize, _>(&b" {} "[..]).count(), 0);
+
+    let error = iter_object_from_reader::<usize, _>(&br#"{"a": 1, "b": true}"#[..])
+        .nth(1)
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(
+        error.path().elements().cloned().collect::<Vec<_>>(),
+        [PathElement::Name("b".into())]
+    );
+    assert_eq!(error.offset(), Some(14));
+
+    for json in ["[]", "{\"a\" 1}", "{\"a\": 1,}", "{\"a\": 1} {}", "{1: 2}"] {
+        assert!(
+            iter_object_from_reader::<usize, _>(json.as_bytes()).any(|result| result.is_err()),
+            "{}",
+            json
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{LazyDocument, Value};
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, vec::Vec};
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+#[test]
+fn lazy_navigate() {
+    let json = r#" {"name": "plain", "escaped": "a\nb", "items": [1, -2.5e3, true, null, {}],
+        "nested": {"ab": {"deep": [[], [false]]}}, "name": "second"} "#;
+    let document = LazyDocument::new(json).unwrap();
+    let root = document.root();
+
+    assert!(root.is_object());
+    assert!(matches!(
+        root.get("name").unwrap().as_str(),
+        Some(Cow::Borrowed("plain"))
+    ));
+    assert!(
+        matches!(root.get("escaped").unwrap().as_str(), Some(Cow::Owned(string)) if string == "a\nb")
+    );
+    assert!(root.get("missing").is_none());
+    assert!(root.index(0).is_none());
+
+    let items = root.get("items").unwrap();
+    assert!(items.is_array());
+    assert_eq!(items.index(0).unwrap().as_f64(), Some(1.0));
+    assert_eq!(items.index(1).unwrap().as_f64(), Some(-2500.0));
+    assert_eq!(items.index(2).unwrap().as_bool(), Some(true));
+    assert!(items.index(3).unwrap().is_null());
+    assert!(items.index(4).unwrap().is_object());
+    assert!(items.index(5).is_none());
+    assert_eq!(items.index(0).unwrap().as_str(), None);
+
+    let deep = root
+        .get("nested")
+        .unwrap()
+        .get("ab")
+        .unwrap()
+        .get("deep")
+        .unwrap();
+    assert!(deep.index(0).unwrap().index(0).is_none());
+    assert_eq!(
+        deep.index(1).unwrap().index(0).unwrap().as_bool(),
+        Some(false)
+    );
+    assert_eq!(deep.raw(), b"[[], [false]]");
+
+    let values: Vec<Value> = items.deserialize().unwrap();
+    assert_eq!(values.len(), 5);
+}
+
+#[test]
+fn lazy_scalar_root() {
+    let document = LazyDocument::new(" 12.5 ").unwrap();
+    assert_eq!(document.root().as_f64(), Some(12.5));
+
+    let document = LazyDocument::from_bytes(b"\"text\"").unwrap();
+    assert_eq!(document.root().as_str().unwrap(), "text");
+}
+
+#[test]
+fn lazy_errors() {
+    for (json, line, column) in [
+        ("", 1, 1),
+        ("[1, 2", 1, 6),
+        ("[1, 2}", 1, 6),
+        ("{\"a\" 1}", 1, 6),
+        ("{\"a\": 1,}", 1, 9),
+        ("[1]\n x", 2, 2),
+        ("[01]", 1, 3),
+        ("[-]", 1, 3),
+        ("[tru]", 1, 5),
+        ("[\"a\\x\"]", 1, 5),
+        ("[\"a\nb\"]", 1, 4),
+        ("[\"abc", 1, 6),
+    ] {
+        let error = LazyDocument::new(json).unwrap_err();
+        let position = error.position().unwrap();
+        assert_eq!(
+            (position.line(), position.column()),
+            (line, column),
+            "{}",
+            json
+        );
+        assert!(error.offset().is_some(), "{}", json);
+    }
+
+    let error = LazyDocument::from_bytes(b"[\"a\xFF\"]").unwrap_err();
+    assert_eq!(error.offset(), Some(3));
+
+    let document = LazyDocument::new("{\"a\": [1, \"x\"]}").unwrap();
+    let error = document
+        .root()
+        .get("a")
+        .unwrap()
+        .deserialize::<Vec<usize>>()
+        .unwrap_err();
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (1, 11));
+    assert_eq!(error.offset(), Some(10));
+}
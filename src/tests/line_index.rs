@@ -0,0 +1,106 @@
+use crate::LineIndex;
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use lct_streams::Position;
+
+#[test]
+fn line_index_offsets() {
+    let input = "{\n  \"é\": 1,\r\n  \"b\": 2\n}";
+    let index = LineIndex::new(input);
+    assert_eq!(index.line_count(), 4);
+
+    for offset in (0..=input.len()).filter(|offset| input.is_char_boundary(*offset)) {
+        let position = index.position(offset).unwrap();
+        assert_eq!(index.offset(position), Some(offset), "{}", position);
+    }
+
+    assert_eq!(index.position(0), Some(Position::new(1, 1)));
+    assert_eq!(index.position(5), Some(Position::new(2, 4)));
+    assert_eq!(index.position(6), None);
+    assert_eq!(index.position(input.len()), Some(Position::new(4, 2)));
+    assert_eq!(index.position(input.len() + 1), None);
+
+    assert_eq!(index.offset(Position::new(2, 11)), Some(13));
+    assert_eq!(index.offset(Position::new(2, 12)), None);
+    assert_eq!(index.offset(Position::new(5, 1)), None);
+    assert_eq!(index.offset(Position::new(1, 0)), None);
+}
+
+#[test]
+fn line_index_utf16() {
+    let index = LineIndex::new("\"a😀b\"\n\"😀\"");
+
+    assert_eq!(index.utf16_column(Position::new(1, 3)), Some(3));
+    assert_eq!(index.utf16_column(Position::new(1, 4)), Some(5));
+    assert_eq!(index.utf16_column(Position::new(2, 4)), Some(5));
+    assert_eq!(index.utf16_column(Position::new(2, 5)), None);
+
+    assert_eq!(index.from_utf16(1, 5), Some(Position::new(1, 4)));
+    assert_eq!(index.from_utf16(1, 4), None);
+    assert_eq!(index.from_utf16(1, 7), Some(Position::new(1, 6)));
+    assert_eq!(index.from_utf16(1, 8), None);
+    assert_eq!(index.from_utf16(3, 1), None);
+}
+
+#[test]
+fn line_index_error_offset() {
+    let input = "{\n  \"é\": [1, 2,, 3]\n}";
+    let error = crate::from_str::<crate::Value>(input).unwrap_err();
+    assert_eq!(error.offset(), Some(input.find(",,").unwrap() + 1));
+
+    let index = LineIndex::new(input);
+    let position = error.position().unwrap();
+    assert_eq!(index.offset(position), error.offset());
+    assert_eq!(index.utf16_column(position), Some(position.column()));
+
+    let recovered = crate::from_str_recovering("[1, ?, 2 3]", 10);
+    let offsets: Vec<_> = recovered.errors().iter().map(|e| e.offset()).collect();
+    assert_eq!(offsets, [Some(4), Some(9)]);
+}
+
+/// Rejects a list once all of its elements have been read
+struct NoList;
+
+impl<'de> data_format::Deserialize<'de> for NoList {
+    fn deserialize<D: data_format::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_list(NoList)
+    }
+}
+
+impl<'de> data_format::Converter<'de> for NoList {
+    type Value = NoList;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("no list")
+    }
+
+    fn convert_list<L: data_format::ListDeserializer<'de>>(
+        self,
+        mut list: L,
+    ) -> Result<Self::Value, L::Error> {
+        while list.next_item::<usize>()?.is_some() {}
+        Err(data_format::DeserializeError::custom(
+            "lists are not allowed",
+        ))
+    }
+}
+
+#[test]
+fn line_index_error_offset_behind_stream() {
+    fn check<'de, T: data_format::Deserialize<'de>>(input: &'de str) {
+        let error = crate::from_str::<T>(input).err().unwrap();
+        let index = LineIndex::new(input);
+        assert!(error.offset().is_some(), "{}", input);
+        assert_eq!(
+            error.offset(),
+            index.offset(error.position().unwrap()),
+            "{}",
+            input
+        );
+    }
+
+    check::<Vec<usize>>("[\"é\", \"ü\", 1.5]");
+    check::<Vec<usize>>("[1, 2, \"ü\u{1F600}\"]");
+    check::<NoList>("[1,\n 2,\n 3]");
+    check::<Vec<NoList>>("\n[\n  [1,\r\n 2,\n\n 3]]");
+}
@@ -0,0 +1,117 @@
+use crate::JsonLinesWriter;
+#[cfg(feature = "no_std")]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use data_format::{ListSerializer, Serialize, SerializeError, Serializer};
+
+#[test]
+fn lines_writer() {
+    let records = ["plain", "two\nlines", "crlf\r\n", "\u{2028}"];
+
+    #[cfg(not(feature = "no_std"))]
+    let output = {
+        let mut writer = JsonLinesWriter::new(Vec::new());
+        for record in records {
+            writer.write(record).unwrap();
+        }
+        writer.write(&[1, 2][..]).unwrap();
+        writer.flush().unwrap();
+        String::from_utf8(writer.into_inner()).unwrap()
+    };
+    #[cfg(feature = "no_std")]
+    let output = {
+        let mut writer = JsonLinesWriter::new(String::new());
+        for record in records {
+            writer.write(record).unwrap();
+        }
+        writer.write(&[1, 2][..]).unwrap();
+        writer.into_inner()
+    };
+
+    assert_eq!(
+        output,
+        "\"plain\"\n\"two\\nlines\"\n\"crlf\\r\\n\"\n\"\u{2028}\"\n[1,2]\n"
+    );
+
+    let lines: Vec<String> = output
+        .lines()
+        .take(records.len())
+        .map(|line| crate::from_str::<String>(line).unwrap().to_string())
+        .collect();
+    assert_eq!(lines, records);
+}
+
+/// A record which fails to serialize after writing part of itself
+struct Failing;
+
+impl Serialize for Failing {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut list = serializer.serialize_list(Some(2))?;
+        list.serialize_item(&1)?;
+        Err(S::Error::custom("failed"))
+    }
+}
+
+#[test]
+fn lines_writer_error() {
+    #[cfg(not(feature = "no_std"))]
+    let output = {
+        let mut writer = JsonLinesWriter::new(Vec::new());
+        writer.write(&[1][..]).unwrap();
+        assert!(writer.write(&Failing).is_err());
+        writer.write(&[2][..]).unwrap();
+        String::from_utf8(writer.into_inner()).unwrap()
+    };
+    #[cfg(feature = "no_std")]
+    let output = {
+        let mut writer = JsonLinesWriter::new(String::new());
+        writer.write(&[1][..]).unwrap();
+        assert!(writer.write(&Failing).is_err());
+        writer.write(&[2][..]).unwrap();
+        writer.into_inner()
+    };
+
+    assert_eq!(output, "[1]\n[2]\n");
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn lines_reader() {
+    use crate::JsonLinesReader;
+    use std::collections::BTreeMap;
+
+    let input = "{\"a\": 1}\r\n\n  \n[1, 2]\n{\"a\": }\n3";
+
+    let mut reader = JsonLinesReader::<_, BTreeMap<String, usize>>::new(input.as_bytes());
+    assert!(reader.next().unwrap().is_ok());
+    assert_eq!(reader.line(), 1);
+    assert!(reader.next().unwrap().is_err());
+    assert_eq!(reader.line(), 2);
+
+    let mut reader = JsonLinesReader::<_, Vec<usize>>::new(input.as_bytes());
+    reader.set_skip_blank_lines(true);
+    let error = reader.next().unwrap().unwrap_err();
+    assert_eq!(reader.line(), 1);
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (1, 1));
+
+    assert_eq!(reader.next().unwrap().unwrap(), [1, 2]);
+    assert_eq!(reader.line(), 4);
+
+    let error = reader.next().unwrap().unwrap_err();
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (5, 1));
+    assert_eq!(error.offset(), Some(input.find("{\"a\": }").unwrap()));
+
+    assert!(reader.next().unwrap().is_err());
+    assert!(reader.next().is_none());
+
+    let mut reader = JsonLinesReader::<_, Vec<usize>>::new(input.as_bytes());
+    reader.set_skip_blank_lines(true);
+    reader.set_skip_invalid(true);
+    let records: Vec<_> = reader.by_ref().map(Result::unwrap).collect();
+    assert_eq!(records, [[1, 2]]);
+    assert_eq!(reader.line(), 6);
+}
@@ -0,0 +1,67 @@
+use crate::Value;
+
+/// The examples from RFC 7386 Appendix A as (target, patch, result)
+const EXAMPLES: &[(&str, &str, &str)] = &[
+    (r#"{"a":"b"}"#, r#"{"a":"c"}"#, r#"{"a":"c"}"#),
+    (r#"{"a":"b"}"#, r#"{"b":"c"}"#, r#"{"a":"b","b":"c"}"#),
+    (r#"{"a":"b"}"#, r#"{"a":null}"#, r#"{}"#),
+    (r#"{"a":"b","b":"c"}"#, r#"{"a":null}"#, r#"{"b":"c"}"#),
+    (r#"{"a":["b"]}"#, r#"{"a":"c"}"#, r#"{"a":"c"}"#),
+    (r#"{"a":"c"}"#, r#"{"a":["b"]}"#, r#"{"a":["b"]}"#),
+    (
+        r#"{"a":{"b":"c"}}"#,
+        r#"{"a":{"b":"d","c":null}}"#,
+        r#"{"a":{"b":"d"}}"#,
+    ),
+    (r#"{"a":[{"b":"c"}]}"#, r#"{"a":[1]}"#, r#"{"a":[1]}"#),
+    (r#"["a","b"]"#, r#"["c","d"]"#, r#"["c","d"]"#),
+    (r#"{"a":"b"}"#, r#"["c"]"#, r#"["c"]"#),
+    (r#"{"a":"foo"}"#, r#"null"#, r#"null"#),
+    (r#"{"a":"foo"}"#, r#""bar""#, r#""bar""#),
+    (r#"{"e":null}"#, r#"{"a":1}"#, r#"{"e":null,"a":1}"#),
+    (r#"[1,2]"#, r#"{"a":"b","c":null}"#, r#"{"a":"b"}"#),
+    (
+        r#"{}"#,
+        r#"{"a":{"bb":{"ccc":null}}}"#,
+        r#"{"a":{"bb":{}}}"#,
+    ),
+];
+
+#[test]
+fn merge_patch_rfc_examples() {
+    for (target, patch, result) in EXAMPLES {
+        let mut target: Value = crate::from_str(target).unwrap();
+        let patch: Value = crate::from_str(patch).unwrap();
+        let result: Value = crate::from_str(result).unwrap();
+
+        target.merge_patch(&patch);
+        assert_eq!(target, result);
+    }
+}
+
+#[test]
+fn merge_diff_round_trip() {
+    for (target, _, result) in EXAMPLES {
+        let mut target: Value = crate::from_str(target).unwrap();
+        let result: Value = crate::from_str(result).unwrap();
+
+        let patch = Value::merge_diff(&target, &result);
+        target.merge_patch(&patch);
+        assert_eq!(target, result);
+    }
+}
+
+#[test]
+fn merge_diff_minimal() {
+    let old: Value = crate::from_str(r#"{"a":{"b":1,"c":[1]},"d":true,"e":"x"}"#).unwrap();
+    let new: Value = crate::from_str(r#"{"a":{"b":2,"c":[1]},"d":true,"f":"y"}"#).unwrap();
+
+    assert_eq!(
+        Value::merge_diff(&old, &new),
+        crate::from_str(r#"{"a":{"b":2},"e":null,"f":"y"}"#).unwrap()
+    );
+    assert_eq!(
+        Value::merge_diff(&old, &old),
+        Value::Object(Default::default())
+    );
+}
@@ -0,0 +1,79 @@
+use crate::{from_file_mmap, DeserializeErrorKind, Spanned, SpannedValue, Value};
+use lct_streams::Position;
+use std::{borrow::Cow, fs, path::PathBuf};
+
+/// Writes `contents` to a file in the temporary directory, returning its path
+fn temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("json-mmap-{}-{}", std::process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn mmap_borrowed() {
+    let path = temp_file("borrowed", "{\"name\": \"plain\", \"escaped\": \"a\\nb\"}");
+    let mapped = unsafe { from_file_mmap::<Value>(&path) }.unwrap();
+
+    let object = match mapped.get() {
+        Value::Object(object) => object,
+        value => panic!("expected an object, found {:?}", value),
+    };
+    assert!(matches!(
+        &object["name"],
+        Value::String(Cow::Borrowed("plain"))
+    ));
+    assert!(matches!(&object["escaped"], Value::String(Cow::Owned(string)) if string == "a\nb"));
+    assert_eq!(
+        mapped.bytes().len(),
+        fs::metadata(&path).unwrap().len() as usize
+    );
+
+    let string = unsafe { from_file_mmap::<Cow<str>>(&path) };
+    drop(mapped);
+    fs::remove_file(&path).unwrap();
+
+    let position = string.unwrap_err().position().unwrap();
+    assert_eq!((position.line(), position.column()), (1, 1));
+}
+
+#[test]
+fn mmap_errors() {
+    let path = temp_file("errors", "[1,\n 2,]");
+    let error = unsafe { from_file_mmap::<Value>(&path) }.unwrap_err();
+    fs::remove_file(&path).unwrap();
+
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (2, 4));
+    assert_eq!(error.offset(), Some(7));
+
+    let error = unsafe { from_file_mmap::<Value>(&path) }.unwrap_err();
+    assert!(matches!(error.kind(), DeserializeErrorKind::Io(_)));
+}
+
+#[test]
+fn mmap_borrowed_covariant() {
+    // These fail to compile if a `Mappable` implementation in the crate stops being covariant
+    fn value<'a>(value: Value<'static>) -> Value<'a> {
+        value
+    }
+
+    fn spanned<'a>(value: Spanned<SpannedValue<'static>>) -> Spanned<SpannedValue<'a>> {
+        value
+    }
+
+    fn string<'a>(value: Cow<'static, str>) -> Cow<'a, str> {
+        value
+    }
+
+    assert!(matches!(value(Value::Null), Value::Null));
+    assert!(matches!(
+        spanned(Spanned::new(
+            SpannedValue::Null,
+            Position::new(1, 1),
+            Position::new(1, 5)
+        ))
+        .into_inner(),
+        SpannedValue::Null
+    ));
+    assert_eq!(string(Cow::Borrowed("plain")), "plain");
+}
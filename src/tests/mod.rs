@@ -3,12 +3,39 @@
 use core::fmt::Debug;
 use data_format::{Deserialize, Serialize};
 
+#[cfg(all(feature = "futures-io", not(feature = "no_std")))]
+mod async_io;
 mod bool;
+mod diff;
+mod duplicate_keys;
+mod error_category;
+mod error_path;
+mod error_span;
+mod float;
+mod iter;
+mod lazy;
+mod line_index;
+mod lines;
 mod list;
 mod map;
+mod merge;
+#[cfg(all(feature = "mmap", not(feature = "no_std")))]
+mod mmap;
 mod null;
 mod number;
+mod patch;
+mod path;
+mod pointer;
+mod push;
+mod reader;
+mod recover;
+mod scan;
+mod snippet;
+mod spanned;
+mod stream;
 mod string;
+mod suggestion;
+mod writer;
 
 /// Runs a test which should always be successful
 pub fn run_success_test<'a, T>(value: &T, expected: &'static str)
@@ -26,3 +53,26 @@ where
 pub fn run_failed_test<'a, T: Deserialize<'a>>(json: &'static str) {
     assert!(crate::from_str::<T>(json).is_err())
 }
+
+/// A small xorshift generator for tests over random inputs, seeded so failures can be reproduced
+pub struct Random(u64);
+
+impl Random {
+    /// Creates a new [`Random`] from a non-zero `seed`
+    pub fn new(seed: u64) -> Self {
+        Random(seed)
+    }
+
+    /// Gets the next random number
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Gets a random number below `bound`
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
@@ -37,3 +37,40 @@ fn number_float_positive() {
 fn number_float_negative() {
     super::run_success_test(&-1.23, "-1.23")
 }
+
+#[test]
+fn number_int_out_of_range() {
+    use crate::{from_str, DeserializeError, DeserializeErrorKind};
+
+    assert_eq!(from_str::<u64>("18446744073709551615").unwrap(), u64::MAX);
+    assert_eq!(from_str::<i64>("9223372036854775807").unwrap(), i64::MAX);
+    assert_eq!(from_str::<i64>("-9223372036854775808").unwrap(), i64::MIN);
+    assert_eq!(
+        from_str::<f64>("18446744073709551616").unwrap(),
+        18446744073709551616.0
+    );
+
+    let check = |json: &str, error: DeserializeError| {
+        assert!(
+            matches!(error.kind(), DeserializeErrorKind::InvalidValue { .. }),
+            "{}: {}",
+            json,
+            error
+        );
+
+        let (start, end) = error.span().unwrap();
+        assert_eq!((start.line(), start.column()), (1, 1));
+        assert_eq!((end.line(), end.column()), (1, json.len() + 1));
+    };
+
+    for json in ["18446744073709551616", "99999999999999999999999"] {
+        check(json, from_str::<u64>(json).unwrap_err());
+    }
+    for json in [
+        "9223372036854775808",
+        "-9223372036854775809",
+        "-99999999999999999999999",
+    ] {
+        check(json, from_str::<i64>(json).unwrap_err());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{Patch, PatchErrorKind, PointerError, Value};
+#[cfg(feature = "no_std")]
+use alloc::{string::ToString, vec::Vec};
+
+/// The general tests from the JSON Patch test suite (github.com/json-patch/json-patch-tests)
+const TESTS: &str = include_str!("json_patch/tests.json");
+
+/// The RFC 6902 Appendix A examples from the JSON Patch test suite
+const SPEC_TESTS: &str = include_str!("json_patch/spec_tests.json");
+
+/// Gets the comment of a test in the suite for use in assertion messages
+fn comment<'a>(test: &'a Value) -> &'a str {
+    match test.pointer("/comment") {
+        Some(Value::String(comment)) => comment,
+        _ => "",
+    }
+}
+
+/// Runs every test in `suite` which is not disabled, returning how many were run
+fn run_suite(suite: &str) -> usize {
+    let tests: Vec<Value> = crate::from_str(suite).unwrap();
+
+    let mut count = 0;
+    for (index, test) in tests.iter().enumerate() {
+        if test.pointer("/disabled") == Some(&Value::Boolean(true)) {
+            continue;
+        }
+        count += 1;
+
+        let original = test.pointer("/doc").unwrap().clone();
+        let patch = crate::to_str(test.pointer("/patch").unwrap()).unwrap();
+
+        let mut document = original.clone();
+        let result = crate::from_str::<Patch>(&patch)
+            .map_err(|_| ())
+            .and_then(|patch| document.apply_patch(&patch).map_err(|_| ()));
+
+        if test.pointer("/error").is_some() {
+            assert!(
+                result.is_err(),
+                "#{} \"{}\" should fail",
+                index,
+                comment(test)
+            );
+            assert_eq!(document, original, "#{} \"{}\"", index, comment(test));
+        } else {
+            assert!(
+                result.is_ok(),
+                "#{} \"{}\" should succeed",
+                index,
+                comment(test)
+            );
+            if let Some(expected) = test.pointer("/expected") {
+                assert_eq!(&document, expected, "#{} \"{}\"", index, comment(test));
+            }
+        }
+    }
+    count
+}
+
+#[test]
+fn patch_suite_tests() {
+    assert_eq!(run_suite(TESTS), 90);
+}
+
+#[test]
+fn patch_suite_spec_tests() {
+    assert_eq!(run_suite(SPEC_TESTS), 16);
+}
+
+#[test]
+fn patch_duplicate_op() {
+    assert!(crate::from_str::<Patch>(
+        r#"[{ "op": "add", "path": "/baz", "value": "qux", "op": "remove" }]"#
+    )
+    .is_err());
+}
+
+#[test]
+fn patch_error_location() {
+    let patch: Patch = crate::from_str(
+        r#"[
+            { "op": "add", "path": "/a", "value": 1 },
+            { "op": "move", "from": "/missing", "path": "/b" }
+        ]"#,
+    )
+    .unwrap();
+
+    let mut document = Value::Object(Default::default());
+    let error = document.apply_patch(&patch).unwrap_err();
+
+    assert_eq!(error.index(), 1);
+    assert_eq!(error.op(), "move");
+    assert_eq!(error.pointer().to_string(), "/missing");
+    assert_eq!(
+        error.kind(),
+        &PatchErrorKind::Pointer(PointerError::NotFound("missing".into()))
+    );
+    assert_eq!(document, Value::Object(Default::default()));
+}
+
+#[test]
+fn patch_round_trip() {
+    const PATCH: &str = r#"[{"op":"add","path":"/a~1b","value":[1,null]},{"op":"remove","path":"/c"},{"op":"move","from":"/d","path":"/e"},{"op":"test","path":"","value":{}}]"#;
+
+    let patch: Patch = crate::from_str(PATCH).unwrap();
+    assert_eq!(patch.len(), 4);
+
+    let json = crate::to_str(&patch).unwrap();
+    assert_eq!(crate::from_str::<Patch>(&json).unwrap(), patch);
+}
@@ -0,0 +1,227 @@
+use crate::{JsonPath, PathElement, PathErrorKind, Value};
+#[cfg(feature = "no_std")]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The example document from RFC 9535 section 1.5
+const STORE: &str = r#"{ "store": {
+    "book": [
+        { "category": "reference", "author": "Nigel Rees", "title": "Sayings of the Century", "price": 8.95 },
+        { "category": "fiction", "author": "Evelyn Waugh", "title": "Sword of Honour", "price": 12.99 },
+        { "category": "fiction", "author": "Herman Melville", "title": "Moby Dick", "isbn": "0-553-21311-3", "price": 8.99 },
+        { "category": "fiction", "author": "J. R. R. Tolkien", "title": "The Lord of the Rings", "isbn": "0-395-19395-8", "price": 22.99 }
+    ],
+    "bicycle": { "color": "red", "price": 399 }
+} }"#;
+
+/// Runs `query` against `json`, returning the normalized paths of the selected nodes
+fn paths(json: &str, query: &str) -> Vec<String> {
+    let value: Value = crate::from_str(json).unwrap();
+    value
+        .query(query)
+        .unwrap()
+        .iter()
+        .map(|node| node.path().to_string())
+        .collect()
+}
+
+/// Runs `query` against `json`, returning the selected values serialized
+fn values(json: &str, query: &str) -> Vec<String> {
+    let value: Value = crate::from_str(json).unwrap();
+    value
+        .query(query)
+        .unwrap()
+        .iter()
+        .map(|node| crate::to_str(node.value()).unwrap())
+        .collect()
+}
+
+#[test]
+fn path_child_selectors() {
+    assert_eq!(
+        values(STORE, "$.store.book[*].author"),
+        [
+            "\"Nigel Rees\"",
+            "\"Evelyn Waugh\"",
+            "\"Herman Melville\"",
+            "\"J. R. R. Tolkien\""
+        ]
+    );
+    assert_eq!(values(STORE, "$['store'][\"bicycle\"].color"), ["\"red\""]);
+    assert_eq!(paths(STORE, "$.store.book[-1]"), ["$['store']['book'][3]"]);
+    assert_eq!(paths(STORE, "$.store.book[4]"), Vec::<String>::new());
+    assert_eq!(paths(STORE, "$.store.missing.book"), Vec::<String>::new());
+}
+
+#[test]
+fn path_descendant_selectors() {
+    assert_eq!(values(STORE, "$..author").len(), 4);
+    assert_eq!(values(STORE, "$.store..price").len(), 5);
+    assert_eq!(paths(STORE, "$..book[2]"), ["$['store']['book'][2]"]);
+    assert_eq!(
+        paths(r#"{"a":[{"a":1}],"b":{"a":2}}"#, "$..a"),
+        ["$['a']", "$['a'][0]['a']", "$['b']['a']"]
+    );
+    assert_eq!(
+        paths(r#"[[1],[2]]"#, "$..[0]"),
+        ["$[0]", "$[0][0]", "$[1][0]"]
+    );
+}
+
+#[test]
+fn path_slices_and_unions() {
+    let json = "[0,1,2,3,4,5,6]";
+    assert_eq!(values(json, "$[1:3]"), ["1", "2"]);
+    assert_eq!(values(json, "$[5:]"), ["5", "6"]);
+    assert_eq!(values(json, "$[:2]"), ["0", "1"]);
+    assert_eq!(values(json, "$[1:5:2]"), ["1", "3"]);
+    assert_eq!(values(json, "$[5:1:-2]"), ["5", "3"]);
+    assert_eq!(values(json, "$[::-1]"), ["6", "5", "4", "3", "2", "1", "0"]);
+    assert_eq!(values(json, "$[-2:]"), ["5", "6"]);
+    assert_eq!(values(json, "$[::0]"), Vec::<String>::new());
+    assert_eq!(values(json, "$[0, 3, 0]"), ["0", "3", "0"]);
+    assert_eq!(values(json, "$[0:2, 5]"), ["0", "1", "5"]);
+}
+
+#[test]
+fn path_filters() {
+    assert_eq!(
+        values(STORE, "$..book[?@.isbn].title"),
+        ["\"Moby Dick\"", "\"The Lord of the Rings\""]
+    );
+    assert_eq!(
+        values(STORE, "$..book[?@.price < 10].title"),
+        ["\"Sayings of the Century\"", "\"Moby Dick\""]
+    );
+    assert_eq!(
+        values(
+            STORE,
+            "$..book[?@.category == 'fiction' && @.price > 20].title"
+        ),
+        ["\"The Lord of the Rings\""]
+    );
+    assert_eq!(
+        values(STORE, "$..book[?!(@.price < 10 || @.isbn)].title"),
+        ["\"Sword of Honour\""]
+    );
+    assert_eq!(
+        values(STORE, "$.store.book[?@.price > $.store.bicycle.price]"),
+        Vec::<String>::new()
+    );
+
+    let json = r#"[1, "1", null, true, {"a": 1}, [1]]"#;
+    assert_eq!(values(json, "$[?@ == 1]"), ["1"]);
+    assert_eq!(values(json, "$[?@ == null]"), ["null"]);
+    assert_eq!(values(json, "$[?@.a == 1]"), ["{\"a\":1}"]);
+    assert_eq!(values(json, "$[?@.missing == @.other]").len(), 6);
+    assert_eq!(values(json, "$[?@ <= true]"), ["true"]);
+    assert_eq!(values(json, "$[?@ > '0']"), ["\"1\""]);
+}
+
+#[test]
+fn path_functions() {
+    assert_eq!(
+        values(STORE, "$..book[?length(@.title) < 10].title"),
+        ["\"Moby Dick\""]
+    );
+    assert_eq!(
+        values(STORE, "$.store[?count(@.*) == 2].color"),
+        ["\"red\""]
+    );
+    assert_eq!(
+        values(STORE, "$..book[?match(@.author, 'J.*')].price"),
+        ["22.99"]
+    );
+    assert_eq!(
+        values(STORE, "$..book[?search(@.title, '[Dd]ick')].price"),
+        ["8.99"]
+    );
+    assert_eq!(
+        values(STORE, "$..book[?value(@..isbn) == '0-553-21311-3'].author"),
+        ["\"Herman Melville\""]
+    );
+
+    let json = r#"["ab", "abc", "a\nb", "", "xaby", 1]"#;
+    assert_eq!(values(json, "$[?match(@, 'ab')]"), ["\"ab\""]);
+    assert_eq!(values(json, "$[?match(@, 'a.c?')]"), ["\"ab\"", "\"abc\""]);
+    assert_eq!(
+        values(json, "$[?search(@, 'ab')]"),
+        ["\"ab\"", "\"abc\"", "\"xaby\""]
+    );
+    assert_eq!(
+        values(json, "$[?match(@, '(a|x)b{1,2}c*')]"),
+        ["\"ab\"", "\"abc\""]
+    );
+    assert_eq!(values(json, "$[?match(@, '[^b]*')]"), ["\"\""]);
+    assert_eq!(values(json, "$[?match(@, 'a(')]").len(), 0);
+    assert_eq!(values(json, "$[?!match(@, '.*')]"), ["\"a\\nb\"", "1"]);
+
+    let json = r#"[{"s": "ab", "p": "a."}, {"s": "ab", "p": "b"}, {"s": "ab", "p": 1}]"#;
+    assert_eq!(values(json, "$[?match(@.s, @.p)].p"), ["\"a.\""]);
+    assert_eq!(values(json, "$[?search(@.s, @.p)].p"), ["\"a.\"", "\"b\""]);
+}
+
+#[test]
+fn path_normalized_paths() {
+    let value: Value = crate::from_str(r#"{"it's":{"a\nb":[1]}}"#).unwrap();
+    let nodes = value.query("$..*").unwrap();
+    assert_eq!(nodes.len(), 3);
+    assert_eq!(nodes[2].path().to_string(), "$['it\\'s']['a\\nb'][0]");
+    assert_eq!(
+        nodes[2].path().elements().last(),
+        Some(&PathElement::Index(0))
+    );
+    assert_eq!(nodes[2].path().to_pointer().to_string(), "/it's/a\nb/0");
+    assert_eq!(nodes[2].value(), &Value::Number(1.0));
+}
+
+#[test]
+fn path_parse_errors() {
+    let errors = [
+        ("", PathErrorKind::UnexpectedEnd),
+        (
+            "store",
+            PathErrorKind::Unexpected {
+                unexpected: 's',
+                expected: "'$'",
+            },
+        ),
+        ("$[01]", PathErrorKind::InvalidInteger),
+        ("$[-0]", PathErrorKind::InvalidInteger),
+        ("$[9007199254740992]", PathErrorKind::InvalidInteger),
+        ("$['\\a']", PathErrorKind::InvalidEscape),
+        (
+            "$[?foo(@)]",
+            PathErrorKind::UnknownFunction("foo".to_string()),
+        ),
+        (
+            "$[?length(@, @)]",
+            PathErrorKind::InvalidArguments("length"),
+        ),
+        (
+            "$[?count(1) == 1]",
+            PathErrorKind::InvalidArguments("count"),
+        ),
+        ("$[?@.* == 1]", PathErrorKind::NonSingularQuery),
+        ("$[?length(@.a)]", PathErrorKind::TypeMismatch),
+        ("$[?match(@, 'a') == true]", PathErrorKind::TypeMismatch),
+    ];
+
+    for (query, kind) in errors {
+        assert_eq!(
+            JsonPath::parse(query).unwrap_err().kind(),
+            &kind,
+            "{}",
+            query
+        );
+    }
+
+    assert_eq!(JsonPath::parse("$.a b").unwrap_err().offset(), 3);
+    assert!(JsonPath::parse("$.a .b [0]").is_ok());
+    assert!(JsonPath::parse("$[?@.a==1 ||@.b]").is_ok());
+    assert!(JsonPath::parse("$[?1]").is_err());
+    assert!(JsonPath::parse("$[?@ == {}]").is_err());
+    assert!(JsonPath::parse("$.1").is_err());
+}
@@ -0,0 +1,142 @@
+use crate::{JsonPointer, PointerError, Value};
+#[cfg(feature = "no_std")]
+use alloc::string::ToString;
+
+const DOCUMENT: &str = r#"{
+    "foo": ["bar", "baz"],
+    "": 0,
+    "a/b": 1,
+    "c%d": 2,
+    "e^f": 3,
+    "g|h": 4,
+    "i\\j": 5,
+    "k\"l": 6,
+    " ": 7,
+    "m~n": 8
+}"#;
+
+#[test]
+fn pointer_rfc_examples() {
+    let document: Value = crate::from_str(DOCUMENT).unwrap();
+
+    assert_eq!(document.pointer(""), Some(&document));
+    assert!(matches!(document.pointer("/foo"), Some(Value::Array(array)) if array.len() == 2));
+    assert_eq!(
+        document.pointer("/foo/0"),
+        Some(&Value::String("bar".into()))
+    );
+    assert_eq!(document.pointer("/"), Some(&Value::Number(0.0)));
+    assert_eq!(document.pointer("/a~1b"), Some(&Value::Number(1.0)));
+    assert_eq!(document.pointer("/c%d"), Some(&Value::Number(2.0)));
+    assert_eq!(document.pointer("/e^f"), Some(&Value::Number(3.0)));
+    assert_eq!(document.pointer("/g|h"), Some(&Value::Number(4.0)));
+    assert_eq!(document.pointer("/i\\j"), Some(&Value::Number(5.0)));
+    assert_eq!(document.pointer("/k\"l"), Some(&Value::Number(6.0)));
+    assert_eq!(document.pointer("/ "), Some(&Value::Number(7.0)));
+    assert_eq!(document.pointer("/m~0n"), Some(&Value::Number(8.0)));
+}
+
+#[test]
+fn pointer_missing() {
+    let document: Value = crate::from_str(DOCUMENT).unwrap();
+
+    assert_eq!(document.pointer("/missing"), None);
+    assert_eq!(document.pointer("/foo/2"), None);
+    assert_eq!(document.pointer("/foo/-"), None);
+    assert_eq!(document.pointer("/foo/01"), None);
+    assert_eq!(document.pointer("foo"), None);
+}
+
+#[test]
+fn pointer_parse_errors() {
+    assert_eq!(
+        JsonPointer::parse("foo"),
+        Err(PointerError::MissingLeadingSlash)
+    );
+    assert_eq!(
+        JsonPointer::parse("/a/b~2"),
+        Err(PointerError::InvalidEscape(4))
+    );
+    assert_eq!(
+        JsonPointer::parse("/a~"),
+        Err(PointerError::InvalidEscape(2))
+    );
+}
+
+#[test]
+fn pointer_display_round_trip() {
+    let mut pointer = JsonPointer::new();
+    pointer.push("servers");
+    pointer.push_index(0);
+    pointer.push("a/b~c");
+
+    assert_eq!(pointer.to_string(), "/servers/0/a~1b~0c");
+    assert_eq!(JsonPointer::parse(&pointer.to_string()), Ok(pointer));
+    assert_eq!(JsonPointer::new().to_string(), "");
+}
+
+#[test]
+fn pointer_mut() {
+    let mut document: Value = crate::from_str(r#"{"servers": [{"port": 80}]}"#).unwrap();
+
+    *document.pointer_mut("/servers/0/port").unwrap() = Value::Number(8080.0);
+    assert_eq!(
+        document.pointer("/servers/0/port"),
+        Some(&Value::Number(8080.0))
+    );
+}
+
+#[test]
+fn pointer_insert() {
+    let mut document: Value = crate::from_str(r#"{"list": [1, 3]}"#).unwrap();
+
+    assert_eq!(
+        document.pointer_insert("/list/1", Value::Number(2.0)),
+        Ok(None)
+    );
+    assert_eq!(
+        document.pointer_insert("/list/-", Value::Number(4.0)),
+        Ok(None)
+    );
+    assert_eq!(document.pointer_insert("/name", Value::Null), Ok(None));
+    assert_eq!(
+        document.pointer_insert("/name", Value::Boolean(true)),
+        Ok(Some(Value::Null))
+    );
+    assert_eq!(
+        document.pointer_insert("/list/5", Value::Null),
+        Err(PointerError::IndexOutOfBounds { index: 5, len: 4 })
+    );
+    assert_eq!(
+        document.pointer_insert("/missing/a", Value::Null),
+        Err(PointerError::NotFound("missing".into()))
+    );
+
+    assert_eq!(
+        document,
+        crate::from_str(r#"{"list": [1, 2, 3, 4], "name": true}"#).unwrap()
+    );
+}
+
+#[test]
+fn pointer_remove() {
+    let mut document: Value = crate::from_str(r#"{"list": [1, 2, 3], "name": null}"#).unwrap();
+
+    assert_eq!(document.pointer_remove("/list/1"), Ok(Value::Number(2.0)));
+    assert_eq!(document.pointer_remove("/name"), Ok(Value::Null));
+    assert_eq!(
+        document.pointer_remove("/name"),
+        Err(PointerError::NotFound("name".into()))
+    );
+    assert_eq!(
+        document.pointer_remove("/list/-"),
+        Err(PointerError::NotFound("-".into()))
+    );
+    assert_eq!(
+        document.pointer_remove("/list/0/a"),
+        Err(PointerError::NotContainer("a".into()))
+    );
+    assert_eq!(document.pointer_remove(""), Err(PointerError::RemoveRoot));
+
+    assert_eq!(document, crate::from_str(r#"{"list": [1, 3]}"#).unwrap());
+}
@@ -0,0 +1,95 @@
+use crate::{PushParser, Spanned, Value};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+/// Parses `chunks` with a [`PushParser`], collecting every value and panicking on an error
+fn parse(chunks: &[&[u8]]) -> Vec<Spanned<Value<'static>>> {
+    let mut parser = PushParser::new();
+    let mut values = Vec::new();
+    for chunk in chunks {
+        parser.feed(chunk);
+        while let Some(value) = parser.next_value().unwrap() {
+            values.push(value);
+        }
+    }
+
+    parser.finish();
+    while let Some(value) = parser.next_value().unwrap() {
+        values.push(value);
+    }
+    assert!(parser.is_finished());
+    values
+}
+
+#[test]
+fn push_split_anywhere() {
+    let stream =
+        "{\"é\": [1, \"\\u00e9\\ud83d\\ude00\"]}\n\"a]\\\"\" [[], {}] 12\n-3.5e2 true\nnull";
+    let bytes = stream.as_bytes();
+
+    let whole = parse(&[bytes]);
+    assert_eq!(whole.len(), 7);
+    assert_eq!(
+        whole[0].value(),
+        &crate::from_str::<Value>("{\"é\": [1, \"é😀\"]}").unwrap()
+    );
+    assert_eq!(whole[4].value(), &Value::Number(-350.0));
+    assert_eq!((whole[4].start().line(), whole[4].start().column()), (3, 1));
+    assert_eq!((whole[6].end().line(), whole[6].end().column()), (4, 5));
+
+    for split in 0..=bytes.len() {
+        assert_eq!(
+            parse(&[&bytes[..split], &bytes[split..]]),
+            whole,
+            "{}",
+            split
+        );
+    }
+
+    let bytes: Vec<&[u8]> = bytes.chunks(1).collect();
+    assert_eq!(parse(&bytes), whole);
+}
+
+#[test]
+fn push_incremental() {
+    let mut parser = PushParser::new();
+    parser.feed(b"[1, 2");
+    assert!(parser.next_value().unwrap().is_none());
+    parser.feed(b"] 3");
+    assert_eq!(
+        parser.next_value().unwrap().unwrap().into_inner(),
+        Value::Array([Value::Number(1.0), Value::Number(2.0)].into())
+    );
+    assert!(parser.next_value().unwrap().is_none());
+    parser.feed(b"4 ");
+    assert_eq!(
+        parser.next_value().unwrap().unwrap().into_inner(),
+        Value::Number(34.0)
+    );
+    assert!(!parser.is_finished());
+    parser.finish();
+    assert!(parser.next_value().unwrap().is_none());
+    assert!(parser.is_finished());
+}
+
+#[test]
+fn push_errors() {
+    let mut parser = PushParser::new();
+    parser.feed(b"{\"a\": 1}\n  [1,");
+    parser.feed(b" 2,] \"ok\"");
+    parser.next_value().unwrap();
+
+    let error = parser.next_value().unwrap_err();
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (2, 9));
+    assert_eq!(error.offset(), Some(17));
+    assert_eq!(
+        parser.next_value().unwrap().unwrap().into_inner(),
+        Value::String("ok".into())
+    );
+
+    parser.feed(b" {\"b\": [");
+    parser.finish();
+    let error = parser.next_value().unwrap_err();
+    assert_eq!(error.classify(), crate::ErrorCategory::Eof);
+}
@@ -0,0 +1,154 @@
+use crate::{Event, Reader};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+/// Reads every event from `json`, panicking on an error
+fn events(json: &str) -> Vec<Event<'_>> {
+    Reader::new(json)
+        .map(|event| event.unwrap().into_inner())
+        .collect()
+}
+
+#[test]
+fn reader_events() {
+    assert_eq!(
+        events(r#" {"a": [1, -2.5e3, "x\n"], "b": {}, "c": [], "d": [true, false, null]} "#),
+        [
+            Event::StartObject,
+            Event::Key("a".into()),
+            Event::StartArray,
+            Event::Number("1"),
+            Event::Number("-2.5e3"),
+            Event::String("x\n".into()),
+            Event::EndArray,
+            Event::Key("b".into()),
+            Event::StartObject,
+            Event::EndObject,
+            Event::Key("c".into()),
+            Event::StartArray,
+            Event::EndArray,
+            Event::Key("d".into()),
+            Event::StartArray,
+            Event::Bool(true),
+            Event::Bool(false),
+            Event::Null,
+            Event::EndArray,
+            Event::EndObject,
+        ]
+    );
+    assert_eq!(events("\"top\""), [Event::String("top".into())]);
+}
+
+#[test]
+fn reader_positions() {
+    let mut reader = Reader::new("{\n  \"key\": [12]\n}");
+    let spans: Vec<_> = core::iter::from_fn(|| reader.next_event().unwrap())
+        .map(|event| {
+            (
+                (event.start().line(), event.start().column()),
+                (event.end().line(), event.end().column()),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        spans,
+        [
+            ((1, 1), (1, 2)),
+            ((2, 3), (2, 8)),
+            ((2, 10), (2, 11)),
+            ((2, 11), (2, 13)),
+            ((2, 13), (2, 14)),
+            ((3, 1), (3, 2)),
+        ]
+    );
+    assert_eq!(reader.depth(), 0);
+}
+
+#[test]
+fn reader_skip_value() {
+    let mut reader = Reader::new(r#"{"big": {"a": [1, {"b": 2}]}, "small": 1, "last": [3]}"#);
+    assert_eq!(
+        reader.next_event().unwrap().unwrap().into_inner(),
+        Event::StartObject
+    );
+    assert_eq!(
+        reader.next_event().unwrap().unwrap().into_inner(),
+        Event::Key("big".into())
+    );
+    assert!(reader.skip_value().unwrap());
+    assert!(reader.skip_value().unwrap());
+    assert_eq!(
+        reader.next_event().unwrap().unwrap().into_inner(),
+        Event::Key("last".into())
+    );
+    assert!(reader.skip_value().unwrap());
+    assert!(!reader.skip_value().unwrap());
+    assert_eq!(
+        reader.next_event().unwrap().unwrap().into_inner(),
+        Event::EndObject
+    );
+    assert!(!reader.skip_value().unwrap());
+    assert!(reader.next_event().unwrap().is_none());
+
+    let mut reader = Reader::new("[1, [2, 3], 4]");
+    reader.next_event().unwrap();
+    assert!(reader.skip_value().unwrap());
+    assert!(reader.skip_value().unwrap());
+    assert_eq!(
+        reader.next_event().unwrap().unwrap().into_inner(),
+        Event::Number("4")
+    );
+    assert_eq!(
+        reader.next_event().unwrap().unwrap().into_inner(),
+        Event::EndArray
+    );
+    assert!(reader.next_event().unwrap().is_none());
+}
+
+#[test]
+fn reader_skip_to_end() {
+    for (json, end) in [
+        ("[1, 2]", Event::EndArray),
+        ("[]", Event::EndArray),
+        ("{\"a\": [1], \"b\": {}}", Event::EndObject),
+        ("{}", Event::EndObject),
+    ] {
+        let mut reader = Reader::new(json);
+        reader.next_event().unwrap();
+
+        let mut skipped = 0;
+        while reader.skip_value().unwrap() {
+            skipped += 1;
+        }
+        assert_eq!(skipped, if json.len() > 2 { 2 } else { 0 }, "{}", json);
+
+        let event = reader.next_event().unwrap().unwrap();
+        assert_eq!(event.value(), &end, "{}", json);
+        assert_eq!(event.end().column(), json.len() + 1, "{}", json);
+        assert!(reader.next_event().unwrap().is_none());
+    }
+}
+
+#[test]
+fn reader_errors() {
+    let mut reader = Reader::new("[1 2]");
+    reader.next_event().unwrap();
+    reader.next_event().unwrap();
+    let error = reader.next_event().unwrap_err();
+    assert_eq!(error.offset(), Some(3));
+    assert!(reader.next_event().unwrap().is_none());
+
+    for json in [
+        "",
+        "[",
+        "{\"a\" 1}",
+        "[1,]",
+        "{\"a\": 1,}",
+        "[1] 2",
+        "[}",
+        "{1: 2}",
+    ] {
+        assert!(Reader::new(json).any(|event| event.is_err()), "{}", json);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{DeserializeErrorKind, ErrorCategory, Value};
+#[cfg(feature = "no_std")]
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Parses `json` with recovery, returning the value serialized and the paths of the errors
+fn recover(json: &str) -> (String, Vec<String>) {
+    let recovered = crate::from_str_recovering(json, 100);
+    let value = crate::to_str(recovered.value()).unwrap();
+    let paths = recovered
+        .errors()
+        .iter()
+        .map(|error| error.path().to_string())
+        .collect();
+    (value, paths)
+}
+
+#[test]
+fn recover_valid() {
+    let json = r#"{"a": [1, true, null], "b": "c"}"#;
+    let recovered = crate::from_str_recovering(json, 10);
+    assert!(recovered.is_ok());
+    assert_eq!(recovered.value(), &crate::from_str::<Value>(json).unwrap());
+}
+
+#[test]
+fn recover_multiple_errors() {
+    let json = r#"{
+        "name": "server",
+        "port": 80O,
+        "hosts": ["a" "b", tru, "d"],
+        "tls": {"cert" "x.pem", "key": "y.pem",},
+        "debug": false
+    }"#;
+
+    let (value, paths) = recover(json);
+    assert_eq!(paths, [".", ".hosts", ".hosts[2]", ".tls.cert", ".tls"]);
+    assert_eq!(
+        value,
+        r#"{"debug":false,"hosts":["a","b",null,"d"],"name":"server","port":80,"tls":{"cert":"x.pem","key":"y.pem"}}"#
+    );
+}
+
+#[test]
+fn recover_brackets() {
+    assert_eq!(
+        recover("[1, 2}"),
+        ("[1,2]".to_string(), vec![".".to_string()])
+    );
+    assert_eq!(
+        recover(r#"{"a": [1, 2}, "b": 3}"#),
+        (r#"{"a":[1,2]}"#.to_string(), vec![".a".to_string()])
+    );
+    assert_eq!(
+        recover(r#"{a: 1, "b": 2}"#),
+        (r#"{"b":2}"#.to_string(), vec![".".to_string()])
+    );
+    assert_eq!(
+        recover("[1, @, 3]"),
+        ("[1,null,3]".to_string(), vec!["[1]".to_string()])
+    );
+}
+
+#[test]
+fn recover_end_of_input() {
+    let recovered = crate::from_str_recovering(r#"{"a": [1, 2"#, 100);
+    assert_eq!(recovered.errors().len(), 1);
+    assert_eq!(recovered.errors()[0].classify(), ErrorCategory::Eof);
+    assert_eq!(crate::to_str(recovered.value()).unwrap(), r#"{"a":[1,2]}"#);
+}
+
+#[test]
+fn recover_max_errors() {
+    let recovered = crate::from_str_recovering("[@, @, @, @, @, 1]", 3);
+    let (value, errors) = recovered.into_parts();
+    assert_eq!(errors.len(), 3);
+    assert!(errors
+        .iter()
+        .all(|error| matches!(error.kind(), DeserializeErrorKind::Unexpected { .. })));
+    assert_eq!(crate::to_str(&value).unwrap(), "[null,null,null]");
+}
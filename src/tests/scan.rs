@@ -0,0 +1,142 @@
+use super::Random;
+use crate::{deserialize::scan, LazyDocument, PushParser, Reader, Spanned, Value};
+#[cfg(feature = "no_std")]
+use alloc::{collections::BTreeMap, format, string::String, vec, vec::Vec};
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeMap;
+
+/// The bytes random inputs are made from, weighted towards the ones the scanners stop at
+const ALPHABET: &[u8] = b"  \t\n\r\"\"\\\\[]{}{}\x00\x1F\x7F\x80\xFFaZ09:,";
+
+/// Creates a random input of up to 100 bytes
+fn random_input(random: &mut Random) -> Vec<u8> {
+    let length = random.below(100);
+    (0..length)
+        .map(|_| match random.below(4) {
+            0 => random.below(256) as u8,
+            _ => ALPHABET[random.below(ALPHABET.len() as u64) as usize],
+        })
+        .collect()
+}
+
+#[test]
+fn scan_matches_scalar() {
+    let mut random = Random::new(0x5EED_0049);
+    for _ in 0..5000 {
+        let input = random_input(&mut random);
+        for start in 0..input.len() {
+            let bytes = &input[start..];
+            assert_eq!(
+                scan::whitespace_len(bytes),
+                scan::scalar::whitespace_len(bytes),
+                "{:?}",
+                bytes
+            );
+            assert_eq!(
+                scan::string_len(bytes),
+                scan::scalar::string_len(bytes),
+                "{:?}",
+                bytes
+            );
+            assert_eq!(
+                scan::structural_len(bytes),
+                scan::scalar::structural_len(bytes),
+                "{:?}",
+                bytes
+            );
+        }
+    }
+}
+
+#[test]
+fn scan_long_strings() {
+    let mut random = Random::new(0x5EED_0050);
+    let pieces = [
+        "a",
+        "é",
+        "\\n",
+        "\\\"",
+        "\\u00e9",
+        "\\ud83d\\ude00",
+        " ",
+        "{[]}",
+    ];
+    let decoded = ["a", "é", "\n", "\"", "é", "😀", " ", "{[]}"];
+
+    for _ in 0..500 {
+        let mut content = String::new();
+        let mut expected = String::new();
+        for _ in 0..random.below(60) {
+            let piece = random.below(pieces.len() as u64) as usize;
+            content.push_str(pieces[piece]);
+            expected.push_str(decoded[piece]);
+        }
+
+        let padding = " ".repeat(random.below(40) as usize);
+        let json = format!(
+            "{}[\"{}\",{}{{\"{}\": [\"{}\"]}}]",
+            padding, content, padding, content, content
+        );
+
+        let string = || Value::String(expected.clone().into());
+        let value = Value::Array(vec![
+            string(),
+            Value::Object(BTreeMap::from([(
+                expected.clone().into(),
+                Value::Array(vec![string()]),
+            )])),
+        ]);
+        assert_eq!(crate::from_str::<Value>(&json).unwrap(), value);
+
+        let document = LazyDocument::new(&json).unwrap();
+        let root = document.root();
+        assert_eq!(root.index(0).unwrap().as_str().unwrap(), expected);
+        let object = root.index(1).unwrap();
+        assert_eq!(
+            object
+                .get(&expected)
+                .unwrap()
+                .index(0)
+                .unwrap()
+                .as_str()
+                .unwrap(),
+            expected
+        );
+
+        let mut parser = PushParser::new();
+        for chunk in json.as_bytes().chunks(7) {
+            parser.feed(chunk);
+        }
+        assert_eq!(parser.next_value().unwrap().unwrap().into_inner(), value);
+    }
+}
+
+#[test]
+fn scan_skip_matches_parse() {
+    let mut random = Random::new(0x5EED_0051);
+    let pieces = [
+        "1", "-0.5e3", "10E+2", "01", "1.", "-", "2x", "true", "nul", "null", "false", "\"a\"",
+        "\"\\n\"", ",", ",", ":", " ", "\n", "[", "]", "{", "}", "é", "\\",
+    ];
+
+    for _ in 0..20000 {
+        let mut json = String::from("[");
+        for _ in 0..random.below(12) {
+            json.push_str(pieces[random.below(pieces.len() as u64) as usize]);
+        }
+        json.push(']');
+
+        let mut reader = Reader::new(&json);
+        let skipped = reader.skip_value();
+        match crate::from_str::<Spanned<Value>>(&json) {
+            Ok(value) => {
+                assert!(skipped.unwrap(), "{}", json);
+                assert_eq!(reader.position(), value.end(), "{}", json);
+            }
+            Err(error) => {
+                let skipped = skipped.expect_err(&json);
+                assert_eq!(skipped.position(), error.position(), "{}", json);
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{DeserializeError, DeserializeErrorKind, Value};
+#[cfg(feature = "no_std")]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+const INPUT: &str = "{\n  \"a\": 1,\n  \"b\" 2,\n  \"c\": 3\n}";
+
+/// Finds the line containing the caret, returning it and the line before it
+fn caret_lines(rendered: &str) -> (&str, &str) {
+    let lines: Vec<&str> = rendered.lines().collect();
+    let index = lines.iter().position(|line| line.ends_with('^')).unwrap();
+    (lines[index - 1], lines[index])
+}
+
+#[test]
+fn snippet_plain() {
+    let error = crate::from_str::<Value>(INPUT).unwrap_err();
+    let position = error.position().unwrap();
+    let rendered = error.snippet(INPUT).to_string();
+
+    assert!(rendered.starts_with(&format!("error: {}\n", error.kind())));
+    assert!(rendered.contains(&format!(" --> {}\n", position)));
+    assert!(!rendered.contains('\x1B'));
+
+    let (line, caret) = caret_lines(&rendered);
+    assert_eq!(line, format!("{} |   \"b\" 2,", position.line()));
+    assert_eq!(caret, format!("  | {}^", " ".repeat(position.column() - 1)));
+    assert_eq!(&line[caret.len() - 1..caret.len()], "2");
+
+    assert_eq!(
+        rendered.lines().filter(|line| line.contains(" | ")).count(),
+        6
+    );
+}
+
+#[test]
+fn snippet_context() {
+    let error = crate::from_str::<Value>(INPUT).unwrap_err();
+
+    let mut snippet = error.snippet(INPUT);
+    snippet.set_context(0);
+    let rendered = snippet.to_string();
+    assert_eq!(rendered.lines().count(), 5);
+    assert!(!rendered.contains("\"a\""));
+
+    snippet.set_context(100);
+    let rendered = snippet.to_string();
+    assert!(rendered.contains("1 | {"));
+    assert!(rendered.contains("5 | }"));
+}
+
+#[test]
+fn snippet_color() {
+    let error = crate::from_str::<Value>(INPUT).unwrap_err();
+
+    let mut snippet = error.snippet(INPUT);
+    snippet.set_color(true);
+    let rendered = snippet.to_string();
+    assert!(rendered.starts_with("\x1B[1;31merror\x1B[0m"));
+    assert!(rendered.contains("\x1B[1;31m^\x1B[0m"));
+}
+
+#[test]
+fn snippet_truncated() {
+    let mut input = String::from("[");
+    for _ in 0..200 {
+        input.push_str("1,");
+    }
+    input.push('x');
+    for _ in 0..200 {
+        input.push_str(",1");
+    }
+    input.push(']');
+
+    let error = crate::from_str::<Value>(&input).unwrap_err();
+    let mut snippet = error.snippet(&input);
+    snippet.set_max_width(40);
+    let rendered = snippet.to_string();
+
+    let (line, caret) = caret_lines(&rendered);
+    assert!(line.len() <= "1 | ".len() + 40 + 2 * "...".len());
+    assert!(line.contains(" | ...") && line.ends_with("..."));
+    assert_eq!(&line[caret.len() - 1..caret.len()], "x");
+}
+
+#[test]
+fn snippet_without_position() {
+    let error = DeserializeError::from(DeserializeErrorKind::MissingField("port"));
+    assert_eq!(
+        error.snippet(INPUT).to_string(),
+        "error: missing field \"port\""
+    );
+}
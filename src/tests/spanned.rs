@@ -0,0 +1,113 @@
+use crate::{Spanned, SpannedValue, Value};
+#[cfg(feature = "no_std")]
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeMap;
+
+/// Gets the (line, column) of the start and end of `spanned`
+fn span<T>(spanned: &Spanned<T>) -> ((usize, usize), (usize, usize)) {
+    (
+        (spanned.start().line(), spanned.start().column()),
+        (spanned.end().line(), spanned.end().column()),
+    )
+}
+
+#[test]
+fn spanned_fields() {
+    let json = "{\n  \"host\": \"localhost\",\n  \"port\":  70000\n}";
+    let config: BTreeMap<String, Spanned<Value>> = crate::from_str(json).unwrap();
+
+    let port = &config["port"];
+    assert_eq!(port.value(), &Value::Number(70000.0));
+    assert_eq!(span(port), ((3, 12), (3, 17)));
+    assert_eq!(span(&config["host"]), ((2, 11), (2, 22)));
+}
+
+#[test]
+fn spanned_nested() {
+    let json = "[[true], [ false , true ]]";
+    let lists: Spanned<Vec<Spanned<Vec<Spanned<bool>>>>> = crate::from_str(json).unwrap();
+
+    assert_eq!(span(&lists), ((1, 1), (1, 27)));
+    assert_eq!(span(&lists.value()[1]), ((1, 10), (1, 26)));
+    assert_eq!(span(&lists.value()[1].value()[0]), ((1, 12), (1, 17)));
+    assert!(*lists.value()[1].value()[1].value());
+}
+
+#[test]
+fn spanned_value() {
+    let json = r#"{"a": [1, {"b": null}], "c": "d"}"#;
+    let value: Spanned<SpannedValue> = crate::from_str(json).unwrap();
+    assert_eq!(span(&value), ((1, 1), (1, 34)));
+
+    let object = match value.value() {
+        SpannedValue::Object(object) => object,
+        _ => panic!("expected an object"),
+    };
+    assert_eq!(span(&object["a"]), ((1, 7), (1, 23)));
+    assert_eq!(span(&object["c"]), ((1, 30), (1, 33)));
+
+    match object["a"].value() {
+        SpannedValue::Array(array) => assert_eq!(span(&array[1]), ((1, 11), (1, 22))),
+        _ => panic!("expected an array"),
+    }
+
+    assert_eq!(
+        value.into_inner().into_value(),
+        crate::from_str::<Value>(json).unwrap()
+    );
+}
+
+#[test]
+fn spanned_serialize() {
+    let value: Spanned<Vec<u8>> = crate::from_str(" [1, 2] ").unwrap();
+    assert_eq!(crate::to_str(&value).unwrap(), "[1,2]");
+}
+
+#[test]
+fn spanned_errors() {
+    assert!(crate::from_str::<Spanned<bool>>("nul").is_err());
+    assert!(crate::from_str::<BTreeMap<String, Spanned<u8>>>(r#"{"a": "b"}"#).is_err());
+}
+
+/// Claims to be [`Spanned`] but asks for the end of the span before the value
+struct EndFirst;
+
+impl<'de> data_format::Deserialize<'de> for EndFirst {
+    fn deserialize<D: data_format::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(EndFirst)
+    }
+}
+
+impl<'de> data_format::Converter<'de> for EndFirst {
+    type Value = EndFirst;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(crate::deserialize::SPANNED_MAGIC)
+    }
+
+    fn convert_map<M: data_format::MapDeserializer<'de>>(
+        self,
+        mut map: M,
+    ) -> Result<Self::Value, M::Error> {
+        map.next_value::<usize>()?;
+        map.next_value::<usize>()?;
+        let _ = map.next_value::<bool>();
+        map.next_value::<usize>()?;
+        map.next_value::<usize>()?;
+        map.next_value::<usize>()?;
+        Ok(EndFirst)
+    }
+}
+
+#[test]
+fn spanned_out_of_order() {
+    let error = crate::from_str::<EndFirst>("  1").err().unwrap();
+    assert_eq!(error.position().unwrap().column(), 3);
+
+    let error = crate::from_str::<EndFirst>("  true").err().unwrap();
+    assert!(matches!(
+        error.kind(),
+        crate::DeserializeErrorKind::InvalidLength { unexpected: 6, .. }
+    ));
+}
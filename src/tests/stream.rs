@@ -0,0 +1,75 @@
+use crate::{StreamDeserializer, StreamMode, Value};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Deserializes every value in `json`, returning each value's range or [`None`] for an error
+fn ranges(json: &str, mode: StreamMode) -> Vec<Option<Range<usize>>> {
+    StreamDeserializer::<Value>::new(json, mode)
+        .map(|result| result.ok().map(|(_, range)| range))
+        .collect()
+}
+
+#[test]
+fn stream_whitespace() {
+    let json = " 1 [2]\n{\"a\": \"b\"}\ttrue ";
+    let values: Vec<Value> = StreamDeserializer::new(json, StreamMode::Whitespace)
+        .map(|result| result.unwrap().0)
+        .collect();
+    assert_eq!(values.len(), 4);
+    assert_eq!(values[3], Value::Boolean(true));
+    assert_eq!(
+        ranges(json, StreamMode::Whitespace),
+        [Some(1..2), Some(3..6), Some(7..17), Some(18..22)]
+    );
+
+    assert_eq!(ranges("{}{}", StreamMode::Whitespace), [Some(0..2), None]);
+    assert_eq!(ranges("  ", StreamMode::Whitespace), []);
+}
+
+#[test]
+fn stream_concatenated() {
+    assert_eq!(
+        ranges("{}{}[] \"a\"1 null", StreamMode::Concatenated),
+        [
+            Some(0..2),
+            Some(2..4),
+            Some(4..6),
+            Some(7..10),
+            Some(10..11),
+            Some(12..16)
+        ]
+    );
+
+    let mut stream = StreamDeserializer::<Value>::new("[1][2,]{}", StreamMode::Concatenated);
+    assert!(stream.next().unwrap().is_ok());
+    let error = stream.next().unwrap().unwrap_err();
+    assert_eq!(error.offset(), Some(6));
+    assert!(stream.next().is_none());
+}
+
+#[test]
+fn stream_record_separated() {
+    let json =
+        "\u{1e}{\"a\": 1}\n\u{1e}[1,\n\u{1e}\u{1e}\"ok\"\n\u{1e}12\u{1e}34\n\u{1e}true false\n";
+    let results: Vec<_> =
+        StreamDeserializer::<Value>::new(json, StreamMode::RecordSeparated).collect();
+    assert_eq!(results.len(), 6);
+
+    assert_eq!(results[0].as_ref().unwrap().1, 1..9);
+    let error = results[1].as_ref().unwrap_err();
+    let position = error.position().unwrap();
+    assert_eq!((position.line(), position.column()), (3, 1));
+    assert_eq!(results[2].as_ref().unwrap().0, Value::String("ok".into()));
+    assert_eq!(
+        results[3].as_ref().unwrap_err().classify(),
+        crate::ErrorCategory::Eof
+    );
+    assert_eq!(results[4].as_ref().unwrap().0, Value::Number(34.0));
+    assert!(results[5].is_err());
+
+    assert_eq!(
+        ranges("[1]\n\u{1e}2\n", StreamMode::RecordSeparated),
+        [None, Some(5..6)]
+    );
+}
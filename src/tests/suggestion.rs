@@ -0,0 +1,57 @@
+use crate::DeserializeErrorKind;
+#[cfg(feature = "no_std")]
+use alloc::{borrow::Cow, string::ToString};
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+const FIELDS: &[&str] = &["host", "port", "timeout", "max_connections"];
+
+/// Creates an unknown field error for `field`
+fn unknown_field(field: &str) -> DeserializeErrorKind<'_> {
+    DeserializeErrorKind::UnknownField {
+        field: Cow::Borrowed(field),
+        expected: FIELDS,
+    }
+}
+
+#[test]
+fn suggestion_close_matches() {
+    assert_eq!(unknown_field("prot").suggestion(), Some("port"));
+    assert_eq!(unknown_field("hots").suggestion(), Some("host"));
+    assert_eq!(unknown_field("timout").suggestion(), Some("timeout"));
+    assert_eq!(unknown_field("Timeout").suggestion(), Some("timeout"));
+    assert_eq!(
+        unknown_field("max_conections").suggestion(),
+        Some("max_connections")
+    );
+}
+
+#[test]
+fn suggestion_no_match() {
+    assert_eq!(unknown_field("username").suggestion(), None);
+    assert_eq!(unknown_field("").suggestion(), None);
+    assert_eq!(
+        DeserializeErrorKind::MissingField("port").suggestion(),
+        None
+    );
+    assert_eq!(
+        DeserializeErrorKind::UnknownField {
+            field: Cow::Borrowed("port"),
+            expected: &[],
+        }
+        .suggestion(),
+        None
+    );
+}
+
+#[test]
+fn suggestion_display() {
+    assert_eq!(
+        unknown_field("prot").to_string(),
+        "unknown field \"prot\", did you mean \"port\"?"
+    );
+    assert_eq!(
+        unknown_field("username").to_string(),
+        "unknown field \"username\", expected host, port, timeout, max_connections"
+    );
+}
@@ -0,0 +1,123 @@
+use crate::{JsonWriter, SerializeError, Value};
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+#[cfg(not(feature = "no_std"))]
+use std::vec::Vec;
+
+/// The output written to by tests
+#[cfg(not(feature = "no_std"))]
+type Output = Vec<u8>;
+
+/// The output written to by tests
+#[cfg(feature = "no_std")]
+type Output = String;
+
+/// Writes JSON to a [`JsonWriter`]
+type Write = fn(&mut JsonWriter<Output>) -> Result<(), SerializeError>;
+
+/// Writes JSON with `write`, returning the output
+fn write<F>(pretty: bool, write: F) -> String
+where
+    F: FnOnce(&mut JsonWriter<Output>) -> Result<(), SerializeError>,
+{
+    let mut writer = match pretty {
+        true => JsonWriter::pretty(Output::new()),
+        false => JsonWriter::new(Output::new()),
+    };
+    write(&mut writer).unwrap();
+
+    #[cfg(not(feature = "no_std"))]
+    return String::from_utf8(writer.finish().unwrap()).unwrap();
+    #[cfg(feature = "no_std")]
+    writer.finish().unwrap()
+}
+
+/// Writes a report of rows as a [`JsonWriter`] would be used to, with keys in sorted order to
+/// match serializing a [`Value`]
+fn report(writer: &mut JsonWriter<Output>) -> Result<(), SerializeError> {
+    writer.begin_object()?;
+    writer.key("empty")?;
+    writer.begin_object()?;
+    writer.end()?;
+    writer.key("rows")?;
+    writer.begin_array()?;
+    for row in 0..2 {
+        writer.begin_object()?;
+        writer.key("id")?;
+        writer.value(&row)?;
+        writer.key("tags")?;
+        writer.value(&["x", "y"][..])?;
+        writer.end()?;
+    }
+    writer.begin_array()?;
+    writer.end()?;
+    writer.end()?;
+    writer.key("title")?;
+    writer.value("a/b")?;
+    writer.end()
+}
+
+#[test]
+fn writer_compact() {
+    let json = write(false, report);
+    assert_eq!(
+        json,
+        r#"{"empty":{},"rows":[{"id":0,"tags":["x","y"]},{"id":1,"tags":["x","y"]},[]],"title":"a\/b"}"#
+    );
+
+    let value: Value = crate::from_str(&json).unwrap();
+    assert_eq!(crate::to_str(&value).unwrap(), json);
+    assert_eq!(write(false, |writer| writer.value(&true)), "true");
+}
+
+#[test]
+fn writer_pretty() {
+    let json = write(true, report);
+    let value: Value = crate::from_str(&json).unwrap();
+    assert_eq!(json, crate::to_str_pretty(&value).unwrap());
+    assert!(json.starts_with("{\n    \"empty\": {},\n    \"rows\": [\n        {\n"));
+}
+
+#[test]
+fn writer_misuse() {
+    let misuse: [Write; 7] = [
+        |writer| writer.key("a"),
+        |writer| writer.end(),
+        |writer| {
+            writer.begin_array()?;
+            writer.key("a")
+        },
+        |writer| {
+            writer.begin_object()?;
+            writer.value(&1)
+        },
+        |writer| {
+            writer.begin_object()?;
+            writer.key("a")?;
+            writer.key("b")
+        },
+        |writer| {
+            writer.begin_object()?;
+            writer.key("a")?;
+            writer.end()
+        },
+        |writer| {
+            writer.value(&1)?;
+            writer.value(&2)
+        },
+    ];
+
+    for (index, misuse) in misuse.into_iter().enumerate() {
+        let mut writer = JsonWriter::new(Output::new());
+        assert!(
+            matches!(misuse(&mut writer), Err(SerializeError::InvalidCall(_))),
+            "{}",
+            index
+        );
+    }
+
+    let mut writer = JsonWriter::new(Output::new());
+    writer.begin_array().unwrap();
+    assert!(writer.finish().is_err());
+    assert!(JsonWriter::new(Output::new()).finish().is_err());
+}
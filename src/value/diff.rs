@@ -0,0 +1,25 @@
+use crate::{patch, ArrayDiff, JsonPointer, PatchOperation, Value};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+impl<'de> Value<'de> {
+    /// Creates the JSON Patch (RFC 6902) operations which turn this value into `other`, comparing
+    /// object members by key and array elements by index
+    pub fn diff(&self, other: &Value<'de>) -> Vec<PatchOperation<'de>> {
+        self.diff_with(other, ArrayDiff::Index)
+    }
+
+    /// Creates the JSON Patch (RFC 6902) operations which turn this value into `other`, comparing
+    /// object members by key and arrays as described by `array_diff`
+    pub fn diff_with(&self, other: &Value<'de>, array_diff: ArrayDiff) -> Vec<PatchOperation<'de>> {
+        let mut operations = Vec::new();
+        patch::diff(
+            self,
+            other,
+            &mut JsonPointer::new(),
+            array_diff,
+            &mut operations,
+        );
+        operations
+    }
+}
@@ -0,0 +1,24 @@
+use crate::Value;
+#[cfg(feature = "no_std")]
+use alloc::borrow::Cow;
+#[cfg(not(feature = "no_std"))]
+use std::borrow::Cow;
+
+impl<'de> Value<'de> {
+    /// Converts this value into one which doesn't borrow from the input, so it can outlive it
+    pub fn into_owned(self) -> Value<'static> {
+        match self {
+            Value::Null => Value::Null,
+            Value::Boolean(value) => Value::Boolean(value),
+            Value::Number(value) => Value::Number(value),
+            Value::String(value) => Value::String(Cow::Owned(value.into_owned())),
+            Value::Array(array) => Value::Array(array.into_iter().map(Value::into_owned).collect()),
+            Value::Object(object) => Value::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (Cow::Owned(key.into_owned()), value.into_owned()))
+                    .collect(),
+            ),
+        }
+    }
+}
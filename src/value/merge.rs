@@ -0,0 +1,76 @@
+use crate::Value;
+#[cfg(feature = "no_std")]
+use alloc::collections::BTreeMap;
+#[cfg(not(feature = "no_std"))]
+use std::collections::BTreeMap;
+
+impl<'de> Value<'de> {
+    /// Applies the JSON Merge Patch (RFC 7386) `patch` to this value.
+    ///
+    /// Members of `patch` which are `null` remove the member from this value, members which are
+    /// objects are merged recursively and anything else replaces the existing member. A `patch`
+    /// which isn't an object replaces this value entirely.
+    pub fn merge_patch(&mut self, patch: &Value<'de>) {
+        let patch = match patch {
+            Value::Object(patch) => patch,
+            patch => {
+                *self = patch.clone();
+                return;
+            }
+        };
+
+        if !matches!(self, Value::Object(_)) {
+            *self = Value::Object(BTreeMap::new());
+        }
+
+        let target = match self {
+            Value::Object(target) => target,
+            _ => unreachable!(),
+        };
+
+        for (key, value) in patch {
+            match value {
+                Value::Null => {
+                    target.remove(key);
+                }
+                value => target
+                    .entry(key.clone())
+                    .or_insert(Value::Null)
+                    .merge_patch(value),
+            }
+        }
+    }
+
+    /// Creates a JSON Merge Patch (RFC 7386) which turns `old` into `new` when applied with
+    /// [`Value::merge_patch`].
+    ///
+    /// Merge patches use `null` to remove members, so members of `new` which are set to `null`
+    /// can't be represented and will be removed by the patch instead.
+    pub fn merge_diff(old: &Value<'de>, new: &Value<'de>) -> Value<'de> {
+        let (old, new) = match (old, new) {
+            (Value::Object(old), Value::Object(new)) => (old, new),
+            (_, new) => return new.clone(),
+        };
+
+        let mut patch = BTreeMap::new();
+        for key in old.keys() {
+            if !new.contains_key(key) {
+                patch.insert(key.clone(), Value::Null);
+            }
+        }
+
+        for (key, new) in new {
+            match old.get(key) {
+                Some(old) if old == new => {}
+                Some(old) => {
+                    patch.insert(key.clone(), Value::merge_diff(old, new));
+                }
+                None => {
+                    patch.insert(key.clone(), new.clone());
+                }
+            }
+        }
+
+        Value::Object(patch)
+    }
+}
@@ -12,6 +12,13 @@ use data_format::{
 #[cfg(not(feature = "no_std"))]
 use std::{borrow::Cow, collections::BTreeMap};
 
+mod diff;
+mod into_owned;
+mod merge;
+mod patch;
+mod pointer;
+mod query;
+
 /// A JSON value, representing any type in JSON
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value<'de> {
@@ -0,0 +1,17 @@
+use crate::{Patch, PatchError, Value};
+
+impl<'de> Value<'de> {
+    /// Applies the JSON Patch (RFC 6902) `patch` to this value.
+    ///
+    /// The patch is applied atomically: if any operation fails, this value is left unchanged and
+    /// the error names the failing operation.
+    pub fn apply_patch(&mut self, patch: &Patch<'de>) -> Result<(), PatchError> {
+        let mut document = self.clone();
+        for (index, operation) in patch.operations().iter().enumerate() {
+            operation.apply(&mut document, index)?;
+        }
+
+        *self = document;
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{JsonPointer, PointerError, Value};
+
+impl<'de> Value<'de> {
+    /// Looks up the value referred to by the JSON Pointer `pointer`, returning [`None`] if the
+    /// pointer is malformed or doesn't refer to an existing value
+    pub fn pointer(&self, pointer: &str) -> Option<&Value<'de>> {
+        JsonPointer::parse(pointer).ok()?.resolve(self).ok()
+    }
+
+    /// Looks up a mutable reference to the value referred to by the JSON Pointer `pointer`,
+    /// returning [`None`] if the pointer is malformed or doesn't refer to an existing value
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut Value<'de>> {
+        JsonPointer::parse(pointer).ok()?.resolve_mut(self).ok()
+    }
+
+    /// Inserts `value` at the location referred to by the JSON Pointer `pointer`, returning any
+    /// object member it replaced. See [`JsonPointer::insert`] for the exact semantics.
+    pub fn pointer_insert(
+        &mut self,
+        pointer: &str,
+        value: Value<'de>,
+    ) -> Result<Option<Value<'de>>, PointerError> {
+        JsonPointer::parse(pointer)?.insert(self, value)
+    }
+
+    /// Removes the value referred to by the JSON Pointer `pointer`, returning it
+    pub fn pointer_remove(&mut self, pointer: &str) -> Result<Value<'de>, PointerError> {
+        JsonPointer::parse(pointer)?.remove(self)
+    }
+}
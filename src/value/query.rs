@@ -0,0 +1,10 @@
+use crate::{JsonPath, PathError, QueryNode, Value};
+#[cfg(feature = "no_std")]
+use alloc::vec::Vec;
+
+impl<'de> Value<'de> {
+    /// Selects the nodes of this value matched by the JSONPath (RFC 9535) query `path`
+    pub fn query(&self, path: &str) -> Result<Vec<QueryNode<'_, 'de>>, PathError> {
+        Ok(JsonPath::parse(path)?.query(self))
+    }
+}